@@ -0,0 +1,110 @@
+//! Builds `sowngwala` without the `std` feature, so
+//! that only `math` (with `libm` for the float
+//! functions) is available. It is built for a bare
+//! target in CI (`thumbv7em-none-eabi`), and the
+//! tests here go through the `libm` path.
+#![no_std]
+
+use sowngwala::math::{
+    angle_between_two_celestial_objects,
+    equatorial_from_ecliptic, solve_kepler,
+    KeplerError,
+};
+
+/// Given the ecliptic latitude (β) and longitude (λ)
+/// in degrees, and the obliquity (ε) in degrees,
+/// returns right ascension (α) in decimal hours, and
+/// declination (δ) in degrees.
+pub fn equatorial(
+    lat: f64,
+    lng: f64,
+    obliquity_deg: f64,
+) -> (f64, f64) {
+    equatorial_from_ecliptic(lat, lng, obliquity_deg)
+}
+
+/// Given the mean anomaly (M) in radians and the
+/// eccentricity (e), returns the eccentric anomaly
+/// (E) in radians.
+pub fn eccentric_anomaly(
+    mean_anom: f64,
+    eccentricity: f64,
+) -> Result<f64, KeplerError> {
+    solve_kepler(mean_anom, eccentricity)
+}
+
+/// Given two positions (α in decimal hours, and δ in
+/// degrees), returns the angle between them in
+/// degrees.
+pub fn separation(
+    asc_0: f64,
+    dec_0: f64,
+    asc_1: f64,
+    dec_1: f64,
+) -> f64 {
+    angle_between_two_celestial_objects(
+        asc_0, dec_0, asc_1, dec_1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abs(x: f64) -> f64 {
+        if x < 0.0 {
+            -x
+        } else {
+            x
+        }
+    }
+
+    #[test]
+    fn equatorial_matches_duffett_smith() {
+        // 1980-04-22 (p.40), for which ε is 23.441853°
+        let (asc, dec) = equatorial(
+            4.0 + (52.0 + 31.0 / 60.0) / 60.0,
+            139.0 + (41.0 + 10.0 / 60.0) / 60.0,
+            23.441_853,
+        );
+
+        // 9h 34m 53.6s, and 19° 32′ 14.2″
+        let asc_0: f64 =
+            9.0 + (34.0 + 53.6 / 60.0) / 60.0;
+        let dec_0: f64 =
+            19.0 + (32.0 + 14.2 / 60.0) / 60.0;
+
+        assert!(
+            abs(asc - asc_0) * 15.0 * 3600.0 < 1.0
+        );
+        assert!(abs(dec - dec_0) * 3600.0 < 1.0);
+    }
+
+    #[test]
+    fn eccentric_anomaly_solves_kepler() {
+        let ecc: f64 =
+            eccentric_anomaly(3.5, 0.2).unwrap();
+
+        // E - e sin E = M
+        assert!(
+            abs(ecc - 0.2 * libm::sin(ecc) - 3.5)
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn separation_along_the_equator() {
+        // 1h of right ascension is 15°.
+        assert!(
+            abs(separation(0.0, 0.0, 1.0, 0.0) - 15.0)
+                < 1e-9
+        );
+        // From the pole, 90° to any point on the
+        // equator.
+        assert!(
+            abs(separation(5.0, 90.0, 13.0, 0.0)
+                - 90.0)
+                < 1e-9
+        );
+    }
+}
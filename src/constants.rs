@@ -4,6 +4,17 @@ pub const ECLIPTIC_LONGITUDE_AT_1990: f64 =
     279.403_303; // ε g
 pub const ECLIPTIC_LONGITUDE_OF_PERIGEE: f64 =
     282.768_422; // ω bar g
+pub const SUN_ALTITUDE_AT_RISE_AND_SET: f64 = -0.833; // h0 (refraction + semidiameter)
+pub const SUN_ANGULAR_DIAMETER_AT_1AU: f64 =
+    0.533_128; // θ o
+pub const CONSTANT_OF_ABERRATION: f64 = 20.5; // κ (arcseconds)
+
+// Angles closer than this (in arcseconds) are equal.
+pub const ANGLE_TOLERANCE_ARCSECONDS: f64 = 1e-6;
+
+// Standard atmosphere for refraction
+pub const STANDARD_PRESSURE_MBAR: f64 = 1012.0; // P
+pub const STANDARD_TEMPERATURE_C: f64 = 10.0; // T
 
 pub const MOON_MEAN_LONGITUDE_AT_THE_EPOCH: f64 =
     318.351_648; // l o
@@ -19,5 +30,121 @@ pub const SEMI_MAJOR_AXIS_OF_MOON_ORBIT: f64 =
 pub const MOON_ANGULAR_SIZE_AT_DISTANCE_A_FROM_THE_EARTH: f64 = 0.518_1; // θ o
 pub const PARALLAX_AT_DISTANCE_A_FROM_THE_EARTH: f64 =
     0.950_7; // π o
+pub const SYNODIC_MONTH: f64 = 29.530_589; // days
+pub const EARTH_EQUATORIAL_RADIUS_KM: f64 = 6378.14; // a (earth)
+
+// Days before and after the exact new moon (and the
+// quarters, and the full moon) to be called so.
+pub const MOON_PHASE_NAME_TOLERANCE: f64 = 1.0;
+
+// Altitude of the moon's center at the rise and the
+// set (h0 = 0.7275π - 34'), which is the horizontal
+// parallax less the refraction and the semidiameter.
+pub const MOON_ALTITUDE_AT_RISE_AND_SET: f64 = 0.125;
+
+// Moon's ecliptic latitudes at the syzygy beyond which
+// eclipses cannot happen (upper ecliptic limits).
+pub const SOLAR_ECLIPTIC_LIMIT: f64 = 1.58;
+pub const LUNAR_ECLIPTIC_LIMIT: f64 = 1.05;
+// Within how many degrees of elongation from the
+// conjunction (or the opposition) we regard as the
+// syzygy (about a day of the moon's motion).
+pub const SYZYGY_TOLERANCE: f64 = 12.0;
+
+/// Orbital elements of a planet at the epoch.
+///
+/// * `period` - Period of the orbit in tropical years (Tp)
+/// * `lng_at_epoch` - Longitude at the epoch (ε)
+/// * `lng_of_perihelion` - Longitude of the perihelion (ϖ)
+/// * `eccentricity` - Eccentricity of the orbit (e)
+/// * `semi_major_axis` - Semi-major axis of the orbit in au (a)
+/// * `inclination` - Inclination of the orbit (i)
+/// * `lng_of_node` - Longitude of the ascending node (Ω)
+#[derive(Debug, Copy, Clone)]
+pub struct OrbitalElements {
+    pub period: f64,
+    pub lng_at_epoch: f64,
+    pub lng_of_perihelion: f64,
+    pub eccentricity: f64,
+    pub semi_major_axis: f64,
+    pub inclination: f64,
+    pub lng_of_node: f64,
+}
+
+// Planets' orbital elements at the epoch 1990.0.
+// (Peter Duffett-Smith, p.125)
+// For Uranus and Neptune, the osculating elements at
+// 1990.0 are derived from VSOP87 instead.
+pub const MERCURY_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 0.240_852,
+        lng_at_epoch: 60.750_646,
+        lng_of_perihelion: 77.299_833,
+        eccentricity: 0.205_633,
+        semi_major_axis: 0.387_099,
+        inclination: 7.004_540,
+        lng_of_node: 48.212_740,
+    };
+pub const VENUS_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 0.615_211,
+        lng_at_epoch: 88.455_855,
+        lng_of_perihelion: 131.430_236,
+        eccentricity: 0.006_778,
+        semi_major_axis: 0.723_332,
+        inclination: 3.394_535,
+        lng_of_node: 76.589_820,
+    };
+pub const MARS_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 1.880_932,
+        lng_at_epoch: 240.739_474,
+        lng_of_perihelion: 335.874_939,
+        eccentricity: 0.093_396,
+        semi_major_axis: 1.523_688,
+        inclination: 1.849_736,
+        lng_of_node: 49.480_308,
+    };
+pub const JUPITER_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 11.863_075,
+        lng_at_epoch: 90.638_185,
+        lng_of_perihelion: 14.170_747,
+        eccentricity: 0.048_482,
+        semi_major_axis: 5.202_561,
+        inclination: 1.303_613,
+        lng_of_node: 100.353_142,
+    };
+pub const SATURN_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 29.471_362,
+        lng_at_epoch: 287.690_033,
+        lng_of_perihelion: 92.861_407,
+        eccentricity: 0.055_581,
+        semi_major_axis: 9.554_747,
+        inclination: 2.488_980,
+        lng_of_node: 113.576_139,
+    };
+pub const URANUS_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 84.174_796,
+        lng_at_epoch: 270.234_649,
+        lng_of_perihelion: 171.942_134,
+        eccentricity: 0.047_238,
+        semi_major_axis: 19.206_551,
+        inclination: 0.780_495,
+        lng_of_node: 74.121_963,
+    };
+pub const NEPTUNE_ELEMENTS: OrbitalElements =
+    OrbitalElements {
+        period: 164.442_826,
+        lng_at_epoch: 282.944_015,
+        lng_of_perihelion: 52.582_497,
+        eccentricity: 0.010_096,
+        semi_major_axis: 30.015_051,
+        inclination: 1.773_865,
+        lng_of_node: 131.652_151,
+    };
 
 pub const J2000: f64 = 2_451_545.0;
+pub const LIGHT_TIME_PER_AU: f64 = 0.005_775_518_3; // days
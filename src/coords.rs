@@ -1,17 +1,36 @@
+use crate::constants::{
+    ANGLE_TOLERANCE_ARCSECONDS,
+    CONSTANT_OF_ABERRATION, STANDARD_PRESSURE_MBAR,
+    STANDARD_TEMPERATURE_C,
+};
+use crate::error::SowngwalaError;
+use crate::math;
+use crate::sun::ecliptic_position_of_the_sun_from_generic_date;
 use crate::time::{
     angle_from_decimal_hours, calibrate_hmsn,
     decimal_hours_from_angle,
-    decimal_hours_from_generic_time, gst_from_utc,
-    lst_from_gst, nano_from_second,
+    decimal_hours_from_generic_time, gst_from_lst,
+    julian_centuries_since_j2000,
+    julian_day_from_generic_date, lst_from_utc,
+    nano_from_second, utc_from_gst,
+};
+use crate::units::{DecimalDegrees, DecimalHours};
+use crate::utils::{
+    apparent_obliquity_of_the_ecliptic,
+    mean_obliquity_of_the_epliptic, nutation,
 };
-use crate::utils::mean_obliquity_of_the_epliptic;
 use chrono::naive::{
     NaiveDate, NaiveDateTime, NaiveTime,
 };
 use chrono::offset::Utc;
-use chrono::{DateTime, Datelike, Timelike};
-use std::convert::From;
+use chrono::{
+    DateTime, Datelike, Duration, Timelike,
+};
+use std::convert::{From, TryFrom};
 use std::f64::consts::PI;
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
 
 /// Example
 /// ```rust
@@ -53,40 +72,302 @@ use std::f64::consts::PI;
 /// assert_eq!(day_excess, -1.0);
 /// ```
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Angle {
     pub hour: i32,
     pub minute: i32,
     pub second: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub day_excess: f64,
+    /// The sign of the whole angle, set only by
+    /// `with_sign` (for which the components are
+    /// the magnitudes), and read through `sign` and
+    /// the accessors. Left out of the serialized form
+    /// unless set, so that the form without it still
+    /// round trips.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "std::ops::Not::not"
+        )
+    )]
+    negative: bool,
 }
 
 impl Angle {
+    /// Builds `Angle` out of the components as they
+    /// are, each with its own sign (such as `0h 1m -1s`
+    /// to be carried over by `calibrate`). A negative
+    /// angle is read by the sign of the first nonzero
+    /// component (see `sign`). To keep the sign apart
+    /// from the magnitudes, see `with_sign`.
     pub fn new(
         hour: i32,
         minute: i32,
         second: f64,
     ) -> Self {
-        // let ((hour, minute, second), day_excess) =
-        //     calibrate_hmsn(hour, minute, second);
-
         Angle {
             hour,
             minute,
             second,
             day_excess: 0_f64,
+            negative: false,
+        }
+    }
+
+    /// Builds `Angle` out of the sign and the
+    /// magnitude of each component. The sign is
+    /// stored apart (see `sign`), and the components
+    /// are kept as the magnitudes, so that `-0°0′30″`
+    /// is no different from `-8°2′42″` in how the sign
+    /// is held (see `angle_from_decimal_hours`). The
+    /// accessors (`hour`, `minute`, and `second`) show
+    /// the sign on the first nonzero component.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Angle;
+    ///
+    /// let angle = Angle::with_sign(-1, 0, 0, 30.0);
+    /// assert_eq!(angle.hour(), 0);
+    /// assert_eq!(angle.minute(), 0);
+    /// assert_eq!(angle.second(), -30.0);
+    /// assert_eq!(angle.sign(), -1);
+    ///
+    /// let angle = Angle::with_sign(-1, 8, 2, 42.0);
+    /// assert_eq!(angle.hour(), -8);
+    /// assert_eq!(angle.minute(), 2);
+    /// assert_eq!(angle.second(), 42.0);
+    /// assert_eq!(angle.sign(), -1);
+    /// ```
+    pub fn with_sign(
+        sign: i8,
+        hour: u32,
+        minute: u32,
+        second: f64,
+    ) -> Self {
+        Angle {
+            hour: hour as i32,
+            minute: minute as i32,
+            second: second.abs(),
+            day_excess: 0_f64,
+            negative: sign < 0,
+        }
+    }
+
+    /// Returns -1, 0, or 1 for the sign of the angle.
+    /// It is the stored sign for `with_sign` (0 when
+    /// all the magnitudes are 0), or otherwise that of
+    /// the first nonzero component.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Angle;
+    ///
+    /// assert_eq!(Angle::new(-8, 2, 42.0).sign(), -1);
+    /// assert_eq!(Angle::new(0, -6, 0.0).sign(), -1);
+    /// assert_eq!(Angle::new(0, 0, 30.0).sign(), 1);
+    /// assert_eq!(Angle::new(0, 0, 0.0).sign(), 0);
+    /// assert_eq!(Angle::with_sign(-1, 0, 0, 30.0).sign(), -1);
+    /// assert_eq!(Angle::with_sign(-1, 0, 0, 0.0).sign(), 0);
+    /// ```
+    pub fn sign(&self) -> i8 {
+        if self.negative {
+            if self.hour != 0
+                || self.minute != 0
+                || self.second != 0.0
+            {
+                -1
+            } else {
+                0
+            }
+        } else if self.hour != 0 {
+            self.hour.signum() as i8
+        } else if self.minute != 0 {
+            self.minute.signum() as i8
+        } else if self.second > 0.0 {
+            1
+        } else if self.second < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the angle as the total arcseconds,
+    /// reading the components as degrees, arcminutes,
+    /// and arcseconds. The sign is that of `sign()`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Angle;
+    ///
+    /// assert_eq!(
+    ///     Angle::new(-8, 13, 30.0).to_arcseconds(),
+    ///     -29_610.0
+    /// );
+    /// assert_eq!(
+    ///     Angle::new(0, -1, 0.5).to_arcseconds(),
+    ///     -60.5
+    /// );
+    /// ```
+    pub fn to_arcseconds(&self) -> f64 {
+        let total: f64 = (self.hour.abs() as f64
+            * 3600.0)
+            + (self.minute.abs() as f64 * 60.0)
+            + self.second.abs();
+
+        if self.sign() < 0 {
+            -total
+        } else {
+            total
+        }
+    }
+
+    /// Builds `Angle` out of the total arcseconds,
+    /// carrying over into arcminutes and degrees. The
+    /// sign is placed on the first nonzero component
+    /// (see `with_sign`).
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Angle;
+    ///
+    /// let angle = Angle::from_arcseconds(-29_610.0);
+    /// assert_eq!(angle.hour(), -8);
+    /// assert_eq!(angle.minute(), 13);
+    /// assert_eq!(angle.second(), 30.0);
+    /// ```
+    pub fn from_arcseconds(total: f64) -> Self {
+        let sign: i8 =
+            if total < 0.0 { -1 } else { 1 };
+        let total: f64 = total.abs();
+
+        let hour: f64 = (total / 3600.0).floor();
+        let minute: f64 =
+            ((total - hour * 3600.0) / 60.0).floor();
+        let second: f64 =
+            total - (hour * 3600.0) - (minute * 60.0);
+
+        Angle::with_sign(
+            sign,
+            hour as u32,
+            minute as u32,
+            second,
+        )
+    }
+
+    /// Returns the angle with the seconds rounded to
+    /// the given decimal places, carrying over into
+    /// the minutes and the hours (or degrees), so that
+    /// the seconds never show "60". The hours are not
+    /// wrapped (`23h 59m 59.9999s` becomes `24h 0m 0s`),
+    /// for which `calibrate` can be used afterwards.
+    /// The sign is placed on the first nonzero
+    /// component (see `with_sign`). The decimal places
+    /// are clamped to 9 (nanoseconds), and the angle is
+    /// returned as it is when it is too large to be
+    /// counted in the units of the last decimal place.
+    ///
+    /// * `decimals` - Decimal places for the seconds
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Angle;
+    ///
+    /// let angle = Angle::new(12, 30, 15.456).round_seconds(2);
+    /// assert_eq!(angle.hour(), 12);
+    /// assert_eq!(angle.minute(), 30);
+    /// assert_eq!(angle.second(), 15.46);
+    ///
+    /// let angle = Angle::new(23, 59, 59.9999).round_seconds(0);
+    /// assert_eq!(angle.hour(), 24);
+    /// assert_eq!(angle.minute(), 0);
+    /// assert_eq!(angle.second(), 0.0);
+    /// ```
+    pub fn round_seconds(
+        &self,
+        decimals: u32,
+    ) -> Self {
+        // Beyond nanoseconds, `f64` has no digits
+        // left to round.
+        let scale: i64 = match 10_i64
+            .checked_pow(decimals.min(9))
+        {
+            Some(scale) => scale,
+            None => return *self,
+        };
+
+        // In the units of the last decimal place
+        let units: f64 = (self.to_arcseconds().abs()
+            * scale as f64)
+            .round();
+        if units.is_nan() || units >= i64::MAX as f64
+        {
+            return *self;
         }
+        let units: i64 = units as i64;
+
+        let (per_hour, per_minute): (i64, i64) =
+            match (
+                scale.checked_mul(3600),
+                scale.checked_mul(60),
+            ) {
+                (Some(h), Some(m)) => (h, m),
+                _ => return *self,
+            };
+
+        let hour: i64 = units / per_hour;
+        let minute: i64 = (units / per_minute) % 60;
+        let second: f64 = (units % per_minute) as f64
+            / scale as f64;
+
+        Angle::with_sign(
+            self.sign(),
+            hour as u32,
+            minute as u32,
+            second,
+        )
     }
 
+    /// Returns the hours (or degrees), which are
+    /// negative for a negative angle (see `with_sign`)
+    /// unless they are 0.
     pub fn hour(&self) -> i32 {
-        self.hour
+        if self.negative {
+            -self.hour.abs()
+        } else {
+            self.hour
+        }
     }
 
+    /// Returns the minutes, which are negative for a
+    /// negative angle (see `with_sign`) only when the
+    /// hours are 0.
     pub fn minute(&self) -> i32 {
-        self.minute
+        if self.negative && self.hour == 0 {
+            -self.minute.abs()
+        } else {
+            self.minute
+        }
     }
 
+    /// Returns the seconds, which are negative for a
+    /// negative angle (see `with_sign`) only when the
+    /// hours and the minutes are 0.
     pub fn second(&self) -> f64 {
-        self.second
+        if self.negative
+            && self.hour == 0
+            && self.minute == 0
+        {
+            -self.second.abs()
+        } else {
+            self.second
+        }
     }
 
     pub fn day_excess(&self) -> f64 {
@@ -97,1048 +378,5651 @@ impl Angle {
         self.into()
     }
 
+    /// Calibrates the angle as a time (see
+    /// `calibrate_hmsn`), carrying over at 24 hours,
+    /// and returns the day excess. For degrees, which
+    /// carry over at 360°, see `time::calibrate_dms`.
     pub fn calibrate(&mut self) -> f64 {
         let ((hour, min, sec), day_excess) =
             calibrate_hmsn(
-                self.hour,
-                self.minute,
-                self.second,
+                self.hour(),
+                self.minute(),
+                self.second(),
             );
 
         self.hour = hour;
         self.minute = min;
         self.second = sec;
+        self.negative = false;
 
         day_excess
     }
+
+    /// Returns `AngleReport` carrying the angle in
+    /// several representations at once. Components
+    /// are read as degrees, arcminutes, and
+    /// arcseconds, and `decimal_hours` is the same
+    /// angle divided by 15.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Angle, AngleReport};
+    ///
+    /// let report: AngleReport =
+    ///     Angle::new(-8, 13, 30.0).report();
+    ///
+    /// assert_eq!(report.sexagesimal, "-8°13′30″");
+    /// assert_eq!(report.decimal_degrees, -8.225);
+    /// assert_eq!(report.total_arcsec, -29_610.0);
+    /// ```
+    pub fn report(&self) -> AngleReport {
+        let decimal_degrees: f64 =
+            decimal_hours_from_angle(*self);
+
+        let sign: &str =
+            if self.sign() < 0 { "-" } else { "" };
+
+        AngleReport {
+            sexagesimal: format!(
+                "{}{}°{}′{}″",
+                sign,
+                self.hour.abs(),
+                self.minute.abs(),
+                self.second.abs()
+            ),
+            decimal_degrees,
+            decimal_hours: decimal_degrees / 15.0,
+            radians: decimal_degrees.to_radians(),
+            total_arcsec: decimal_degrees * 3600.0,
+        }
+    }
+}
+
+/// An angle in several representations, which is
+/// what `Angle::report` returns.
+///
+/// * `sexagesimal` - Such as `"-8°13′30″"` (which
+///   parses back into `Angle`)
+/// * `decimal_degrees` - Degrees
+/// * `decimal_hours` - Hours (15 degrees to an hour)
+/// * `radians` - Radians
+/// * `total_arcsec` - Arcseconds
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AngleReport {
+    pub sexagesimal: String,
+    pub decimal_degrees: f64,
+    pub decimal_hours: f64,
+    pub radians: f64,
+    pub total_arcsec: f64,
+}
+
+/// Given `Angle`, returns the calibrated `Angle`
+/// (seconds and minutes within 0 to 60, and hours
+/// within 0 to 24), and the day excess (how many
+/// days were carried over, which is negative when
+/// borrowed). This is what `From<Angle> for NaiveTime`
+/// does before building the time. For normalizing a
+/// plain number, see `utils::normalize_angle`.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{normalize_angle, Angle};
+///
+/// // 90 seconds of arc carry over to a minute
+/// let (angle, day_excess) =
+///     normalize_angle(Angle::new(0, 0, 90.0));
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), 1);
+/// assert_eq!(angle.second(), 30.0);
+/// assert_eq!(day_excess, 0.0);
+///
+/// let (angle, day_excess) =
+///     normalize_angle(Angle::new(23, 59, 90.0));
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), 0);
+/// assert_eq!(angle.second(), 30.0);
+/// assert_eq!(day_excess, 1.0);
+/// ```
+pub fn normalize_angle(angle: Angle) -> (Angle, f64) {
+    let mut angle_1 = angle;
+    let day_excess: f64 = angle_1.calibrate();
+    (angle_1, day_excess)
+}
+
+/// Angles are compared by their total arcseconds
+/// (`to_arcseconds`), so that the sign is that of the
+/// first nonzero component, and `-0:0:30` is less
+/// than `0:0:0`. Those within
+/// `ANGLE_TOLERANCE_ARCSECONDS` (1e-6") of each other
+/// are equal, which absorbs the rounding errors of
+/// the conversions (but is not transitive). The day
+/// excess is not compared.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::Angle;
+///
+/// assert!(Angle::new(0, 0, -30.0) < Angle::new(0, 0, 0.0));
+/// assert!(Angle::new(-8, 2, 42.0) < Angle::new(-8, 0, 0.0));
+/// assert_eq!(Angle::new(0, 60, 0.0), Angle::new(1, 0, 0.0));
+/// assert_eq!(
+///     Angle::new(0, 0, 1.0),
+///     Angle::new(0, 0, 1.000_000_000_1)
+/// );
+/// ```
+impl PartialEq for Angle {
+    fn eq(&self, other: &Self) -> bool {
+        (self.to_arcseconds() - other.to_arcseconds())
+            .abs()
+            < ANGLE_TOLERANCE_ARCSECONDS
+    }
+}
+
+impl PartialOrd for Angle {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<std::cmp::Ordering> {
+        if self == other {
+            Some(std::cmp::Ordering::Equal)
+        } else {
+            self.to_arcseconds()
+                .partial_cmp(&other.to_arcseconds())
+        }
+    }
+}
+
+/// Given altitudes (a), returns the highest one, or
+/// `None` for an empty slice (or when any of them is
+/// `NaN`).
+///
+/// * `angles` - Altitudes
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{max_altitude, Angle};
+///
+/// let angles = [
+///     Angle::new(-12, 30, 0.0),
+///     Angle::new(45, 0, 0.0),
+///     Angle::new(0, -10, 0.0),
+/// ];
+/// let max = max_altitude(&angles).unwrap();
+/// assert_eq!(max, Angle::new(45, 0, 0.0));
+///
+/// assert!(max_altitude(&[]).is_none());
+/// ```
+pub fn max_altitude(
+    angles: &[Angle],
+) -> Option<Angle> {
+    let mut max: Option<Angle> = None;
+
+    for angle in angles.iter() {
+        match max {
+            None => max = Some(*angle),
+            Some(m) => match angle.partial_cmp(&m) {
+                Some(std::cmp::Ordering::Greater) => {
+                    max = Some(*angle)
+                }
+                Some(_) => {}
+                None => return None,
+            },
+        }
+    }
+
+    max
 }
 
+/// Negative angles are counted back from
+/// the midnight.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveTime;
+/// use sowngwala::coords::Angle;
+///
+/// let t: NaiveTime = Angle::new(-1, 30, 0.0).into();
+/// assert_eq!(t, NaiveTime::from_hms(22, 30, 0));
+///
+/// let t: NaiveTime = Angle::new(0, 0, -30.0).into();
+/// assert_eq!(t, NaiveTime::from_hms(23, 59, 30));
+/// ```
 impl From<Angle> for NaiveTime {
     fn from(angle: Angle) -> Self {
-        let mut angle_1 = angle;
-        angle_1.calibrate();
+        let angle_0 = if angle.sign() < 0 {
+            Angle::new(
+                -angle.hour().abs(),
+                -angle.minute().abs(),
+                -angle.second().abs(),
+            )
+        } else {
+            angle
+        };
+        let (angle_1, _day_excess) =
+            normalize_angle(angle_0);
 
         let (sec, nano): (u32, u32) =
             nano_from_second(angle_1.second());
 
-        NaiveTime::from_hms_nano(
+        NaiveTime::from_hms_nano_opt(
             angle_1.hour() as u32,
             angle_1.minute() as u32,
             sec,
             nano,
         )
+        .unwrap()
     }
 }
 
-pub enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+/// Treats `Angle` as a time span, keeping the sign,
+/// and nanoseconds (see `nano_from_second`). Handy for
+/// adding an hour-angle to a timestamp.
+///
+/// Example:
+/// ```rust
+/// use chrono::Duration;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Angle;
+///
+/// let d: Duration = Angle::new(-1, 30, 0.25).into();
+/// assert_eq!(
+///     d,
+///     -(Duration::minutes(90)
+///         + Duration::milliseconds(250))
+/// );
+///
+/// let dt = NaiveDate::from_ymd(2022, 1, 1).and_hms(12, 0, 0)
+///     + Duration::from(Angle::new(2, 15, 0.0));
+/// assert_eq!(dt, NaiveDate::from_ymd(2022, 1, 1).and_hms(14, 15, 0));
+/// ```
+impl From<Angle> for Duration {
+    fn from(angle: Angle) -> Self {
+        let second: f64 = angle.second().abs();
+        let extra_minutes: f64 =
+            (second / 60.0).floor();
+        let (sec, nano): (u32, u32) =
+            nano_from_second(
+                second - extra_minutes * 60.0,
+            );
 
-// Geometric Coordinate
-#[derive(Debug)]
-pub struct Coord {
-    pub lat: f64,
-    pub lng: f64,
+        let duration = Duration::hours(
+            angle.hour().abs() as i64,
+        ) + Duration::minutes(
+            angle.minute().abs() as i64
+                + extra_minutes as i64,
+        ) + Duration::seconds(
+            sec as i64,
+        ) + Duration::nanoseconds(
+            nano as i64,
+        );
+
+        if angle.sign() < 0 {
+            -duration
+        } else {
+            duration
+        }
+    }
 }
 
-// Ecliptic Coordinate
-#[derive(Debug)]
-pub struct EcliCoord {
-    pub lat: f64,
-    pub lng: f64,
+/// Errors for converting `Duration` into `Angle`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AngleDurationError {
+    /// Hours do not fit in `Angle`.
+    TooManyHours(i64),
 }
 
-// Galactic Coordinate
-#[derive(Debug)]
-pub struct GalacCoord {
-    pub lat: f64,
-    pub lng: f64,
+impl fmt::Display for AngleDurationError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            AngleDurationError::TooManyHours(h) => {
+                write!(f, "Too many hours: {}", h)
+            }
+        }
+    }
 }
 
-// Equatorial Coordinate
-#[derive(Debug)]
-pub struct EquaCoord {
-    pub asc: Angle, // right ascension (α)
-    pub dec: Angle, // declination (δ)
+impl std::error::Error for AngleDurationError {}
+
+/// Treats a time span as `Angle`, keeping the sign,
+/// and nanoseconds. Fails only when the hours do not
+/// fit in `i32`.
+///
+/// Example:
+/// ```rust
+/// use chrono::Duration;
+/// use std::convert::TryFrom;
+/// use sowngwala::coords::Angle;
+///
+/// let angle = Angle::try_from(
+///     -(Duration::minutes(90) + Duration::nanoseconds(5)),
+/// )
+/// .unwrap();
+/// assert_eq!(angle.hour(), -1);
+/// assert_eq!(angle.minute(), 30);
+/// assert_eq!(angle.second(), 0.000_000_005);
+/// ```
+impl TryFrom<Duration> for Angle {
+    type Error = AngleDurationError;
+
+    fn try_from(
+        duration: Duration,
+    ) -> Result<Self, Self::Error> {
+        let negative: bool =
+            duration < Duration::zero();
+        let duration = if negative {
+            -duration
+        } else {
+            duration
+        };
+
+        let secs: i64 = duration.num_seconds();
+        let nanos: i64 = (duration
+            - Duration::seconds(secs))
+        .num_nanoseconds()
+        .unwrap_or(0);
+
+        let hours: i64 = secs / 3600;
+        if hours > i32::MAX as i64 {
+            return Err(
+                AngleDurationError::TooManyHours(
+                    hours,
+                ),
+            );
+        }
+        let minutes: i64 = (secs % 3600) / 60;
+        let second: f64 = (secs % 60) as f64
+            + nanos as f64 / 1_000_000_000.0;
+
+        Ok(Angle::with_sign(
+            if negative { -1 } else { 1 },
+            hours as u32,
+            minutes as u32,
+            second,
+        ))
+    }
 }
 
-// Equatorial Coordinate (with Hour-Angle)
-#[derive(Debug)]
-pub struct EquaCoord2 {
-    pub ha: Angle,  // hour-angle (H)
-    pub dec: Angle, // declination (δ)
+// Given `Angle` and seconds (of time) to add, returns
+// `Angle` within 0h to 24h, with the days carried over
+// added to the day excess.
+fn _angle_plus_seconds(
+    angle: Angle,
+    secs: f64,
+) -> Angle {
+    let total: f64 = angle.to_arcseconds() + secs;
+    let days: f64 = (total / 86_400.0).floor();
+
+    let mut angle_1 = Angle::from_arcseconds(
+        total - days * 86_400.0,
+    );
+    angle_1.day_excess = angle.day_excess + days;
+    angle_1
 }
 
-// Ecliptic coordinate
-#[derive(Debug)]
-pub struct HorizCoord {
-    pub alt: Angle, // altitude (a)
-    pub azi: Angle, // azimuth (A)
+// Seconds of `Duration` including the nanoseconds
+fn _seconds_from_duration(duration: Duration) -> f64 {
+    let secs: i64 = duration.num_seconds();
+    let nanos: i64 = (duration
+        - Duration::seconds(secs))
+    .num_nanoseconds()
+    .unwrap_or(0);
+    secs as f64 + nanos as f64 / 1_000_000_000.0
 }
 
-/// Given UTC, right ascension (α), and longitude
-/// (along with its direction), returns
-/// hour-angle (H).
-///
-/// * `utc` - UTC
-/// * `asc` - Right-ascension
-/// * `lng` - Longitude
-/// * `dir` - Direction for Longitude
-///
-/// Reference:
-/// - (Peter Duffett-Smith, p.35)
+/// Advances `Angle` (as hours, such as an hour angle
+/// or sidereal time) by `Duration`, carrying over at
+/// 24 hours. The resulting angle is always within 0h
+/// to 24h, and the days carried over (negative when
+/// borrowed) are added to `day_excess`.
 ///
 /// Example:
 /// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::{DateTime, Timelike};
-/// use chrono::offset::Utc;
-/// use sowngwala::time::{
-///   build_utc,
-///   angle_from_decimal_hours,
-///   decimal_hours_from_generic_time,
-///   lst_from_gst,
-/// };
-/// use sowngwala::coords::{
-///   Angle,
-///   Direction,
-///   hour_angle_from_utc
-/// };
+/// use chrono::Duration;
+/// use sowngwala::coords::Angle;
 ///
-/// let dir = Direction::West;
-/// let lng = 64.0;
-/// let asc: Angle = Angle::new(18, 32, 21.0);
+/// let angle = Angle::new(2, 30, 0.0) + Duration::minutes(90);
+/// assert_eq!(angle.hour(), 4);
+/// assert_eq!(angle.minute(), 0);
+/// assert_eq!(angle.day_excess(), 0.0);
 ///
-/// // TODO: Do we need `zone`? Originally, no zone.
-/// let zone: i32 = 4;
-/// let nanosecond: u32 = 670_000_000;
-/// let utc: DateTime<Utc> =
-///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
+/// let angle = Angle::new(23, 0, 0.0) + Duration::minutes(90);
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), 30);
+/// assert_eq!(angle.day_excess(), 1.0);
+/// ```
+impl Add<Duration> for Angle {
+    type Output = Angle;
+
+    fn add(self, duration: Duration) -> Angle {
+        _angle_plus_seconds(
+            self,
+            _seconds_from_duration(duration),
+        )
+    }
+}
+
+/// Moves `Angle` back by `Duration`, borrowing at 0h
+/// (see `Add<Duration>`).
 ///
-/// let hour_angle: Angle =
-///     hour_angle_from_utc(utc, asc, lng, dir);
+/// Example:
+/// ```rust
+/// use chrono::Duration;
+/// use sowngwala::coords::Angle;
 ///
-/// assert_eq!(hour_angle.hour(), 5);
-/// assert_eq!(hour_angle.minute(), 51);
-/// assert_approx_eq!(
-///     hour_angle.second(), // 44.22957675918951
-///     44.0,
-///     1e-2
-/// );
+/// let angle = Angle::new(0, 30, 0.0) - Duration::minutes(90);
+/// assert_eq!(angle.hour(), 23);
+/// assert_eq!(angle.minute(), 0);
+/// assert_eq!(angle.day_excess(), -1.0);
 /// ```
-pub fn hour_angle_from_utc(
-    utc: DateTime<Utc>,
-    asc: Angle,
-    lng: f64,
-    dir: Direction,
-) -> Angle {
-    let gst: NaiveTime = gst_from_utc(utc);
-    let gst_0: NaiveDateTime = NaiveDate::from_ymd(
-        utc.year(),
-        utc.month(),
-        utc.day(),
-    )
-    .and_hms_nano(
-        gst.hour(),
-        gst.minute(),
-        gst.second(),
-        gst.nanosecond(),
-    );
+impl Sub<Duration> for Angle {
+    type Output = Angle;
 
-    let lst: NaiveTime =
-        lst_from_gst(gst_0, lng, dir);
-    let lst_decimal: f64 =
-        decimal_hours_from_generic_time(lst);
-    let asc_decimal: f64 =
-        decimal_hours_from_angle(asc);
+    fn sub(self, duration: Duration) -> Angle {
+        _angle_plus_seconds(
+            self,
+            -_seconds_from_duration(duration),
+        )
+    }
+}
 
-    let mut hour_angle = lst_decimal - asc_decimal;
+/// Errors for parsing `Angle` from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AngleParseError {
+    /// A component is not a valid number.
+    InvalidNumber(String),
+    /// More than 3 components (or none) were given.
+    TooManyComponents(usize),
+    /// Minutes or seconds are not within `0..60`.
+    OutOfRange(String),
+}
 
-    if hour_angle < 0.0 {
-        hour_angle += 24.0;
+impl fmt::Display for AngleParseError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            AngleParseError::InvalidNumber(s) => {
+                write!(f, "Invalid number: {}", s)
+            }
+            AngleParseError::TooManyComponents(n) => {
+                write!(
+                    f,
+                    "Expected 1 to 3 components, but got {}",
+                    n
+                )
+            }
+            AngleParseError::OutOfRange(s) => {
+                write!(f, "Out of range: {}", s)
+            }
+        }
     }
-
-    angle_from_decimal_hours(hour_angle)
 }
 
-/// Given UT, hour-angle (H), and longitude
-/// (along with its direction), returns right
-/// ascension (α).
-///
-/// * `utc` - UTC
-/// * `ha` - Hour-angle (H)
-/// * `lng` - Longitude
-/// * `dir` - Direction for Longitude
-///
-/// Reference:
-/// - (Peter Duffett-Smith, p.35)
+impl std::error::Error for AngleParseError {}
+
+/// Parses sexagesimal strings such as `"18:31:27"`,
+/// `"18h31m27s"`, `"-8° 13′ 30″"`, and `"23 13 10.5"`.
+/// Minutes and seconds may be omitted. A leading sign
+/// applies to the whole quantity, and is placed on the
+/// first nonzero component (which is how
+/// `angle_from_decimal_hours` places it).
 ///
 /// Example:
 /// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::{DateTime, Timelike};
-/// use chrono::offset::Utc;
-/// use sowngwala::time::build_utc;
-/// use sowngwala::coords::{
-///   Angle,
-///   Direction,
-///   right_ascension_from_utc
-/// };
+/// use sowngwala::coords::{Angle, AngleParseError};
 ///
-/// let dir = Direction::West;
-/// let lng = 64.0;
+/// let angle: Angle = "-8° 13′ 30″".parse().unwrap();
+/// assert_eq!(angle.hour(), -8);
+/// assert_eq!(angle.minute(), 13);
+/// assert_eq!(angle.second(), 30.0);
 ///
-/// // hour-angle
-/// let ha = Angle::new(5, 51, 44.0);
-///
-/// // TODO: Do we need `zone`? Originally, no zone.
-/// let zone: i32 = 4;
-/// let nanosecond: u32 = 670_000_000;
-/// let utc: DateTime<Utc> =
-///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
+/// let angle: Angle = "-0:13:10".parse().unwrap();
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), -13);
+/// assert_eq!(angle.second(), 10.0);
 ///
-/// let asc = right_ascension_from_utc(
-///     utc,
-///     ha,
-///     lng,
-///     dir
-/// );
-/// assert_eq!(asc.hour(), 18);
-/// assert_eq!(asc.minute(), 32);
-/// assert_approx_eq!(
-///     asc.second(), // 21.229576759189968
-///     21.0,
-///     1e-1
+/// assert_eq!(
+///     "18:61:00".parse::<Angle>().unwrap_err(),
+///     AngleParseError::OutOfRange("61".into())
 /// );
 /// ```
-pub fn right_ascension_from_utc(
-    utc: DateTime<Utc>,
-    ha: Angle,
-    lng: f64,
-    dir: Direction,
-) -> Angle {
-    let gst = gst_from_utc(utc);
-    let gst_0: NaiveDateTime = NaiveDate::from_ymd(
-        utc.year(),
-        utc.month(),
-        utc.day(),
-    )
-    .and_hms_nano(
-        gst.hour(),
-        gst.minute(),
-        gst.second(),
-        gst.nanosecond(),
-    );
+impl FromStr for Angle {
+    type Err = AngleParseError;
 
-    let lst: NaiveTime =
-        lst_from_gst(gst_0, lng, dir);
-    let lst_decimal: f64 =
-        decimal_hours_from_generic_time(lst);
-    let ha_decimal: f64 =
-        decimal_hours_from_angle(ha);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (negative, body) = if let Some(rest) =
+            s.strip_prefix('-')
+        {
+            (true, rest)
+        } else if let Some(rest) = s.strip_prefix('+')
+        {
+            (false, rest)
+        } else {
+            (false, s)
+        };
 
-    let mut asc = lst_decimal - ha_decimal;
-    if asc < 0.0 {
-        asc += 24.0;
+        let parts: Vec<&str> = body
+            .split(|c: char| {
+                c.is_whitespace()
+                    || ":hmsd°'′\"″".contains(c)
+            })
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        if parts.is_empty() || parts.len() > 3 {
+            return Err(
+                AngleParseError::TooManyComponents(
+                    parts.len(),
+                ),
+            );
+        }
+
+        let int = |p: &str| -> Result<
+            i32,
+            AngleParseError,
+        > {
+            p.parse::<i32>().map_err(|_| {
+                AngleParseError::InvalidNumber(
+                    p.into(),
+                )
+            })
+        };
+
+        let hour: i32 = int(parts[0])?;
+        let min: i32 = match parts.get(1) {
+            Some(p) => int(p)?,
+            None => 0,
+        };
+        let sec: f64 = match parts.get(2) {
+            Some(p) => {
+                p.parse::<f64>().map_err(|_| {
+                    AngleParseError::InvalidNumber(
+                        (*p).into(),
+                    )
+                })?
+            }
+            None => 0.0,
+        };
+
+        if hour < 0 {
+            return Err(AngleParseError::OutOfRange(
+                parts[0].into(),
+            ));
+        }
+        if !(0..60).contains(&min) {
+            return Err(AngleParseError::OutOfRange(
+                parts[1].into(),
+            ));
+        }
+        if !(0.0..60.0).contains(&sec) {
+            return Err(AngleParseError::OutOfRange(
+                parts[2].into(),
+            ));
+        }
+
+        let sign: i8 = if negative { -1 } else { 1 };
+
+        Ok(Angle::with_sign(
+            sign,
+            hour as u32,
+            min as u32,
+            sec,
+        ))
     }
+}
 
-    angle_from_decimal_hours(asc)
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
 }
 
-/// Given equatorial coordinate with hour-angle (H),
-/// declination (δ), and observer's latitude (φ),
-/// returns altitude (a) and azimuth (A) for that of
-/// horizontal coordinate.
-///
-/// * `coord` - Equatorial coordinate (with hour-angle)
-/// * `coord.ha` - Hour-angle (H)
-/// * `coord.dec` - Declination (δ)
-/// * `lat` - Latitude (φ)
-///
-/// Reference:
-/// - (Peter Duffett-Smith, pp.36-37)
-///
-/// Example:
-/// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use sowngwala::coords::{
-///   Angle,
-///   EquaCoord2,
-///   HorizCoord,
-///   horizon_from_equatorial
-/// };
+// Geometric Coordinate
+// (`lat` is positive for North, `lng` for East)
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Coord {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+impl Coord {
+    /// Builds `Coord` out of the magnitudes and the
+    /// directions, such as "35.68° North, 64° West".
+    /// South and West make the values negative. The
+    /// signs of `lat` and `lng` themselves are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `lat_dir` is not North or South, or
+    /// `lng_dir` is not East or West. See `try_new`
+    /// for the version returning an error instead.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Coord, Direction};
+    ///
+    /// let coord = Coord::new(
+    ///     52.0,
+    ///     Direction::North,
+    ///     64.0,
+    ///     Direction::West,
+    /// );
+    /// assert_eq!(coord.latitude_signed(), 52.0);
+    /// assert_eq!(coord.longitude_signed(), -64.0);
+    /// ```
+    pub fn new(
+        lat: f64,
+        lat_dir: Direction,
+        lng: f64,
+        lng_dir: Direction,
+    ) -> Self {
+        match Coord::try_new(
+            lat, lat_dir, lng, lng_dir,
+        ) {
+            Ok(coord) => coord,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// The same as `new`, but returns
+    /// `SowngwalaError::InvalidDirection` when
+    /// `lat_dir` is not North or South, or `lng_dir` is
+    /// not East or West.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Coord, Direction};
+    /// use sowngwala::error::SowngwalaError;
+    ///
+    /// let coord = Coord::try_new(
+    ///     52.0,
+    ///     Direction::South,
+    ///     64.0,
+    ///     Direction::East,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(coord.latitude_signed(), -52.0);
+    /// assert_eq!(coord.longitude_signed(), 64.0);
+    ///
+    /// assert_eq!(
+    ///     Coord::try_new(
+    ///         52.0,
+    ///         Direction::East,
+    ///         64.0,
+    ///         Direction::East,
+    ///     )
+    ///     .err(),
+    ///     Some(SowngwalaError::InvalidDirection)
+    /// );
+    /// ```
+    pub fn try_new(
+        lat: f64,
+        lat_dir: Direction,
+        lng: f64,
+        lng_dir: Direction,
+    ) -> Result<Self, SowngwalaError> {
+        let lat: f64 =
+            match lat_dir {
+                Direction::North => lat.abs(),
+                Direction::South => -lat.abs(),
+                _ => return Err(
+                    SowngwalaError::InvalidDirection,
+                ),
+            };
+        let lng: f64 =
+            match lng_dir {
+                Direction::East => lng.abs(),
+                Direction::West => -lng.abs(),
+                _ => return Err(
+                    SowngwalaError::InvalidDirection,
+                ),
+            };
+
+        Ok(Coord { lat, lng })
+    }
+
+    /// Latitude in degrees (positive for North).
+    pub fn latitude_signed(&self) -> f64 {
+        self.lat
+    }
+
+    /// Longitude in degrees (positive for East).
+    pub fn longitude_signed(&self) -> f64 {
+        self.lng
+    }
+
+    /// Splits the signed longitude into its magnitude
+    /// and `Direction`, which is what `lst_from_gst`
+    /// and `hour_angle_from_utc` expect.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Coord, Direction};
+    ///
+    /// let coord = Coord { lat: 52.0, lng: -64.0 };
+    /// let (lng, dir) = coord.lng_with_direction();
+    ///
+    /// assert_eq!(lng, 64.0);
+    /// assert!(matches!(dir, Direction::West));
+    /// ```
+    pub fn lng_with_direction(
+        &self,
+    ) -> (f64, Direction) {
+        if self.lng < 0.0 {
+            (self.lng.abs(), Direction::West)
+        } else {
+            (self.lng, Direction::East)
+        }
+    }
+}
+
+// Observer's location and the atmosphere
+// (`height_m` above the sea level, and the pressure
+// and the temperature for refraction)
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Observer {
+    pub coord: Coord,
+    pub height_m: f64,
+    pub pressure_mbar: f64,
+    pub temp_c: f64,
+}
+
+impl Observer {
+    /// Builds `Observer` at the sea level under the
+    /// standard atmosphere (1012 mbar and 10°C). Use
+    /// the struct update syntax for the others.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Observer;
+    ///
+    /// let observer = Observer::at(35.68, 139.77);
+    /// assert_eq!(observer.height_m, 0.0);
+    /// assert_eq!(observer.pressure_mbar, 1012.0);
+    /// assert_eq!(observer.temp_c, 10.0);
+    ///
+    /// let summit = Observer {
+    ///     height_m: 3_776.0,
+    ///     pressure_mbar: 630.0,
+    ///     temp_c: -5.0,
+    ///     ..Observer::at(35.36, 138.73)
+    /// };
+    /// assert_eq!(summit.coord.lat, 35.36);
+    /// ```
+    pub fn at(lat: f64, lng: f64) -> Self {
+        Observer {
+            coord: Coord { lat, lng },
+            height_m: 0.0,
+            pressure_mbar: STANDARD_PRESSURE_MBAR,
+            temp_c: STANDARD_TEMPERATURE_C,
+        }
+    }
+
+    /// Given the true altitude (a) in degrees, returns
+    /// the refraction (R) in degrees for the observer's
+    /// atmosphere (see `refraction_from_altitude`).
+    pub fn refraction(&self, alt: f64) -> f64 {
+        refraction_from_altitude(
+            alt,
+            self.pressure_mbar,
+            self.temp_c,
+        )
+    }
+
+    /// Same as `apply_refraction`, but for the
+    /// observer's atmosphere.
+    pub fn apply_refraction(
+        &self,
+        coord: HorizCoord,
+    ) -> HorizCoord {
+        apply_refraction(
+            coord,
+            self.pressure_mbar,
+            self.temp_c,
+        )
+    }
+
+    /// Returns how much lower (in degrees) the
+    /// altitude of the rise and the set is for the
+    /// observer than for the one at the sea level under
+    /// the standard atmosphere. It is the dip of the
+    /// horizon (`dip_correction`), and the difference
+    /// of the refraction at the horizon from the
+    /// standard one.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::Observer;
+    ///
+    /// let observer = Observer::at(35.68, 139.77);
+    /// assert_eq!(observer.rise_set_correction(), 0.0);
+    ///
+    /// // Cold air bends the light more.
+    /// let cold = Observer {
+    ///     temp_c: -30.0,
+    ///     ..observer
+    /// };
+    /// assert!(cold.rise_set_correction() > 0.0);
+    /// ```
+    pub fn rise_set_correction(&self) -> f64 {
+        let standard: f64 = refraction_from_altitude(
+            0.0,
+            STANDARD_PRESSURE_MBAR,
+            STANDARD_TEMPERATURE_C,
+        );
+        dip_correction(self.height_m)
+            + (self.refraction(0.0) - standard)
+    }
+}
+
+// Ecliptic Coordinate
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct EcliCoord {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+// Galactic Coordinate
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct GalacCoord {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+// Defines a newtype of `Angle` for a specific kind
+// of angle, which dereferences to `Angle`. An `Angle`
+// becomes the kind only explicitly (with `new`), and
+// there is no `From<Angle>` (nor `Into`), so that
+// `.into()` never picks the kind for the caller.
+macro_rules! angle_kind {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub struct $name(pub Angle);
+
+        impl $name {
+            /// Makes the kind out of `Angle`.
+            pub fn new(angle: Angle) -> Self {
+                $name(angle)
+            }
+        }
+
+        impl From<$name> for Angle {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = Angle;
+
+            fn deref(&self) -> &Angle {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Angle {
+                &mut self.0
+            }
+        }
+    };
+}
+
+angle_kind!(
+    /// Right ascension (α) in hours.
+    RightAscension
+);
+
+angle_kind!(
+    /// Declination (δ) in degrees.
+    Declination
+);
+
+angle_kind!(
+    /// Hour-angle (H) in hours.
+    HourAngle
+);
+
+/// Equatorial Coordinate
 ///
-/// let lat = 52.0;
+/// Each field has its own kind of `Angle`, so that
+/// passing a declination for right ascension does
+/// not compile:
+/// ```compile_fail
+/// use sowngwala::coords::{Angle, Declination, EquaCoord};
 ///
-/// // hour-angle
-/// let ha = Angle::new(5, 51, 44.0);
+/// let dec = Declination::new(Angle::new(19, 32, 14.2));
+/// let coord = EquaCoord { asc: dec, dec };
+/// ```
 ///
-/// // declination
-/// let dec = Angle::new(23, 13, 10.0);
+/// Nor does `Angle` turn into any kind with `into()`
+/// (the kind is always spelled out with `new`):
+/// ```compile_fail
+/// use sowngwala::coords::{Angle, EquaCoord};
 ///
-/// let coord_0 = EquaCoord2 { ha, dec };
-/// let coord = horizon_from_equatorial(coord_0, lat);
-/// let alt: Angle = coord.alt;
-/// let azi: Angle = coord.azi;
+/// let coord = EquaCoord {
+///     asc: Angle::new(18, 32, 21.0).into(),
+///     dec: Angle::new(23, 13, 10.0).into(),
+/// };
+/// ```
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct EquaCoord {
+    pub asc: RightAscension, // right ascension (α)
+    pub dec: Declination,    // declination (δ)
+}
+
+/// Equatorial Coordinate (with Hour-Angle)
 ///
-/// assert_eq!(alt.hour(), 19);
-/// assert_eq!(alt.minute(), 20);
-/// assert_approx_eq!(
-///     alt.second(), // 3.6428077696939454
-///     4.0,
-///     1e-0
-/// );
+/// Right ascension is not hour-angle:
+/// ```compile_fail
+/// use sowngwala::coords::{
+///     Angle, Declination, EquaCoord2, RightAscension,
+/// };
 ///
-/// assert_eq!(azi.hour(), 283);
-/// assert_eq!(azi.minute(), 16);
-/// assert_approx_eq!(
-///     azi.second(), // 15.698162189496543
-///     16.0,
-///     1e-0
-/// );
+/// let asc = RightAscension::new(Angle::new(18, 32, 21.0));
+/// let coord = EquaCoord2 {
+///     ha: asc,
+///     dec: Declination::new(Angle::new(23, 13, 10.0)),
+/// };
 /// ```
-pub fn horizon_from_equatorial(
-    coord: EquaCoord2,
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct EquaCoord2 {
+    pub ha: HourAngle, // hour-angle (H)
+    pub dec: Declination, // declination (δ)
+}
+
+// Ecliptic coordinate
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct HorizCoord {
+    pub alt: Angle, // altitude (a)
+    pub azi: Angle, // azimuth (A)
+}
+
+// Given longitude and latitude (in degrees), returns
+// the unit vector pointing there, with x toward the
+// longitude 0°, y toward 90°, and z toward the pole.
+fn _cartesian_from_spherical(
+    lng: f64,
     lat: f64,
-) -> HorizCoord {
-    let hour_angle: f64 =
-        (decimal_hours_from_angle(coord.ha) * 15.0)
-            .to_radians();
-    let decline: f64 =
-        decimal_hours_from_angle(coord.dec)
-            .to_radians();
-    let latitude: f64 = lat.to_radians();
+) -> [f64; 3] {
+    let lng: f64 = lng.to_radians();
+    let lat: f64 = lat.to_radians();
+    [
+        lat.cos() * lng.cos(),
+        lat.cos() * lng.sin(),
+        lat.sin(),
+    ]
+}
 
-    let altitude = ((decline.sin() * latitude.sin())
-        + (decline.cos()
-            * latitude.cos()
-            * hour_angle.cos()))
-    .asin();
+// The inverse of `_cartesian_from_spherical`, which
+// returns longitude (0° to 360°) and latitude (in
+// degrees). The vector need not be a unit vector
+// (and the zero vector gives 0° for both).
+fn _spherical_from_cartesian(
+    v: [f64; 3],
+) -> (f64, f64) {
+    let [x, y, z] = v;
+    let lat: f64 = z.atan2(x.hypot(y)).to_degrees();
+    let mut lng: f64 = y.atan2(x).to_degrees();
+    if lng < 0.0 {
+        lng += 360.0;
+    }
+    (lng, lat)
+}
 
-    let mut azimuth = ((decline.sin()
-        - (latitude.sin() * altitude.sin()))
-        / (latitude.cos() * altitude.cos()))
-    .acos();
+// Whether the two angles (in arcseconds) are within
+// the tolerance of each other. When `full` (the
+// arcseconds of a full circle) is given, the
+// difference is taken the shorter way around (so
+// that 359°59'59.5" and 0° are 0.5" apart).
+fn _within_arcseconds(
+    a: f64,
+    b: f64,
+    tol_arcsec: f64,
+    full: Option<f64>,
+) -> bool {
+    let mut diff: f64 = (a - b).abs();
+    if let Some(full) = full {
+        diff = diff.rem_euclid(full);
+        diff = diff.min(full - diff);
+    }
+    diff <= tol_arcsec
+}
 
-    azimuth = if hour_angle.sin() < 0.0 {
-        azimuth
-    } else {
-        (2.0 * PI) - azimuth
-    };
+impl EquaCoord {
+    /// Returns the unit vector for the direction, with
+    /// x toward the vernal equinox (α = 0h), y toward
+    /// α = 6h, and z toward the north celestial pole.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Angle, EquaCoord, Declination, RightAscension};
+    ///
+    /// let coord = EquaCoord {
+    ///     asc: RightAscension::new(Angle::new(6, 0, 0.0)),
+    ///     dec: Declination::new(Angle::new(0, 0, 0.0)),
+    /// };
+    /// let [x, y, z] = coord.to_cartesian();
+    /// assert!(x.abs() < 1e-12);
+    /// assert!((y - 1.0).abs() < 1e-12);
+    /// assert!(z.abs() < 1e-12);
+    /// ```
+    pub fn to_cartesian(&self) -> [f64; 3] {
+        _cartesian_from_spherical(
+            decimal_hours_from_angle(self.asc) * 15.0,
+            decimal_hours_from_angle(self.dec),
+        )
+    }
 
-    HorizCoord {
-        alt: angle_from_decimal_hours(
-            altitude.to_degrees(),
-        ),
-        azi: angle_from_decimal_hours(
-            azimuth.to_degrees(),
-        ),
+    /// Given a vector (in the axes of `to_cartesian`),
+    /// returns the direction as right ascension (α)
+    /// and declination (δ).
+    pub fn from_cartesian(v: [f64; 3]) -> Self {
+        let (lng, lat) = _spherical_from_cartesian(v);
+        EquaCoord {
+            asc: RightAscension::new(
+                angle_from_decimal_hours(lng / 15.0),
+            ),
+            dec: Declination::new(
+                angle_from_decimal_hours(lat),
+            ),
+        }
+    }
+
+    /// Whether the two coordinates are within the
+    /// tolerance (in arcseconds) of each other, field
+    /// by field. Right ascension is compared in the
+    /// arcseconds of arc (15" to a second of time),
+    /// and the shorter way around 24h.
+    ///
+    /// * `other` - The other coordinate
+    /// * `tol_arcsec` - Tolerance (in arcseconds)
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Angle, EquaCoord, Declination, RightAscension};
+    ///
+    /// let coord = EquaCoord {
+    ///     asc: RightAscension::new(Angle::new(18, 32, 21.0)),
+    ///     dec: Declination::new(Angle::new(23, 13, 10.0)),
+    /// };
+    /// let near = EquaCoord {
+    ///     asc: RightAscension::new(Angle::new(18, 32, 21.0)),
+    ///     dec: Declination::new(Angle::new(23, 13, 10.5)),
+    /// };
+    /// assert!(coord.approx_eq(&near, 1.0));
+    /// assert!(!coord.approx_eq(&near, 0.1));
+    /// ```
+    pub fn approx_eq(
+        &self,
+        other: &EquaCoord,
+        tol_arcsec: f64,
+    ) -> bool {
+        _within_arcseconds(
+            self.asc.to_arcseconds() * 15.0,
+            other.asc.to_arcseconds() * 15.0,
+            tol_arcsec,
+            Some(360.0 * 3600.0),
+        ) && _within_arcseconds(
+            self.dec.to_arcseconds(),
+            other.dec.to_arcseconds(),
+            tol_arcsec,
+            None,
+        )
     }
 }
 
-/// Given altitude (a), azimuth (A), and observer's
-/// latitude (φ), returns hour-angle (H) and
-/// declination (δ) for that of equatorial coordinate.
-///
-/// * `coord` - Horizontal coordinate
-/// * `coord.alt` - Altitude (a)
-/// * `coord.azi` - Azimuth (A)
-/// * `lat` - Latitude (φ)
-///
-/// Reference:
-/// - (Peter Duffett-Smith, pp.38-39)
-///
-/// Example:
-/// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use sowngwala::coords::{
-///   Angle,
-///   EquaCoord2,
-///   HorizCoord,
-///   equatorial_from_horizon
-/// };
-///
-/// let lat = 52.0;
-///
-/// // altitude
-/// let alt = Angle::new(19, 20, 4.0);
-///
-/// // azimuth
-/// let azi = Angle::new(283, 16, 16.0);
+impl EquaCoord2 {
+    /// Same as `EquaCoord::approx_eq`, but for the
+    /// hour-angle (H) in place of right ascension.
+    pub fn approx_eq(
+        &self,
+        other: &EquaCoord2,
+        tol_arcsec: f64,
+    ) -> bool {
+        _within_arcseconds(
+            self.ha.to_arcseconds() * 15.0,
+            other.ha.to_arcseconds() * 15.0,
+            tol_arcsec,
+            Some(360.0 * 3600.0),
+        ) && _within_arcseconds(
+            self.dec.to_arcseconds(),
+            other.dec.to_arcseconds(),
+            tol_arcsec,
+            None,
+        )
+    }
+}
+
+impl EcliCoord {
+    /// Returns the unit vector for the direction, with
+    /// x toward the vernal equinox (λ = 0°), y toward
+    /// λ = 90°, and z toward the north ecliptic pole.
+    pub fn to_cartesian(&self) -> [f64; 3] {
+        _cartesian_from_spherical(self.lng, self.lat)
+    }
+
+    /// Given a vector (in the axes of `to_cartesian`),
+    /// returns the direction as ecliptic latitude (β)
+    /// and longitude (λ).
+    pub fn from_cartesian(v: [f64; 3]) -> Self {
+        let (lng, lat) = _spherical_from_cartesian(v);
+        EcliCoord { lat, lng }
+    }
+
+    /// Whether the two coordinates are within the
+    /// tolerance (in arcseconds) of each other, field
+    /// by field. Longitudes are compared the shorter
+    /// way around 360°.
+    pub fn approx_eq(
+        &self,
+        other: &EcliCoord,
+        tol_arcsec: f64,
+    ) -> bool {
+        _within_arcseconds(
+            self.lng * 3600.0,
+            other.lng * 3600.0,
+            tol_arcsec,
+            Some(360.0 * 3600.0),
+        ) && _within_arcseconds(
+            self.lat * 3600.0,
+            other.lat * 3600.0,
+            tol_arcsec,
+            None,
+        )
+    }
+}
+
+impl GalacCoord {
+    /// Returns the unit vector for the direction, with
+    /// x toward the galactic center (l = 0°), y toward
+    /// l = 90°, and z toward the north galactic pole.
+    pub fn to_cartesian(&self) -> [f64; 3] {
+        _cartesian_from_spherical(self.lng, self.lat)
+    }
+
+    /// Given a vector (in the axes of `to_cartesian`),
+    /// returns the direction as galactic latitude (b)
+    /// and longitude (l).
+    pub fn from_cartesian(v: [f64; 3]) -> Self {
+        let (lng, lat) = _spherical_from_cartesian(v);
+        GalacCoord { lat, lng }
+    }
+
+    /// Same as `EcliCoord::approx_eq`, but for the
+    /// galactic latitude (b) and longitude (l).
+    pub fn approx_eq(
+        &self,
+        other: &GalacCoord,
+        tol_arcsec: f64,
+    ) -> bool {
+        _within_arcseconds(
+            self.lng * 3600.0,
+            other.lng * 3600.0,
+            tol_arcsec,
+            Some(360.0 * 3600.0),
+        ) && _within_arcseconds(
+            self.lat * 3600.0,
+            other.lat * 3600.0,
+            tol_arcsec,
+            None,
+        )
+    }
+}
+
+impl HorizCoord {
+    /// Returns the unit vector for the direction, with
+    /// x toward the north (A = 0°), y toward the east
+    /// (A = 90°), and z toward the zenith. Mind that
+    /// the axes are left-handed, since the azimuth goes
+    /// from the north through the east.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sowngwala::coords::{Angle, HorizCoord};
+    ///
+    /// let zenith = HorizCoord {
+    ///     alt: Angle::new(90, 0, 0.0),
+    ///     azi: Angle::new(0, 0, 0.0),
+    /// };
+    /// let [x, y, z] = zenith.to_cartesian();
+    /// assert!(x.abs() < 1e-12);
+    /// assert!(y.abs() < 1e-12);
+    /// assert!((z - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn to_cartesian(&self) -> [f64; 3] {
+        _cartesian_from_spherical(
+            decimal_hours_from_angle(self.azi),
+            decimal_hours_from_angle(self.alt),
+        )
+    }
+
+    /// Given a vector (in the axes of `to_cartesian`),
+    /// returns the direction as altitude (a) and
+    /// azimuth (A).
+    pub fn from_cartesian(v: [f64; 3]) -> Self {
+        let (lng, lat) = _spherical_from_cartesian(v);
+        HorizCoord {
+            alt: angle_from_decimal_hours(lat),
+            azi: angle_from_decimal_hours(lng),
+        }
+    }
+
+    /// Whether the two coordinates are within the
+    /// tolerance (in arcseconds) of each other, field
+    /// by field. Azimuths are compared the shorter way
+    /// around 360°.
+    pub fn approx_eq(
+        &self,
+        other: &HorizCoord,
+        tol_arcsec: f64,
+    ) -> bool {
+        _within_arcseconds(
+            self.azi.to_arcseconds(),
+            other.azi.to_arcseconds(),
+            tol_arcsec,
+            Some(360.0 * 3600.0),
+        ) && _within_arcseconds(
+            self.alt.to_arcseconds(),
+            other.alt.to_arcseconds(),
+            tol_arcsec,
+            None,
+        )
+    }
+}
+
+// Whether it rises and sets on the day
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RiseSetStatus {
+    Normal,      // rises and/or sets
+    Circumpolar, // above the horizon all day
+    NeverRises,  // below the horizon all day
+}
+
+// Rise and set times (in UT)
+// (`None` when it does not rise or set on the day,
+// and `status` tells if it stays above or below the
+// horizon all day)
+#[derive(Debug, Copy, Clone)]
+pub struct RiseSet {
+    pub rise: Option<NaiveTime>,
+    pub set: Option<NaiveTime>,
+    pub status: RiseSetStatus,
+}
+
+impl RiseSet {
+    /// Given the rise and the set, returns `RiseSet`
+    /// with the status `Normal`. Either may be `None`
+    /// when the event happens on another day (as it
+    /// sometimes does for the moon).
+    ///
+    /// Example:
+    /// ```rust
+    /// use chrono::naive::NaiveTime;
+    /// use sowngwala::coords::{RiseSet, RiseSetStatus};
+    ///
+    /// let rise_set = RiseSet::new(
+    ///     Some(NaiveTime::from_hms(5, 0, 0)),
+    ///     None,
+    /// );
+    /// assert_eq!(rise_set.status, RiseSetStatus::Normal);
+    /// assert!(rise_set.set.is_none());
+    /// ```
+    pub fn new(
+        rise: Option<NaiveTime>,
+        set: Option<NaiveTime>,
+    ) -> Self {
+        RiseSet {
+            rise,
+            set,
+            status: RiseSetStatus::Normal,
+        }
+    }
+
+    /// Returns `RiseSet` for the object above the
+    /// horizon all day.
+    pub fn circumpolar() -> Self {
+        RiseSet {
+            rise: None,
+            set: None,
+            status: RiseSetStatus::Circumpolar,
+        }
+    }
+
+    /// Returns `RiseSet` for the object below the
+    /// horizon all day.
+    pub fn never_rises() -> Self {
+        RiseSet {
+            rise: None,
+            set: None,
+            status: RiseSetStatus::NeverRises,
+        }
+    }
+}
+
+/// Given UTC, right ascension (α), and longitude
+/// (along with its direction), returns
+/// hour-angle (H).
 ///
-/// let coord_0 = HorizCoord { alt, azi };
-/// let coord: EquaCoord2 =
-///     equatorial_from_horizon(coord_0, lat);
-/// let ha: Angle = coord.ha;
-/// let dec: Angle = coord.dec;
+/// * `utc` - UTC
+/// * `asc` - Right-ascension
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
 ///
-/// assert_eq!(ha.hour(), 5);
-/// assert_eq!(ha.minute(), 51);
-/// assert_approx_eq!(
-///     ha.second(), // 43.998769832229954
-///     44.0,
-///     1e-0
-/// );
+/// Reference:
+/// - (Peter Duffett-Smith, p.35)
 ///
-/// assert_eq!(dec.hour(), 23);
-/// assert_eq!(dec.minute(), 13);
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::{DateTime, Timelike};
+/// use chrono::offset::Utc;
+/// use sowngwala::time::{
+///   build_utc,
+///   angle_from_decimal_hours,
+///   decimal_hours_from_generic_time,
+///   lst_from_gst,
+/// };
+/// use sowngwala::coords::{
+///   Angle,
+///   Direction,
+///   HourAngle,
+///   RightAscension,
+///   hour_angle_from_utc
+/// };
+///
+/// let dir = Direction::West;
+/// let lng = 64.0;
+/// let asc = RightAscension::new(Angle::new(18, 32, 21.0));
+///
+/// // TODO: Do we need `zone`? Originally, no zone.
+/// let zone: i32 = 4;
+/// let nanosecond: u32 = 670_000_000;
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
+///
+/// let hour_angle: HourAngle =
+///     hour_angle_from_utc(utc, asc, lng, dir);
+///
+/// assert_eq!(hour_angle.hour(), 5);
+/// assert_eq!(hour_angle.minute(), 51);
 /// assert_approx_eq!(
-///     dec.second(), // 10.456528456985552
-///     10.0,
-///     1e-1
+///     hour_angle.second(), // 44.22957675918951
+///     44.0,
+///     1e-2
 /// );
 /// ```
-pub fn equatorial_from_horizon(
-    coord: HorizCoord,
-    lat: f64,
-) -> EquaCoord2 {
-    let altitude: f64 =
-        decimal_hours_from_angle(coord.alt)
-            .to_radians();
-    let azimuth: f64 =
-        decimal_hours_from_angle(coord.azi)
-            .to_radians();
-    let latitude: f64 = lat.to_radians();
+pub fn hour_angle_from_utc(
+    utc: DateTime<Utc>,
+    asc: RightAscension,
+    lng: f64,
+    dir: Direction,
+) -> HourAngle {
+    let lst: NaiveTime = lst_from_utc(utc, lng, dir);
+    let lst_decimal: f64 =
+        decimal_hours_from_generic_time(lst);
+    let asc_decimal: f64 =
+        decimal_hours_from_angle(asc);
 
-    let decline = ((altitude.sin() * latitude.sin())
-        + (altitude.cos()
-            * latitude.cos()
-            * azimuth.cos()))
-    .asin();
+    let mut hour_angle = lst_decimal - asc_decimal;
 
-    let mut hour_angle = ((altitude.sin()
-        - (latitude.sin() * decline.sin()))
-        / (latitude.cos() * decline.cos()))
-    .acos();
+    if hour_angle < 0.0 {
+        hour_angle += 24.0;
+    }
 
-    hour_angle = if azimuth.sin() < 0.0 {
-        hour_angle
+    HourAngle::new(angle_from_decimal_hours(
+        hour_angle,
+    ))
+}
+
+/// Same as `hour_angle_from_utc`, but takes right
+/// ascension (α) as `DecimalHours` and the longitude
+/// as `DecimalDegrees` (positive for East), and
+/// returns hour-angle (H) as `DecimalHours`. Mixing
+/// up hours and degrees does not compile.
+///
+/// * `utc` - UTC
+/// * `asc` - Right-ascension
+/// * `lng` - Longitude (positive for East)
+///
+/// # Panics
+///
+/// Panics when the hour-angle is `NaN` or infinite,
+/// which does not happen since `DecimalHours` and
+/// `DecimalDegrees` are always finite. See
+/// `try_hour_angle_from_utc_typed` for the version
+/// returning an error instead.
+///
+/// Example:
+/// ```rust
+/// use std::convert::TryFrom;
+/// use chrono::DateTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::coords::hour_angle_from_utc_typed;
+/// use sowngwala::time::build_utc;
+/// use sowngwala::units::{DecimalDegrees, DecimalHours};
+///
+/// let asc = DecimalHours::try_from(
+///     18.0 + (32.0 + 21.0 / 60.0) / 60.0,
+/// )
+/// .unwrap();
+/// let lng = DecimalDegrees::try_from(-64.0).unwrap();
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, 670_000_000);
+///
+/// let hour_angle: f64 =
+///     hour_angle_from_utc_typed(utc, asc, lng).into();
+///
+/// // 5h 51m 44s
+/// assert!((hour_angle - 5.862_284).abs() < 1e-5);
+/// ```
+pub fn hour_angle_from_utc_typed(
+    utc: DateTime<Utc>,
+    asc: DecimalHours,
+    lng: DecimalDegrees,
+) -> DecimalHours {
+    match try_hour_angle_from_utc_typed(utc, asc, lng)
+    {
+        Ok(hour_angle) => hour_angle,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Same as `hour_angle_from_utc_typed`, but returns
+/// `SowngwalaError::Unit` when the hour-angle is
+/// `NaN` or infinite.
+///
+/// Example:
+/// ```rust
+/// use std::convert::TryFrom;
+/// use chrono::DateTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::coords::{
+///     hour_angle_from_utc_typed,
+///     try_hour_angle_from_utc_typed,
+/// };
+/// use sowngwala::time::build_utc;
+/// use sowngwala::units::{DecimalDegrees, DecimalHours};
+///
+/// let asc = DecimalHours::try_from(18.5).unwrap();
+/// let lng = DecimalDegrees::try_from(-64.0).unwrap();
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, 0);
+///
+/// let hour_angle: f64 =
+///     try_hour_angle_from_utc_typed(utc, asc, lng)
+///         .unwrap()
+///         .into();
+/// let hour_angle_0: f64 =
+///     hour_angle_from_utc_typed(utc, asc, lng).into();
+/// assert_eq!(hour_angle, hour_angle_0);
+/// ```
+pub fn try_hour_angle_from_utc_typed(
+    utc: DateTime<Utc>,
+    asc: DecimalHours,
+    lng: DecimalDegrees,
+) -> Result<DecimalHours, SowngwalaError> {
+    let lng: f64 = lng.into();
+    let (lng, dir) = if lng < 0.0 {
+        (-lng, Direction::West)
     } else {
-        (2.0 * PI) - hour_angle
+        (lng, Direction::East)
     };
 
-    hour_angle /= PI / 12.0;
-
-    EquaCoord2 {
-        ha: angle_from_decimal_hours(hour_angle),
-        dec: angle_from_decimal_hours(
-            decline.to_degrees(),
+    let hour_angle: HourAngle = hour_angle_from_utc(
+        utc,
+        RightAscension::new(
+            angle_from_decimal_hours(asc.into()),
         ),
-    }
+        lng,
+        dir,
+    );
+
+    Ok(DecimalHours::try_from(
+        decimal_hours_from_angle(hour_angle),
+    )?)
 }
 
-/// Given LST and hour-angle (H), returns right
-/// ascension (α),
+/// Given UT, hour-angle (H), and longitude
+/// (along with its direction), returns right
+/// ascension (α).
 ///
-/// * `lst` - LST
+/// * `utc` - UTC
 /// * `ha` - Hour-angle (H)
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.39)
+/// - (Peter Duffett-Smith, p.35)
 ///
 /// Example:
 /// ```rust
 /// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use chrono::naive::{
-///     NaiveDate,
-///     NaiveDateTime,
-/// };
+/// use chrono::{DateTime, Timelike};
+/// use chrono::offset::Utc;
+/// use sowngwala::time::build_utc;
 /// use sowngwala::coords::{
 ///   Angle,
-///   right_ascension_from_lst_and_hour_angle,
+///   Direction,
+///   HourAngle,
+///   right_ascension_from_utc
 /// };
 ///
-/// let lst: NaiveDateTime =
-///     NaiveDate::from_ymd(1980, 4, 22)
-///         .and_hms(0, 24, 5);
+/// let dir = Direction::West;
+/// let lng = 64.0;
 ///
 /// // hour-angle
-/// let ha: Angle = Angle::new(5, 51, 44.0);
+/// let ha = HourAngle::new(Angle::new(5, 51, 44.0));
 ///
-/// let asc: Angle =
-///     right_ascension_from_lst_and_hour_angle(
-///         lst,
-///         ha
-///     );
+/// // TODO: Do we need `zone`? Originally, no zone.
+/// let zone: i32 = 4;
+/// let nanosecond: u32 = 670_000_000;
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
 ///
+/// let asc = right_ascension_from_utc(
+///     utc,
+///     ha,
+///     lng,
+///     dir
+/// );
 /// assert_eq!(asc.hour(), 18);
 /// assert_eq!(asc.minute(), 32);
 /// assert_approx_eq!(
-///     asc.second(), // 20.99999999999966
+///     asc.second(), // 21.229576759189968
 ///     21.0,
-///     1e-0
+///     1e-1
 /// );
 /// ```
-pub fn right_ascension_from_lst_and_hour_angle<T>(
-    lst: T,
-    ha: Angle,
-) -> Angle
-where
-    T: Datelike,
-    T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
-{
+pub fn right_ascension_from_utc(
+    utc: DateTime<Utc>,
+    ha: HourAngle,
+    lng: f64,
+    dir: Direction,
+) -> RightAscension {
+    let lst: NaiveTime = lst_from_utc(utc, lng, dir);
+    let lst_decimal: f64 =
+        decimal_hours_from_generic_time(lst);
     let ha_decimal: f64 =
         decimal_hours_from_angle(ha);
-    let angle = Angle::new(
-        lst.hour() as i32,
-        lst.minute() as i32,
-        lst.second() as f64,
-    );
-    let lst_decimal: f64 =
-        decimal_hours_from_angle(angle);
+
     let mut asc = lst_decimal - ha_decimal;
     if asc < 0.0 {
         asc += 24.0;
     }
 
-    angle_from_decimal_hours(asc)
+    RightAscension::new(angle_from_decimal_hours(asc))
 }
 
-/// Given ecliptic ecliptic latitude (β) and
-/// longitude (λ) (optionally takes date for specific
-/// obliquity of the ecliptic (ε)), returns right
-/// ascension (α) and declination (δ) for that of
-/// equatorial coordinate.
+/// Given equatorial coordinate with hour-angle (H),
+/// UT, and longitude (along with its direction),
+/// returns equatorial coordinate with right ascension
+/// (α). Declination (δ) is carried over as is.
 ///
-/// * `coord` - Ecliptic coordinate
-/// * `coord.lat` - Latitude (β)
-/// * `coord.lng` - Longitude (λ)
-/// * `date` - Date for specific obliquity of the eplictic (ε)
+/// * `coord` - Equatorial coordinate (with hour-angle)
+/// * `utc` - UTC
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, pp.40-41)
+/// - (Peter Duffett-Smith, p.35)
 ///
 /// Example:
 /// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use chrono::naive::NaiveDate;
-/// use sowngwala::time::decimal_hours_from_angle;
+/// use chrono::DateTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::time::build_utc;
 /// use sowngwala::coords::{
 ///   Angle,
-///   EcliCoord,
-///   equatorial_from_ecliptic_with_generic_date
+///   Declination,
+///   Direction,
+///   EquaCoord,
+///   EquaCoord2,
+///   HourAngle,
+///   equa_coord_from_equa_coord2,
 /// };
 ///
-/// let lat_0 = Angle::new(4, 52, 31.0);
-/// let lng_0 = Angle::new(139, 41, 10.0);
-///
-/// let coord_0 = EcliCoord {
-///     lat: decimal_hours_from_angle(lat_0),
-///     lng: decimal_hours_from_angle(lng_0),
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, 670_000_000);
+/// let coord = EquaCoord2 {
+///     ha: HourAngle::new(Angle::new(5, 51, 44.0)),
+///     dec: Declination::new(Angle::new(23, 13, 10.0)),
 /// };
 ///
-/// // To calculate a specific value
-/// // for mean obliquity of the ecliptic.
-/// let date = NaiveDate::from_ymd(1980, 4, 22);
-/// let coord =
-///     equatorial_from_ecliptic_with_generic_date(
-///         coord_0,
-///         date
-///     );
-/// let asc: Angle = coord.asc;
-/// let dec: Angle = coord.dec;
-///
-/// assert_eq!(asc.hour(), 9);
-/// assert_eq!(asc.minute(), 34);
-/// assert_approx_eq!(
-///     asc.second(), // 53.58216253599352
-///     53.6,
-///     1e-2
-/// );
-///
-/// assert_eq!(dec.hour(), 19);
-/// assert_eq!(dec.minute(), 32);
-/// assert_approx_eq!(
-///     dec.second(), // 14.100993558899972
-///     14.2,
-///     1e-2
+/// let coord: EquaCoord = equa_coord_from_equa_coord2(
+///     coord,
+///     utc,
+///     64.0,
+///     Direction::West,
 /// );
+/// assert_eq!(coord.asc.hour(), 18);
+/// assert_eq!(coord.asc.minute(), 32);
+/// assert_eq!(coord.dec.hour(), 23);
 /// ```
-#[allow(clippy::many_single_char_names)]
-pub fn equatorial_from_ecliptic_with_generic_date<T>(
-    coord: EcliCoord,
-    date: T,
-) -> EquaCoord
-where
-    T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
-{
-    let oblique =
-        mean_obliquity_of_the_epliptic(date)
-            .to_radians();
-
-    let oblique_cos = oblique.cos();
-    let oblique_sin = oblique.sin();
-
-    let lat = coord.lat; // latitude (β)
-    let lng = coord.lng; // longitude (λ)
-
-    let lat_cos = lat.to_radians().cos();
-    let lat_sin = lat.to_radians().sin();
-    let lat_tan = lat.to_radians().tan();
-    let lng_cos = lng.to_radians().cos();
-    let lng_sin = lng.to_radians().sin();
-
-    let decline_0 = (lat_sin * oblique_cos)
-        + (lat_cos * oblique_sin * lng_sin);
-    let decline = decline_0.asin().to_degrees();
-
-    let y = (lng_sin * oblique_cos)
-        - (lat_tan * oblique_sin);
-    let x = lng_cos;
-
-    let mut asc = y.atan2(x).to_degrees();
-    asc -= 360.0 * (asc / 360.0).floor();
-    asc /= 15.0;
-
+pub fn equa_coord_from_equa_coord2(
+    coord: EquaCoord2,
+    utc: DateTime<Utc>,
+    lng: f64,
+    dir: Direction,
+) -> EquaCoord {
     EquaCoord {
-        asc: angle_from_decimal_hours(asc),
-        dec: angle_from_decimal_hours(decline),
+        asc: right_ascension_from_utc(
+            utc, coord.ha, lng, dir,
+        ),
+        dec: coord.dec,
     }
 }
 
-pub fn equatorial_from_ecliptic(
-    coord: EcliCoord,
-) -> EquaCoord {
-    equatorial_from_ecliptic_with_generic_date(
-        coord,
-        NaiveDate::from_ymd(2021, 1, 0),
-    )
-}
-
-/// Given right ascension (α) and declination (δ) of
-/// equatorial coordinate (optionally takes date for
-/// specific obliquity of the ecliptic (ε)),
-/// returns latitude (β) and longitude (λ)
+/// Given equatorial coordinate with right ascension
+/// (α), UT, and longitude (along with its direction),
+/// returns equatorial coordinate with hour-angle (H).
+/// Declination (δ) is carried over as is.
 ///
 /// * `coord` - Equatorial coordinate
-/// * `coord.asc` - Right ascension (α)
-/// * `coord.dec` - Declination (δ)
-/// * `date` - Date for specific obliquity of the eplictic (ε)
+/// * `utc` - UTC
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.42)
+/// - (Peter Duffett-Smith, p.35)
 ///
 /// Example:
 /// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use chrono::naive::{
-///     NaiveDate,
-///     NaiveDateTime,
-/// };
-/// use sowngwala::time::angle_from_decimal_hours;
+/// use chrono::DateTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::time::build_utc;
 /// use sowngwala::coords::{
 ///   Angle,
-///   EcliCoord,
+///   Declination,
+///   Direction,
 ///   EquaCoord,
-///   ecliptic_from_equatorial_with_generic_date,
+///   EquaCoord2,
+///   RightAscension,
+///   equa_coord2_from_equa_coord,
 /// };
 ///
-/// // right-ascension
-/// let asc: Angle = Angle::new(9, 34, 53.6);
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, 670_000_000);
+/// let coord = EquaCoord {
+///     asc: RightAscension::new(Angle::new(18, 32, 21.0)),
+///     dec: Declination::new(Angle::new(23, 13, 10.0)),
+/// };
 ///
-/// // declination
-/// let dec: Angle = Angle::new(19, 32, 14.2);
+/// let coord: EquaCoord2 = equa_coord2_from_equa_coord(
+///     coord,
+///     utc,
+///     64.0,
+///     Direction::West,
+/// );
+/// assert_eq!(coord.ha.hour(), 5);
+/// assert_eq!(coord.ha.minute(), 51);
+/// assert_eq!(coord.dec.hour(), 23);
+/// ```
+pub fn equa_coord2_from_equa_coord(
+    coord: EquaCoord,
+    utc: DateTime<Utc>,
+    lng: f64,
+    dir: Direction,
+) -> EquaCoord2 {
+    EquaCoord2 {
+        ha: hour_angle_from_utc(
+            utc, coord.asc, lng, dir,
+        ),
+        dec: coord.dec,
+    }
+}
+
+/// Given right ascension (α), declination (δ), date,
+/// and observer's coordinate, returns UT of the
+/// transit (when LST equals α) and the altitude at
+/// the transit (90° - |φ - δ|). Since a sidereal day
+/// is shorter than a solar day, an object may transit
+/// twice on the same date (a few minutes after 0h,
+/// and again shortly before 24h), in which case the
+/// earlier one is returned. The altitude is negative
+/// when the object stays below the horizon.
 ///
-/// // To calculate a specific value for mean obliquity
-/// // of the ecliptic.
-/// let date = NaiveDate::from_ymd(1980, 4, 22);
-/// let coord_0 = EquaCoord { asc, dec };
+/// * `asc` - Right ascension (α)
+/// * `dec` - Declination (δ)
+/// * `date` - Date (in UT)
+/// * `coord` - Observer's coordinate
 ///
-/// let coord: EcliCoord =
-///     ecliptic_from_equatorial_with_generic_date(
-///         coord_0,
-///         date
-///     );
-/// let lat: Angle =
-///     angle_from_decimal_hours(coord.lat);
-/// let lng: Angle =
-///     angle_from_decimal_hours(coord.lng);
+/// Reference:
+/// - (Peter Duffett-Smith, pp.18-20)
 ///
-/// assert_eq!(lat.hour(), 4);
-/// assert_eq!(lat.minute(), 52);
-/// assert_approx_eq!(
-///     lat.second(), // 31.17490012745307
-///     31.0,
-///     1e-2
-/// );
+/// Example:
+/// ```rust
+/// use chrono::Timelike;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///     transit_from_date, Angle, Coord, Declination,
+///     RightAscension,
+/// };
 ///
-/// assert_eq!(lng.hour(), 139);
-/// assert_eq!(lng.minute(), 41);
-/// assert_approx_eq!(
-///     lng.second(), // 10.207621429881328
-///     10.0,
-///     3e-2
-/// );
+/// // Sirius from Tokyo
+/// let asc = RightAscension::new(Angle::new(6, 45, 8.9));
+/// let dec = Declination::new(Angle::new(-16, 42, 58.0));
+/// let coord = Coord { lat: 35.68, lng: 139.65 };
+/// let date = NaiveDate::from_ymd(2022, 1, 1);
+///
+/// let (utc, alt) = transit_from_date(asc, dec, date, coord);
+/// assert_eq!(utc.hour(), 14);
+/// assert_eq!(utc.minute(), 41);
+///
+/// // 90° - |35.68° - (-16.716°)| = 37.604°
+/// assert_eq!(alt.hour(), 37);
+/// assert_eq!(alt.minute(), 36);
 /// ```
-#[allow(clippy::many_single_char_names)]
-pub fn ecliptic_from_equatorial_with_generic_date<T>(
-    coord: EquaCoord,
-    date: T,
-) -> EcliCoord
-where
-    T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
-{
-    let oblique: f64 =
-        mean_obliquity_of_the_epliptic(date)
-            .to_radians();
-    let oblique_cos: f64 = oblique.cos();
-    let oblique_sin: f64 = oblique.sin();
+pub fn transit_from_date(
+    asc: RightAscension,
+    dec: Declination,
+    date: NaiveDate,
+    coord: Coord,
+) -> (NaiveTime, Angle) {
+    let lst: NaiveDateTime =
+        date.and_time(asc.0.into());
+    let (lng, dir) = coord.lng_with_direction();
+    let gst: NaiveTime = gst_from_lst(lst, lng, dir);
+    let utc: NaiveTime =
+        utc_from_gst(date.and_time(gst));
 
-    // right ascension (α)
-    let mut asc_decimal: f64 =
-        decimal_hours_from_angle(coord.asc)
-            .to_radians();
+    let alt: f64 = 90.0
+        - (coord.lat - decimal_hours_from_angle(dec))
+            .abs();
 
-    // declination (δ)
-    let dec_decimal: f64 =
-        decimal_hours_from_angle(coord.dec)
-            .to_radians();
-    asc_decimal *= 15.0;
+    (utc, angle_from_decimal_hours(alt))
+}
 
-    let asc_sin: f64 = asc_decimal.sin();
-    let asc_cos: f64 = asc_decimal.cos();
-    let dec_sin: f64 = dec_decimal.sin();
-    let dec_cos: f64 = dec_decimal.cos();
-    let dec_tan: f64 = dec_decimal.tan();
+/// Given a list of named objects (α and δ), date, and
+/// observer's coordinate, returns the name, UT of the
+/// transit, and the altitude at the transit of each
+/// object (see `transit_from_date`), sorted by the
+/// altitude (the highest first). Objects which stay
+/// below the horizon at the transit (thus never seen
+/// that night) are excluded.
+///
+/// * `objects` - Names and equatorial coordinates
+/// * `date` - Date (in UT)
+/// * `coord` - Observer's coordinate
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///     best_transit_tonight, Angle, Coord, EquaCoord,
+///     Declination, RightAscension,
+/// };
+///
+/// let objects = vec![
+///     (
+///         "Sirius".to_string(),
+///         EquaCoord {
+///             asc: RightAscension::new(Angle::new(6, 45, 8.9)),
+///             dec: Declination::new(Angle::new(-16, 42, 58.0)),
+///         },
+///     ),
+///     (
+///         "Capella".to_string(),
+///         EquaCoord {
+///             asc: RightAscension::new(Angle::new(5, 16, 41.4)),
+///             dec: Declination::new(Angle::new(45, 59, 53.0)),
+///         },
+///     ),
+/// ];
+/// let coord = Coord { lat: 35.68, lng: 139.65 };
+/// let date = NaiveDate::from_ymd(2022, 1, 1);
+///
+/// let best = best_transit_tonight(&objects, date, coord);
+/// assert_eq!(best[0].0, "Capella");
+/// assert_eq!(best[1].0, "Sirius");
+/// ```
+pub fn best_transit_tonight(
+    objects: &[(String, EquaCoord)],
+    date: NaiveDate,
+    coord: Coord,
+) -> Vec<(String, NaiveTime, Angle)> {
+    let mut transits: Vec<(
+        String,
+        NaiveTime,
+        Angle,
+    )> = objects
+        .iter()
+        .map(|(name, equa)| {
+            let (utc, alt) = transit_from_date(
+                equa.asc, equa.dec, date, coord,
+            );
+            (name.clone(), utc, alt)
+        })
+        .filter(|(_, _, alt)| {
+            alt.to_arcseconds() >= 0.0
+        })
+        .collect();
 
-    let lat_0: f64 = (dec_sin * oblique_cos)
-        - (dec_cos * oblique_sin * asc_sin);
-    let lat: f64 = lat_0.asin().to_degrees();
+    transits.sort_by(|a, b| {
+        b.2.partial_cmp(&a.2)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
 
-    let y: f64 = (asc_sin * oblique_cos)
-        + (dec_tan * oblique_sin);
-    let x: f64 = asc_cos;
+    transits
+}
 
-    let mut lng: f64 = y.atan2(x).to_degrees();
-    lng -= 360.0 * (lng / 360.0).floor();
+/// Given the observer's height above the sea level
+/// (in meters), returns the dip of the horizon (in
+/// degrees), which is how far the sea horizon is
+/// depressed below the astronomical horizon (≈ 1.76'
+/// √h). Refraction is included in the coefficient.
+/// Subtract it from the altitude of the rise and the
+/// set to get those for the observer at the height.
+///
+/// * `height_m` - Height of the observer (in meters)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::dip_correction;
+///
+/// assert_eq!(dip_correction(0.0), 0.0);
+/// // At 100 m, the horizon is some 18' down.
+/// assert_approx_eq!(dip_correction(100.0), 0.293, 1e-3);
+/// // Below the sea level, there is no dip.
+/// assert_eq!(dip_correction(-10.0), 0.0);
+/// ```
+pub fn dip_correction(height_m: f64) -> f64 {
+    if height_m <= 0.0 {
+        return 0.0;
+    }
+    0.0293 * height_m.sqrt()
+}
 
-    EcliCoord { lat, lng }
+// Whether an object of a declination is ever seen
+// at a latitude
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    Circumpolar, // never sets
+    Sometimes,   // rises and sets
+    NeverRises,  // never seen
 }
 
-/// Given right ascension (α) and declination (δ) of
-/// equatorial coordinate, returns galactic
-/// latitude (b) and galactic longitude (l).
+/// Given declination (δ) and observer's latitude (φ),
+/// returns whether the object is circumpolar
+/// (δ > 90 - φ), never rises (δ < φ - 90), or rises
+/// and sets. For the southern hemisphere, the sign of
+/// δ is flipped. Refraction and parallax are not
+/// considered.
 ///
-/// * `coord` - Equatorial coordinate
-/// * `coord.asc` - Right ascension (α)
-/// * `coord.dec` - Declination (δ)
+/// * `dec` - Declination (δ)
+/// * `lat` - Latitude (φ)
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.43)
+/// - (Peter Duffett-Smith, p.49)
 ///
 /// Example:
 /// ```rust
-/// use approx_eq::assert_approx_eq;
-/// use chrono::Timelike;
-/// use chrono::naive::{
-///     NaiveDate,
-///     NaiveDateTime,
-/// };
-/// use sowngwala::time::angle_from_decimal_hours;
 /// use sowngwala::coords::{
-///   Angle,
-///   EquaCoord,
-///   GalacCoord,
-///   galactic_from_equatorial,
+///     visibility, Angle, Declination, Visibility,
 /// };
 ///
-/// // right-ascension
-/// let asc: Angle = Angle::new(10, 21, 0.0);
+/// let polaris = Declination::new(Angle::new(89, 15, 51.0));
+/// let canopus = Declination::new(Angle::new(-52, 41, 44.0));
 ///
-/// // declination
-/// let dec: Angle = Angle::new(10, 3, 11.0);
+/// // Polaris from Tokyo
+/// assert_eq!(
+///     visibility(polaris, 35.68),
+///     Visibility::Circumpolar
+/// );
+/// // Canopus from Tokyo
+/// assert_eq!(
+///     visibility(canopus, 35.68),
+///     Visibility::Sometimes
+/// );
+/// // Canopus from London
+/// assert_eq!(
+///     visibility(canopus, 51.5),
+///     Visibility::NeverRises
+/// );
+/// ```
+pub fn visibility(
+    dec: Declination,
+    lat: f64,
+) -> Visibility {
+    let mut dec: f64 = decimal_hours_from_angle(dec);
+    if lat < 0.0 {
+        dec = -dec;
+    }
+    let lat: f64 = lat.abs();
+
+    if dec > 90.0 - lat {
+        Visibility::Circumpolar
+    } else if dec < lat - 90.0 {
+        Visibility::NeverRises
+    } else {
+        Visibility::Sometimes
+    }
+}
+
+/// Given declination (δ) and observer's latitude (φ),
+/// returns the azimuths (A) of rising and setting.
+/// Returns `None` when the object is circumpolar, or
+/// never rises (|tan φ tan δ| > 1). Refraction and
+/// parallax are not considered (for them, see
+/// `rise_set_azimuth_at_altitude`).
 ///
-/// let coord_0 = EquaCoord { asc, dec };
+/// * `dec` - Declination (δ)
+/// * `lat` - Latitude (φ)
 ///
-/// let coord: GalacCoord =
-///     galactic_from_equatorial(coord_0);
-/// let lat: Angle =
-///     angle_from_decimal_hours(coord.lat);
-/// let lng: Angle =
-///     angle_from_decimal_hours(coord.lng);
+/// Reference:
+/// - (Peter Duffett-Smith, p.49)
 ///
-/// assert_eq!(lat.hour(), 51);
-/// assert_eq!(lat.minute(), 7);
-/// assert_approx_eq!(
-///     lat.second(), // 20.16407768754391
-///     20.0,
-///     1e-2
-/// );
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::{Angle, Declination, rise_set_azimuth};
+/// use sowngwala::time::decimal_hours_from_angle;
 ///
-/// assert_eq!(lng.hour(), 232);
-/// assert_eq!(lng.minute(), 14);
-/// assert_approx_eq!(
-///     lng.second(), // 52.38055557683538
-///     52.0,
-///     1e-2
-/// );
+/// // Betelgeuse from latitude 30° N
+/// let dec = Declination::new(Angle::new(7, 24, 25.0));
+/// let (rise, set) = rise_set_azimuth(dec, 30.0).unwrap();
+///
+/// let rise = decimal_hours_from_angle(rise);
+/// let set = decimal_hours_from_angle(set);
+/// assert_approx_eq!(rise, 81.43, 1e-3);
+/// assert_approx_eq!(set, 360.0 - rise, 1e-12);
 /// ```
-#[allow(clippy::many_single_char_names)]
-pub fn galactic_from_equatorial(
-    coord: EquaCoord,
-) -> GalacCoord {
-    // right ascension (α)
-    let mut asc_decimal: f64 =
-        decimal_hours_from_angle(coord.asc)
-            .to_radians();
-
-    // declination (δ)
-    let dec_decimal: f64 =
-        decimal_hours_from_angle(coord.dec)
-            .to_radians();
-    asc_decimal *= 15.0;
+pub fn rise_set_azimuth(
+    dec: Declination,
+    lat: f64,
+) -> Option<(Angle, Angle)> {
+    // Circumpolar (or never rises)
+    if visibility(dec, lat) != Visibility::Sometimes {
+        return None;
+    }
 
-    let dec_sin: f64 = dec_decimal.sin();
-    let dec_cos: f64 = dec_decimal.cos();
-    let asc_192: f64 = (asc_decimal.to_degrees()
-        - 192.25)
-        .to_radians();
+    rise_set_azimuth_at_altitude(dec, lat, 0.0)
+}
 
-    let r_27: f64 = (27.4_f64).to_radians();
-    let r_27_sin: f64 = r_27.sin();
-    let r_27_cos: f64 = r_27.cos();
+/// Same as `rise_set_azimuth`, but for when the object
+/// is at the given altitude (h0) instead of on the
+/// horizon, such as -0.833° for the sun's rise and set
+/// times (which take in the refraction and the
+/// semidiameter). Returns `None` when the object does
+/// not get to the altitude, or stays above it.
+///
+/// cos A = (sin δ - sin φ sin h0) / (cos φ cos h0)
+///
+/// * `dec` - Declination (δ)
+/// * `lat` - Latitude (φ)
+/// * `alt` - Altitude (h0) in degrees
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.49)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{
+///     rise_set_azimuth, rise_set_azimuth_at_altitude,
+///     Angle, Declination,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // On the celestial equator, from latitude 60° N,
+/// // the object is 1.44° north of due east when it
+/// // is 0.833° below the horizon.
+/// let dec = Declination::new(Angle::new(0, 0, 0.0));
+/// let (rise, _) =
+///     rise_set_azimuth_at_altitude(dec, 60.0, -0.833)
+///         .unwrap();
+/// let rise = decimal_hours_from_angle(rise);
+/// assert!((rise - 88.557).abs() < 1e-3);
+///
+/// // At 0°, the same as `rise_set_azimuth`
+/// let (rise_0, _) =
+///     rise_set_azimuth_at_altitude(dec, 60.0, 0.0)
+///         .unwrap();
+/// let (rise_1, _) = rise_set_azimuth(dec, 60.0).unwrap();
+/// assert_eq!(
+///     decimal_hours_from_angle(rise_0),
+///     decimal_hours_from_angle(rise_1)
+/// );
+/// ```
+pub fn rise_set_azimuth_at_altitude(
+    dec: Declination,
+    lat: f64,
+    alt: f64,
+) -> Option<(Angle, Angle)> {
+    let dec: f64 =
+        decimal_hours_from_angle(dec).to_radians();
+    let lat: f64 = lat.to_radians();
+    let alt: f64 = alt.to_radians();
 
-    let b_sin: f64 =
-        dec_cos * r_27.cos() * asc_192.cos()
-            + (dec_sin * r_27_sin);
-    let b: f64 = b_sin.asin();
+    let cos_az: f64 = (dec.sin()
+        - lat.sin() * alt.sin())
+        / (lat.cos() * alt.cos());
 
-    let y: f64 = dec_sin - (b_sin * r_27_sin);
-    let x: f64 = dec_cos * asc_192.sin() * r_27_cos;
+    // Does not get to the altitude (or stays above)
+    if !(-1.0..=1.0).contains(&cos_az) {
+        return None;
+    }
 
-    let mut l: f64 = y.atan2(x).to_degrees();
-    l -= 360.0 * (l / 360.0).floor();
-    l += 33.0;
+    let rise: f64 = cos_az.acos().to_degrees();
+    let set: f64 = 360.0 - rise;
 
-    GalacCoord {
-        lat: b.to_degrees(),
-        lng: l,
-    }
+    Some((
+        angle_from_decimal_hours(rise),
+        angle_from_decimal_hours(set),
+    ))
 }
 
-/// Given galactic latitude (b) and galactic
-/// longitude (l), returns right ascension (α)
-/// and declination (δ) of equatorial coordinate,
+/// Given equatorial coordinate with hour-angle (H),
+/// declination (δ), and observer's latitude (φ),
+/// returns altitude (a) and azimuth (A) for that of
+/// horizontal coordinate.
 ///
-/// * `coord` - Galactic coordinate
-/// * `coord.lat` - Galactic latitude (b)
-/// * `coord.lng` - Galactic longitude (l)
+/// * `coord` - Equatorial coordinate (with hour-angle)
+/// * `coord.ha` - Hour-angle (H)
+/// * `coord.dec` - Declination (δ)
+/// * `lat` - Latitude (φ)
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.44)
+/// - (Peter Duffett-Smith, pp.36-37)
 ///
 /// Example:
 /// ```rust
 /// use approx_eq::assert_approx_eq;
 /// use chrono::Timelike;
-/// use chrono::naive::{
-///     NaiveDate,
-///     NaiveDateTime,
-/// };
-/// use sowngwala::time::decimal_hours_from_angle;
 /// use sowngwala::coords::{
 ///   Angle,
-///   EquaCoord,
-///   GalacCoord,
-///   equatorial_from_galactic
+///   Declination,
+///   EquaCoord2,
+///   HorizCoord,
+///   HourAngle,
+///   horizon_from_equatorial,
 /// };
 ///
-/// // Galactic latitude (b)
-/// let lat: Angle = Angle::new(51, 7, 20.0);
+/// let lat = 52.0;
 ///
-/// // Galactic latitude (l)
-/// let lng: Angle = Angle::new(232, 14, 52.0);
+/// // hour-angle
+/// let ha = Angle::new(5, 51, 44.0);
 ///
-/// let coord_0 = GalacCoord {
-///     lat: decimal_hours_from_angle(lat),
-///     lng: decimal_hours_from_angle(lng),
-/// };
+/// // declination
+/// let dec = Angle::new(23, 13, 10.0);
 ///
-/// let coord: EquaCoord =
-///     equatorial_from_galactic(coord_0);
-/// let asc: Angle = coord.asc;
-/// let dec: Angle = coord.dec;
+/// let coord_0 = EquaCoord2 {
+///     ha: HourAngle::new(ha),
+///     dec: Declination::new(dec),
+/// };
+/// let coord = horizon_from_equatorial(coord_0, lat);
+/// let alt: Angle = coord.alt;
+/// let azi: Angle = coord.azi;
 ///
-/// assert_eq!(asc.hour(), 10);
-/// // TODO:
-/// // The book tells it should be 21 for 'asc.min'.
-/// assert_eq!(asc.minute(), 20);
+/// assert_eq!(alt.hour(), 19);
+/// assert_eq!(alt.minute(), 20);
 /// assert_approx_eq!(
-///     asc.second(), // 59.98205693746215
-///     59.9,
-///     1e-2
+///     alt.second(), // 3.6428077696939454
+///     4.0,
+///     1e-0
 /// );
 ///
-/// assert_eq!(dec.hour(), 10);
-/// assert_eq!(dec.minute(), 3);
+/// assert_eq!(azi.hour(), 283);
+/// assert_eq!(azi.minute(), 16);
 /// assert_approx_eq!(
-///     dec.second(), // 11.117231829019829
-///     11.0,
-///     2e-2
+///     azi.second(), // 15.698162189496543
+///     16.0,
+///     1e-0
 /// );
 /// ```
-#[allow(clippy::many_single_char_names)]
-pub fn equatorial_from_galactic(
-    coord: GalacCoord,
-) -> EquaCoord {
-    let b: f64 = coord.lat.to_radians(); // // Galactic latitude (b)
-    let l: f64 = coord.lng.to_radians(); // Galactic longitude (l)
-    let b_sin: f64 = b.sin();
-    let b_cos: f64 = b.cos();
-
-    let l_minus_33: f64 =
-        (l.to_degrees() - 33.0).to_radians();
-    let l_minus_33_sin: f64 = l_minus_33.sin();
-    let l_minus_33_cos: f64 = l_minus_33.cos();
-
-    let r_27: f64 = (27.4_f64).to_radians();
-    let r_27_sin: f64 = r_27.sin();
-    let r_27_cos: f64 = r_27.cos();
+pub fn horizon_from_equatorial(
+    coord: EquaCoord2,
+    lat: f64,
+) -> HorizCoord {
+    let hour_angle: f64 =
+        (decimal_hours_from_angle(coord.ha) * 15.0)
+            .to_radians();
+    let decline: f64 =
+        decimal_hours_from_angle(coord.dec)
+            .to_radians();
+    let latitude: f64 = lat.to_radians();
 
-    let dec_sin: f64 =
-        (b_cos * r_27_cos * l_minus_33_sin)
-            + (b_sin * r_27_sin);
-    let dec: f64 = dec_sin.asin();
+    let altitude = ((decline.sin() * latitude.sin())
+        + (decline.cos()
+            * latitude.cos()
+            * hour_angle.cos()))
+    .asin();
 
-    let y: f64 = b_cos * l_minus_33_cos;
-    let x: f64 = (b_sin * r_27_cos)
-        - (b_cos * r_27_sin * l_minus_33_sin);
+    let mut azimuth = ((decline.sin()
+        - (latitude.sin() * altitude.sin()))
+        / (latitude.cos() * altitude.cos()))
+    .acos();
 
-    let mut asc: f64 = y.atan2(x).to_degrees();
-    asc += 192.25;
-    asc -= 360.0 * (asc / 360.0).floor();
-    asc /= 15.0;
+    azimuth = if hour_angle.sin() < 0.0 {
+        azimuth
+    } else {
+        (2.0 * PI) - azimuth
+    };
 
-    EquaCoord {
-        asc: angle_from_decimal_hours(asc),
-        dec: angle_from_decimal_hours(
-            dec.to_degrees(),
+    HorizCoord {
+        alt: angle_from_decimal_hours(
+            altitude.to_degrees(),
+        ),
+        azi: angle_from_decimal_hours(
+            azimuth.to_degrees(),
         ),
     }
 }
 
-/// Given coordinates for two celestial objects
-/// expressed in ecliptic coordinate system
-/// (latitude (β) and longitude (λ)), returns
-/// the angle between them.
+/// Given altitude (a), azimuth (A), and observer's
+/// latitude (φ), returns hour-angle (H) and
+/// declination (δ) for that of equatorial coordinate.
 ///
-/// * `coord_0` - Equatorial coordinate
-/// * `coord_0.asc` - Right ascension (α)
-/// * `coord_0.dec` - Declination (δ)
-/// * `coord_1` - Equatorial coordinate
-/// * `coord_1.asc` - Right ascension (α)
-/// * `coord_1.dec` - Declination (δ)
+/// * `coord` - Horizontal coordinate
+/// * `coord.alt` - Altitude (a)
+/// * `coord.azi` - Azimuth (A)
+/// * `lat` - Latitude (φ)
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.51)
+/// - (Peter Duffett-Smith, pp.38-39)
 ///
 /// Example:
 /// ```rust
 /// use approx_eq::assert_approx_eq;
 /// use chrono::Timelike;
-/// use chrono::naive::{
-///     NaiveDate,
-///     NaiveDateTime,
-/// };
 /// use sowngwala::coords::{
 ///   Angle,
-///   EquaCoord,
-///   angle_between_two_celestial_objects_for_equatorial
+///   EquaCoord2,
+///   HorizCoord,
+///   equatorial_from_horizon
 /// };
 ///
-/// // right-ascension (for Beta Orionis)
-/// let asc_0: Angle = Angle::new(5, 13, 31.7);
-///
-/// // declination (for Beta Orionis)
-/// let dec_0: Angle = Angle::new(-8, 13, 30.0);
+/// let lat = 52.0;
 ///
-/// // right-ascension (for Canis Majoris)
-/// let asc_1: Angle = Angle::new(6, 44, 13.4);
+/// // altitude
+/// let alt = Angle::new(19, 20, 4.0);
 ///
-/// // declination (for Canis Majoris)
-/// let dec_1: Angle = Angle::new(-16, 41, 11.0);
+/// // azimuth
+/// let azi = Angle::new(283, 16, 16.0);
 ///
-/// let angle: f64 =
-///     angle_between_two_celestial_objects_for_equatorial(
-///         EquaCoord { asc: asc_0, dec: dec_0 },
-///         EquaCoord { asc: asc_1, dec: dec_1 }
-///     );
+/// let coord_0 = HorizCoord { alt, azi };
+/// let coord: EquaCoord2 =
+///     equatorial_from_horizon(coord_0, lat);
+/// let ha: Angle = coord.ha.into();
+/// let dec: Angle = coord.dec.into();
 ///
+/// assert_eq!(ha.hour(), 5);
+/// assert_eq!(ha.minute(), 51);
 /// assert_approx_eq!(
-///     angle, // 23.67384942216419
-///     23.673_850,
-///     1e-6
+///     ha.second(), // 43.998769832229954
+///     44.0,
+///     1e-0
 /// );
-/// ```
-#[allow(clippy::many_single_char_names)]
-pub fn angle_between_two_celestial_objects_for_equatorial(
-    coord_0: EquaCoord,
-    coord_1: EquaCoord,
-) -> f64 {
-    angle_between_two_celestial_objects(
-        decimal_hours_from_angle(coord_0.asc),
-        decimal_hours_from_angle(coord_0.dec),
-        decimal_hours_from_angle(coord_1.asc),
-        decimal_hours_from_angle(coord_1.dec),
-    )
+///
+/// assert_eq!(dec.hour(), 23);
+/// assert_eq!(dec.minute(), 13);
+/// assert_approx_eq!(
+///     dec.second(), // 10.456528456985552
+///     10.0,
+///     1e-1
+/// );
+/// ```
+pub fn equatorial_from_horizon(
+    coord: HorizCoord,
+    lat: f64,
+) -> EquaCoord2 {
+    let altitude: f64 =
+        decimal_hours_from_angle(coord.alt)
+            .to_radians();
+    let azimuth: f64 =
+        decimal_hours_from_angle(coord.azi)
+            .to_radians();
+    let latitude: f64 = lat.to_radians();
+
+    let decline = ((altitude.sin() * latitude.sin())
+        + (altitude.cos()
+            * latitude.cos()
+            * azimuth.cos()))
+    .asin();
+
+    let mut hour_angle = ((altitude.sin()
+        - (latitude.sin() * decline.sin()))
+        / (latitude.cos() * decline.cos()))
+    .acos();
+
+    hour_angle = if azimuth.sin() < 0.0 {
+        hour_angle
+    } else {
+        (2.0 * PI) - hour_angle
+    };
+
+    hour_angle /= PI / 12.0;
+
+    EquaCoord2 {
+        ha: HourAngle::new(angle_from_decimal_hours(
+            hour_angle,
+        )),
+        dec: Declination::new(
+            angle_from_decimal_hours(
+                decline.to_degrees(),
+            ),
+        ),
+    }
 }
 
-/// Given coordinates for two celestial objects
-/// expressed in galactic coordinate system
-/// (latitude (b) and longitude (l)), returns
-/// the angle between them.
+/// Given LST and hour-angle (H), returns right
+/// ascension (α),
 ///
-/// * `coord_0` - Galactic coordinate
-/// * `coord_0.lat` - Galactic latitude (b)
-/// * `coord_0.lng` - Galactic longitude (l)
-/// * `coord_1` - Galactic coordinate
-/// * `coord_1.lat` - Galactic latitude (b)
-/// * `coord_1.lng` - Galactic longitude (l)
+/// * `lst` - LST
+/// * `ha` - Hour-angle (H)
 ///
 /// Reference:
-/// - (Peter Duffett-Smith, p.51)
-#[allow(clippy::many_single_char_names)]
-pub fn angle_between_two_celestial_objects_for_galactic(
-    coord_0: GalacCoord,
-    coord_1: GalacCoord,
-) -> f64 {
-    angle_between_two_celestial_objects(
-        coord_0.lat,
-        coord_0.lng,
-        coord_1.lat,
-        coord_1.lng,
-    )
+/// - (Peter Duffett-Smith, p.39)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::coords::{
+///   Angle,
+///   HourAngle,
+///   RightAscension,
+///   right_ascension_from_lst_and_hour_angle,
+/// };
+///
+/// let lst: NaiveDateTime =
+///     NaiveDate::from_ymd(1980, 4, 22)
+///         .and_hms(0, 24, 5);
+///
+/// // hour-angle
+/// let ha = HourAngle::new(Angle::new(5, 51, 44.0));
+///
+/// let asc: RightAscension =
+///     right_ascension_from_lst_and_hour_angle(
+///         lst,
+///         ha
+///     );
+///
+/// assert_eq!(asc.hour(), 18);
+/// assert_eq!(asc.minute(), 32);
+/// assert_approx_eq!(
+///     asc.second(), // 20.99999999999966
+///     21.0,
+///     1e-0
+/// );
+/// ```
+pub fn right_ascension_from_lst_and_hour_angle<T>(
+    lst: T,
+    ha: HourAngle,
+) -> RightAscension
+where
+    T: Datelike,
+    T: Timelike,
+{
+    let ha_decimal: f64 =
+        decimal_hours_from_angle(ha);
+    let angle = Angle::new(
+        lst.hour() as i32,
+        lst.minute() as i32,
+        lst.second() as f64,
+    );
+    let lst_decimal: f64 =
+        decimal_hours_from_angle(angle);
+    let mut asc = lst_decimal - ha_decimal;
+    if asc < 0.0 {
+        asc += 24.0;
+    }
+
+    RightAscension::new(angle_from_decimal_hours(asc))
+}
+
+/// Given LST and right ascension (α), returns
+/// hour-angle (H = LST - α), wrapped into 0h to 24h.
+/// This is the inverse of
+/// `right_ascension_from_lst_and_hour_angle`, and
+/// unlike `hour_angle_from_utc`, needs neither UTC nor
+/// the observer's longitude.
+///
+/// * `lst` - LST
+/// * `asc` - Right ascension (α)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.35)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::coords::{
+///   Angle,
+///   HourAngle,
+///   RightAscension,
+///   hour_angle_from_lst_and_right_ascension,
+/// };
+///
+/// let lst: NaiveDateTime =
+///     NaiveDate::from_ymd(1980, 4, 22)
+///         .and_hms(0, 24, 5);
+///
+/// // right ascension
+/// let asc = RightAscension::new(Angle::new(18, 32, 21.0));
+///
+/// let ha: HourAngle =
+///     hour_angle_from_lst_and_right_ascension(
+///         lst,
+///         asc
+///     );
+///
+/// assert_eq!(ha.hour(), 5);
+/// assert_eq!(ha.minute(), 51);
+/// assert_approx_eq!(
+///     ha.second(),
+///     44.0,
+///     1e-6
+/// );
+/// ```
+pub fn hour_angle_from_lst_and_right_ascension<T>(
+    lst: T,
+    asc: RightAscension,
+) -> HourAngle
+where
+    T: Datelike,
+    T: Timelike,
+{
+    let asc_decimal: f64 =
+        decimal_hours_from_angle(asc);
+    let lst_decimal: f64 =
+        decimal_hours_from_generic_time(lst);
+    let mut ha = lst_decimal - asc_decimal;
+    ha -= 24.0 * (ha / 24.0).floor();
+
+    HourAngle::new(angle_from_decimal_hours(ha))
 }
 
+/// Given ecliptic ecliptic latitude (β) and
+/// longitude (λ) (optionally takes date for specific
+/// obliquity of the ecliptic (ε)), returns right
+/// ascension (α) and declination (δ) for that of
+/// equatorial coordinate.
+///
+/// * `coord` - Ecliptic coordinate
+/// * `coord.lat` - Latitude (β)
+/// * `coord.lng` - Longitude (λ)
+/// * `date` - Date for specific obliquity of the eplictic (ε)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.40-41)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::decimal_hours_from_angle;
+/// use sowngwala::coords::{
+///   Angle,
+///   EcliCoord,
+///   equatorial_from_ecliptic_with_generic_date
+/// };
+///
+/// let lat_0 = Angle::new(4, 52, 31.0);
+/// let lng_0 = Angle::new(139, 41, 10.0);
+///
+/// let coord_0 = EcliCoord {
+///     lat: decimal_hours_from_angle(lat_0),
+///     lng: decimal_hours_from_angle(lng_0),
+/// };
+///
+/// // To calculate a specific value
+/// // for mean obliquity of the ecliptic.
+/// let date = NaiveDate::from_ymd(1980, 4, 22);
+/// let coord =
+///     equatorial_from_ecliptic_with_generic_date(
+///         coord_0,
+///         date
+///     );
+/// let asc: Angle = coord.asc.into();
+/// let dec: Angle = coord.dec.into();
+///
+/// assert_eq!(asc.hour(), 9);
+/// assert_eq!(asc.minute(), 34);
+/// assert_approx_eq!(
+///     asc.second(), // 53.58216253599352
+///     53.6,
+///     1e-2
+/// );
+///
+/// assert_eq!(dec.hour(), 19);
+/// assert_eq!(dec.minute(), 32);
+/// assert_approx_eq!(
+///     dec.second(), // 14.100993558899972
+///     14.2,
+///     1e-2
+/// );
+/// ```
 #[allow(clippy::many_single_char_names)]
-pub fn angle_between_two_celestial_objects(
-    asc_0: f64,
-    dec_0: f64,
-    asc_1: f64,
-    dec_1: f64,
-) -> f64 {
-    let tmp = ((asc_0 - asc_1) * 15.0).to_radians();
-    let dec_0 = dec_0.to_radians();
-    let dec_1 = dec_1.to_radians();
-    let d_cos = (dec_0.sin() * dec_1.sin())
-        + (dec_0.cos() * dec_1.cos() * tmp.cos());
+pub fn equatorial_from_ecliptic_with_generic_date<T>(
+    coord: EcliCoord,
+    date: T,
+) -> EquaCoord
+where
+    T: Datelike,
+{
+    equatorial_from_ecliptic_with_obliquity(
+        coord,
+        mean_obliquity_of_the_epliptic(date),
+    )
+}
 
-    d_cos.acos().to_degrees()
+/// Same as `equatorial_from_ecliptic_with_generic_date`,
+/// but for the apparent place. The nutation in
+/// longitude (Δψ) is added to the ecliptic longitude
+/// (λ), and the apparent obliquity of the ecliptic
+/// (the mean one with the nutation in obliquity (Δε)
+/// added) is used (see `nutation`). They differ from
+/// the mean place by some arcseconds (up to about 17
+/// arcseconds in longitude, and 9 in obliquity).
+///
+/// * `coord` - Ecliptic coordinate
+/// * `coord.lat` - Ecliptic latitude (β)
+/// * `coord.lng` - Ecliptic longitude (λ)
+/// * `date` - Datelike
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", p.144)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///   angle_between_two_celestial_objects_for_equatorial,
+///   equatorial_from_ecliptic_apparent,
+///   equatorial_from_ecliptic_with_generic_date,
+///   EcliCoord,
+/// };
+///
+/// let date = NaiveDate::from_ymd(1980, 4, 22);
+/// let coord = || EcliCoord {
+///     lat: 4.875_278,
+///     lng: 139.686_111,
+/// };
+///
+/// let apparent = equatorial_from_ecliptic_apparent(coord(), date);
+/// let mean =
+///     equatorial_from_ecliptic_with_generic_date(coord(), date);
+///
+/// // Some arcseconds apart
+/// let diff: f64 =
+///     angle_between_two_celestial_objects_for_equatorial(
+///         apparent, mean,
+///     ) * 3600.0;
+/// assert!(diff > 1.0 && diff < 25.0);
+/// ```
+pub fn equatorial_from_ecliptic_apparent<T>(
+    coord: EcliCoord,
+    date: T,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let (d_psi, _d_eps): (f64, f64) = nutation(date);
+
+    equatorial_from_ecliptic_with_obliquity(
+        EcliCoord {
+            lat: coord.lat,
+            lng: coord.lng + d_psi,
+        },
+        apparent_obliquity_of_the_ecliptic(date),
+    )
+}
+
+/// Same as `equatorial_from_ecliptic_with_generic_date`,
+/// but takes the obliquity of the ecliptic (ε) instead
+/// of the date. When transforming many coordinates for
+/// the same date, compute the obliquity only once
+/// (using `mean_obliquity_of_the_epliptic`), and pass
+/// it in.
+///
+/// * `coord` - Ecliptic coordinate
+/// * `obliquity_deg` - Obliquity of the ecliptic (ε) in degrees
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///     equatorial_from_ecliptic_with_generic_date,
+///     equatorial_from_ecliptic_with_obliquity,
+///     EcliCoord,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+/// use sowngwala::utils::mean_obliquity_of_the_epliptic;
+///
+/// let date = NaiveDate::from_ymd(1980, 4, 22);
+/// let obliquity = mean_obliquity_of_the_epliptic(date);
+///
+/// let coord_0 = equatorial_from_ecliptic_with_obliquity(
+///     EcliCoord { lat: 4.875, lng: 139.686 },
+///     obliquity,
+/// );
+/// let coord_1 = equatorial_from_ecliptic_with_generic_date(
+///     EcliCoord { lat: 4.875, lng: 139.686 },
+///     date,
+/// );
+/// assert_eq!(
+///     decimal_hours_from_angle(coord_0.asc),
+///     decimal_hours_from_angle(coord_1.asc)
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn equatorial_from_ecliptic_with_obliquity(
+    coord: EcliCoord,
+    obliquity_deg: f64,
+) -> EquaCoord {
+    let (asc, decline): (f64, f64) =
+        math::equatorial_from_ecliptic(
+            coord.lat,
+            coord.lng,
+            obliquity_deg,
+        );
+
+    EquaCoord {
+        asc: RightAscension::new(
+            angle_from_decimal_hours(asc),
+        ),
+        dec: Declination::new(
+            angle_from_decimal_hours(decline),
+        ),
+    }
+}
+
+pub fn equatorial_from_ecliptic(
+    coord: EcliCoord,
+) -> EquaCoord {
+    equatorial_from_ecliptic_with_generic_date(
+        coord,
+        NaiveDate::from_ymd(2021, 1, 0),
+    )
+}
+
+/// Given right ascension (α) and declination (δ) of
+/// equatorial coordinate (optionally takes date for
+/// specific obliquity of the ecliptic (ε)),
+/// returns latitude (β) and longitude (λ)
+///
+/// * `coord` - Equatorial coordinate
+/// * `coord.asc` - Right ascension (α)
+/// * `coord.dec` - Declination (δ)
+/// * `date` - Date for specific obliquity of the eplictic (ε)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.42)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::time::angle_from_decimal_hours;
+/// use sowngwala::coords::{
+///   Angle,
+///   Declination,
+///   EcliCoord,
+///   EquaCoord,
+///   RightAscension,
+///   ecliptic_from_equatorial_with_generic_date,
+/// };
+///
+/// // right-ascension
+/// let asc: Angle = Angle::new(9, 34, 53.6);
+///
+/// // declination
+/// let dec: Angle = Angle::new(19, 32, 14.2);
+///
+/// // To calculate a specific value for mean obliquity
+/// // of the ecliptic.
+/// let date = NaiveDate::from_ymd(1980, 4, 22);
+/// let coord_0 = EquaCoord {
+///     asc: RightAscension::new(asc),
+///     dec: Declination::new(dec),
+/// };
+///
+/// let coord: EcliCoord =
+///     ecliptic_from_equatorial_with_generic_date(
+///         coord_0,
+///         date
+///     );
+/// let lat: Angle =
+///     angle_from_decimal_hours(coord.lat);
+/// let lng: Angle =
+///     angle_from_decimal_hours(coord.lng);
+///
+/// assert_eq!(lat.hour(), 4);
+/// assert_eq!(lat.minute(), 52);
+/// assert_approx_eq!(
+///     lat.second(), // 31.17490012745307
+///     31.0,
+///     1e-2
+/// );
+///
+/// assert_eq!(lng.hour(), 139);
+/// assert_eq!(lng.minute(), 41);
+/// assert_approx_eq!(
+///     lng.second(), // 10.207621429881328
+///     10.0,
+///     3e-2
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn ecliptic_from_equatorial_with_generic_date<T>(
+    coord: EquaCoord,
+    date: T,
+) -> EcliCoord
+where
+    T: Datelike,
+{
+    ecliptic_from_equatorial_with_obliquity(
+        coord,
+        mean_obliquity_of_the_epliptic(date),
+    )
+}
+
+/// Same as `ecliptic_from_equatorial_with_generic_date`,
+/// but takes the obliquity of the ecliptic (ε) instead
+/// of the date.
+///
+/// * `coord` - Equatorial coordinate
+/// * `obliquity_deg` - Obliquity of the ecliptic (ε) in degrees
+pub fn ecliptic_from_equatorial_with_obliquity(
+    coord: EquaCoord,
+    obliquity_deg: f64,
+) -> EcliCoord {
+    let oblique: f64 = obliquity_deg.to_radians();
+    let oblique_cos: f64 = oblique.cos();
+    let oblique_sin: f64 = oblique.sin();
+
+    // right ascension (α)
+    let mut asc_decimal: f64 =
+        decimal_hours_from_angle(coord.asc)
+            .to_radians();
+
+    // declination (δ)
+    let dec_decimal: f64 =
+        decimal_hours_from_angle(coord.dec)
+            .to_radians();
+    asc_decimal *= 15.0;
+
+    let asc_sin: f64 = asc_decimal.sin();
+    let asc_cos: f64 = asc_decimal.cos();
+    let dec_sin: f64 = dec_decimal.sin();
+    let dec_cos: f64 = dec_decimal.cos();
+    let dec_tan: f64 = dec_decimal.tan();
+
+    let lat_0: f64 = (dec_sin * oblique_cos)
+        - (dec_cos * oblique_sin * asc_sin);
+    let lat: f64 = lat_0.asin().to_degrees();
+
+    let y: f64 = (asc_sin * oblique_cos)
+        + (dec_tan * oblique_sin);
+    let x: f64 = asc_cos;
+
+    let mut lng: f64 = y.atan2(x).to_degrees();
+    lng -= 360.0 * (lng / 360.0).floor();
+
+    EcliCoord { lat, lng }
+}
+
+/// Given the heliocentric ecliptic coordinate and the
+/// radius vector of a planet (or a comet), and those
+/// of the earth, returns the geocentric ecliptic
+/// coordinate of the planet. Converts both into
+/// heliocentric rectangular coordinates, subtracts
+/// the earth's, and converts the result back into
+/// latitude and longitude (in [0, 360)).
+///
+/// * `planet` - Heliocentric latitude (b) and longitude (l) of the planet (in degrees)
+/// * `planet_r` - Radius vector (r) of the planet (in AU)
+/// * `earth` - Heliocentric latitude and longitude (L) of the earth (in degrees)
+/// * `earth_r` - Radius vector (R) of the earth (in AU)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.125-128)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::{
+///     EcliCoord,
+///     geocentric_ecliptic_from_heliocentric,
+/// };
+///
+/// // Seen from the earth at 1 AU on the x-axis, a
+/// // planet at 1 AU on the y-axis is at 135°.
+/// let coord: EcliCoord = geocentric_ecliptic_from_heliocentric(
+///     EcliCoord { lat: 0.0, lng: 90.0 },
+///     1.0,
+///     EcliCoord { lat: 0.0, lng: 0.0 },
+///     1.0,
+/// );
+/// assert_approx_eq!(coord.lng, 135.0, 1e-12);
+/// assert_eq!(coord.lat, 0.0);
+/// ```
+pub fn geocentric_ecliptic_from_heliocentric(
+    planet: EcliCoord,
+    planet_r: f64,
+    earth: EcliCoord,
+    earth_r: f64,
+) -> EcliCoord {
+    geocentric_ecliptic_with_distance_from_heliocentric(
+        planet, planet_r, earth, earth_r,
+    )
+    .0
+}
+
+/// Same as `geocentric_ecliptic_from_heliocentric`,
+/// but also returns the distance from the earth (Δ)
+/// in AU.
+pub fn geocentric_ecliptic_with_distance_from_heliocentric(
+    planet: EcliCoord,
+    planet_r: f64,
+    earth: EcliCoord,
+    earth_r: f64,
+) -> (EcliCoord, f64) {
+    let rect = |coord: EcliCoord,
+                r: f64|
+     -> (f64, f64, f64) {
+        let lat: f64 = coord.lat.to_radians();
+        let lng: f64 = coord.lng.to_radians();
+        (
+            r * lat.cos() * lng.cos(),
+            r * lat.cos() * lng.sin(),
+            r * lat.sin(),
+        )
+    };
+
+    let (x_1, y_1, z_1) = rect(planet, planet_r);
+    let (x_0, y_0, z_0) = rect(earth, earth_r);
+
+    let x: f64 = x_1 - x_0;
+    let y: f64 = y_1 - y_0;
+    let z: f64 = z_1 - z_0;
+
+    let mut lng: f64 = y.atan2(x).to_degrees();
+    lng -= 360.0 * (lng / 360.0).floor();
+
+    let lat: f64 =
+        z.atan2((x * x + y * y).sqrt()).to_degrees();
+    let distance: f64 =
+        (x * x + y * y + z * z).sqrt();
+
+    (EcliCoord { lat, lng }, distance)
+}
+
+/// Given right ascension (α) and declination (δ) of
+/// equatorial coordinate, returns galactic
+/// latitude (b) and galactic longitude (l).
+///
+/// * `coord` - Equatorial coordinate
+/// * `coord.asc` - Right ascension (α)
+/// * `coord.dec` - Declination (δ)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.43)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::time::angle_from_decimal_hours;
+/// use sowngwala::coords::{
+///   Angle,
+///   Declination,
+///   EquaCoord,
+///   GalacCoord,
+///   RightAscension,
+///   galactic_from_equatorial,
+/// };
+///
+/// // right-ascension
+/// let asc: Angle = Angle::new(10, 21, 0.0);
+///
+/// // declination
+/// let dec: Angle = Angle::new(10, 3, 11.0);
+///
+/// let coord_0 = EquaCoord {
+///     asc: RightAscension::new(asc),
+///     dec: Declination::new(dec),
+/// };
+///
+/// let coord: GalacCoord =
+///     galactic_from_equatorial(coord_0);
+/// let lat: Angle =
+///     angle_from_decimal_hours(coord.lat);
+/// let lng: Angle =
+///     angle_from_decimal_hours(coord.lng);
+///
+/// assert_eq!(lat.hour(), 51);
+/// assert_eq!(lat.minute(), 7);
+/// assert_approx_eq!(
+///     lat.second(), // 20.16407768754391
+///     20.0,
+///     1e-2
+/// );
+///
+/// assert_eq!(lng.hour(), 232);
+/// assert_eq!(lng.minute(), 14);
+/// assert_approx_eq!(
+///     lng.second(), // 52.38055557683538
+///     52.0,
+///     1e-2
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn galactic_from_equatorial(
+    coord: EquaCoord,
+) -> GalacCoord {
+    galactic_from_equatorial_with_epoch(
+        coord,
+        GalacticEpoch::B1950,
+    )
+}
+
+/// The galactic coordinate system of the given epoch.
+/// `B1950` is what Duffett-Smith uses (and is the
+/// default for the crate), and `J2000` is the one
+/// for the coordinates referred to J2000.0 (FK5).
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::GalacticEpoch;
+///
+/// let (asc, dec, node) = GalacticEpoch::J2000.pole();
+/// assert_eq!(asc, 192.859_48);
+/// assert_eq!(dec, 27.128_25);
+/// assert_eq!(node, 32.931_92);
+/// ```
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq,
+)]
+pub enum GalacticEpoch {
+    #[default]
+    B1950,
+    J2000,
+}
+
+impl GalacticEpoch {
+    /// Returns right ascension (α) and declination
+    /// (δ) of the north galactic pole, and the
+    /// galactic longitude (l) of the ascending node
+    /// of the galactic plane on the equator, all in
+    /// degrees.
+    pub fn pole(&self) -> (f64, f64, f64) {
+        match self {
+            GalacticEpoch::B1950 => {
+                (192.25, 27.4, 33.0)
+            }
+            // The galactic longitude of the north
+            // celestial pole is 122.93192°, which is
+            // 90° past the ascending node.
+            GalacticEpoch::J2000 => {
+                (192.859_48, 27.128_25, 32.931_92)
+            }
+        }
+    }
+}
+
+/// Same as `galactic_from_equatorial`, but for the
+/// galactic coordinate system of the given epoch.
+///
+/// * `coord` - Equatorial coordinate (of `epoch`)
+/// * `epoch` - `GalacticEpoch`
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{
+///     galactic_from_equatorial_with_epoch,
+///     Angle, EquaCoord, GalacticEpoch, GalacCoord,
+///     Declination, RightAscension,
+/// };
+///
+/// // Sagittarius A* (J2000.0) is near the galactic
+/// // center (l = 359.944°, b = -0.046°).
+/// let coord = EquaCoord {
+///     asc: RightAscension::new(Angle::new(17, 45, 40.04)),
+///     dec: Declination::new(Angle::new(-29, 0, 28.1)),
+/// };
+/// let galac: GalacCoord = galactic_from_equatorial_with_epoch(
+///     coord,
+///     GalacticEpoch::J2000,
+/// );
+/// assert!((galac.lng - 359.944).abs() < 1e-3);
+/// assert!((galac.lat + 0.046).abs() < 1e-3);
+/// ```
+pub fn galactic_from_equatorial_with_epoch(
+    coord: EquaCoord,
+    epoch: GalacticEpoch,
+) -> GalacCoord {
+    let (pole_asc, pole_dec, node_lng) = epoch.pole();
+
+    // right ascension (α)
+    let mut asc_decimal: f64 =
+        decimal_hours_from_angle(coord.asc)
+            .to_radians();
+
+    // declination (δ)
+    let dec_decimal: f64 =
+        decimal_hours_from_angle(coord.dec)
+            .to_radians();
+    asc_decimal *= 15.0;
+
+    let dec_sin: f64 = dec_decimal.sin();
+    let dec_cos: f64 = dec_decimal.cos();
+    let asc_192: f64 = (asc_decimal.to_degrees()
+        - pole_asc)
+        .to_radians();
+
+    let r_27: f64 = pole_dec.to_radians();
+    let r_27_sin: f64 = r_27.sin();
+    let r_27_cos: f64 = r_27.cos();
+
+    let b_sin: f64 =
+        dec_cos * r_27.cos() * asc_192.cos()
+            + (dec_sin * r_27_sin);
+    let b: f64 = b_sin.asin();
+
+    let y: f64 = dec_sin - (b_sin * r_27_sin);
+    let x: f64 = dec_cos * asc_192.sin() * r_27_cos;
+
+    let mut l: f64 = y.atan2(x).to_degrees();
+    l += node_lng;
+    l -= 360.0 * (l / 360.0).floor();
+
+    GalacCoord {
+        lat: b.to_degrees(),
+        lng: l,
+    }
+}
+
+/// Given galactic latitude (b) and galactic
+/// longitude (l), returns right ascension (α)
+/// and declination (δ) of equatorial coordinate,
+///
+/// * `coord` - Galactic coordinate
+/// * `coord.lat` - Galactic latitude (b)
+/// * `coord.lng` - Galactic longitude (l)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.44)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+/// use sowngwala::coords::{
+///   Angle,
+///   EquaCoord,
+///   GalacCoord,
+///   equatorial_from_galactic
+/// };
+///
+/// // Galactic latitude (b)
+/// let lat: Angle = Angle::new(51, 7, 20.0);
+///
+/// // Galactic latitude (l)
+/// let lng: Angle = Angle::new(232, 14, 52.0);
+///
+/// let coord_0 = GalacCoord {
+///     lat: decimal_hours_from_angle(lat),
+///     lng: decimal_hours_from_angle(lng),
+/// };
+///
+/// let coord: EquaCoord =
+///     equatorial_from_galactic(coord_0);
+/// let asc: Angle = coord.asc.into();
+/// let dec: Angle = coord.dec.into();
+///
+/// assert_eq!(asc.hour(), 10);
+/// // The book gives 10h 21m 0s, which is merely
+/// // 10h 20m 59.98s rounded up.
+/// assert_eq!(asc.minute(), 20);
+/// assert_approx_eq!(
+///     asc.second(), // 59.98205693746215
+///     59.9,
+///     1e-2
+/// );
+///
+/// assert_eq!(dec.hour(), 10);
+/// assert_eq!(dec.minute(), 3);
+/// assert_approx_eq!(
+///     dec.second(), // 11.117231829019829
+///     11.0,
+///     2e-2
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn equatorial_from_galactic(
+    coord: GalacCoord,
+) -> EquaCoord {
+    equatorial_from_galactic_with_epoch(
+        coord,
+        GalacticEpoch::B1950,
+    )
+}
+
+/// Same as `equatorial_from_galactic`, but for the
+/// galactic coordinate system of the given epoch.
+///
+/// * `coord` - Galactic coordinate
+/// * `epoch` - `GalacticEpoch`
+#[allow(clippy::many_single_char_names)]
+pub fn equatorial_from_galactic_with_epoch(
+    coord: GalacCoord,
+    epoch: GalacticEpoch,
+) -> EquaCoord {
+    let (pole_asc, pole_dec, node_lng) = epoch.pole();
+
+    let b: f64 = coord.lat.to_radians(); // // Galactic latitude (b)
+    let l: f64 = coord.lng.to_radians(); // Galactic longitude (l)
+    let b_sin: f64 = b.sin();
+    let b_cos: f64 = b.cos();
+
+    let l_minus_33: f64 =
+        (l.to_degrees() - node_lng).to_radians();
+    let l_minus_33_sin: f64 = l_minus_33.sin();
+    let l_minus_33_cos: f64 = l_minus_33.cos();
+
+    let r_27: f64 = pole_dec.to_radians();
+    let r_27_sin: f64 = r_27.sin();
+    let r_27_cos: f64 = r_27.cos();
+
+    let dec_sin: f64 =
+        (b_cos * r_27_cos * l_minus_33_sin)
+            + (b_sin * r_27_sin);
+    let dec: f64 = dec_sin.asin();
+
+    let y: f64 = b_cos * l_minus_33_cos;
+    let x: f64 = (b_sin * r_27_cos)
+        - (b_cos * r_27_sin * l_minus_33_sin);
+
+    let mut asc: f64 = y.atan2(x).to_degrees();
+    asc += pole_asc;
+    asc -= 360.0 * (asc / 360.0).floor();
+    asc /= 15.0;
+
+    EquaCoord {
+        asc: RightAscension::new(
+            angle_from_decimal_hours(asc),
+        ),
+        dec: Declination::new(
+            angle_from_decimal_hours(
+                dec.to_degrees(),
+            ),
+        ),
+    }
+}
+
+/// Given right ascension (α0) and declination (δ0)
+/// referred to the equinox of `from`, returns those
+/// referred to the equinox of `to`. The coordinate is
+/// rigorously rotated by the three precessional
+/// angles (ζ, z, and θ), which grow at the annual
+/// rates of the precessional constants m (ζ + z,
+/// about 3.07s) and n (θ, about 20.04"). The angles
+/// are taken for the epochs in Julian centuries from
+/// J2000.0 (IAU 1976), so that any pair of epochs
+/// works. Proper motions are not applied.
+///
+/// * `coord` - Equatorial coordinate at `from`
+/// * `from` - Epoch of `coord`
+/// * `to` - Epoch to precess to
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.63)
+/// - (Jean Meeus, "Astronomical Algorithms", p.134)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///     precess_equatorial, Angle, EquaCoord,
+///     Declination, RightAscension,
+/// };
+///
+/// // 3C 273 from B1950.0 to J2000.0, which is
+/// // catalogued at 12h 29m 06.70s, +2° 03' 08.6"
+/// let coord = EquaCoord {
+///     asc: RightAscension::new(Angle::new(12, 26, 33.246)),
+///     dec: Declination::new(Angle::new(2, 19, 43.29)),
+/// };
+/// let coord: EquaCoord = precess_equatorial(
+///     coord,
+///     NaiveDate::from_ymd(1950, 1, 1),
+///     NaiveDate::from_ymd(2000, 1, 1),
+/// );
+///
+/// assert_eq!(coord.asc.hour(), 12);
+/// assert_eq!(coord.asc.minute(), 29);
+/// assert!((coord.asc.second() - 6.70).abs() < 0.2);
+/// assert_eq!(coord.dec.hour(), 2);
+/// assert_eq!(coord.dec.minute(), 3);
+/// assert!((coord.dec.second() - 8.6).abs() < 3.0);
+///
+/// // θ Persei from J2000.0 to 2028 November 13
+/// // (Meeus, Example 21.b, with the proper motion
+/// // applied beforehand), where Meeus gives
+/// // 2h 46m 11.331s, +49° 20' 54.54"
+/// let coord = EquaCoord {
+///     asc: RightAscension::new(Angle::new(2, 44, 12.975)),
+///     dec: Declination::new(Angle::new(49, 13, 39.90)),
+/// };
+/// let coord: EquaCoord = precess_equatorial(
+///     coord,
+///     NaiveDate::from_ymd(2000, 1, 1),
+///     NaiveDate::from_ymd(2028, 11, 13),
+/// );
+///
+/// assert_eq!(coord.asc.hour(), 2);
+/// assert_eq!(coord.asc.minute(), 46);
+/// assert!((coord.asc.second() - 11.331).abs() < 0.05);
+/// assert_eq!(coord.dec.hour(), 49);
+/// assert_eq!(coord.dec.minute(), 20);
+/// assert!((coord.dec.second() - 54.54).abs() < 0.5);
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn precess_equatorial(
+    coord: EquaCoord,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> EquaCoord {
+    let jd_0: f64 =
+        julian_day_from_generic_date(from);
+    let jd: f64 = julian_day_from_generic_date(to);
+
+    // Julian centuries from J2000.0 to `from` (T), and
+    // from `from` to `to` (t)
+    let tt: f64 = julian_centuries_since_j2000(jd_0);
+    let t: f64 = (jd - jd_0) / 36_525.0;
+
+    let k: f64 = 2306.2181 + (1.396_56 * tt)
+        - (0.000_139 * tt * tt);
+
+    // Precessional angles (in arcseconds)
+    let zeta: f64 = (k * t)
+        + ((0.301_88 - 0.000_344 * tt) * t * t)
+        + (0.017_998 * t * t * t);
+    let z: f64 = (k * t)
+        + ((1.094_68 + 0.000_066 * tt) * t * t)
+        + (0.018_203 * t * t * t);
+    let theta: f64 = ((2004.3109
+        - (0.853_30 * tt)
+        - (0.000_217 * tt * tt))
+        * t)
+        - ((0.426_65 + 0.000_217 * tt) * t * t)
+        - (0.041_833 * t * t * t);
+
+    let zeta: f64 = (zeta / 3600.0).to_radians();
+    let z: f64 = (z / 3600.0).to_radians();
+    let theta: f64 = (theta / 3600.0).to_radians();
+
+    let asc: f64 =
+        (decimal_hours_from_angle(coord.asc) * 15.0)
+            .to_radians();
+    let dec: f64 =
+        decimal_hours_from_angle(coord.dec)
+            .to_radians();
+
+    let a: f64 = dec.cos() * (asc + zeta).sin();
+    let b: f64 = (theta.cos()
+        * dec.cos()
+        * (asc + zeta).cos())
+        - (theta.sin() * dec.sin());
+    let c: f64 = (theta.sin()
+        * dec.cos()
+        * (asc + zeta).cos())
+        + (theta.cos() * dec.sin());
+
+    let mut asc_1: f64 =
+        (a.atan2(b) + z).to_degrees();
+    asc_1 -= 360.0 * (asc_1 / 360.0).floor();
+
+    EquaCoord {
+        asc: RightAscension::new(
+            angle_from_decimal_hours(asc_1 / 15.0),
+        ),
+        dec: Declination::new(
+            angle_from_decimal_hours(
+                c.asin().to_degrees(),
+            ),
+        ),
+    }
+}
+
+/// Given right ascension (α) and declination (δ) of
+/// a star (the mean place), returns those displaced
+/// by the annual aberration on the date. The
+/// coordinate is converted into the ecliptic
+/// longitude (λ) and latitude (β), and with the
+/// sun's geometric longitude (⊙) and the constant of
+/// aberration (κ = 20.5"),
+///
+/// Δλ = -κ cos(⊙ - λ) / cos β
+/// Δβ = -κ sin(⊙ - λ) sin β
+///
+/// are applied before converting it back. A star on
+/// the ecliptic (β = 0) is displaced only in
+/// longitude. The small terms from the eccentricity
+/// of the earth's orbit are ignored (about 0.3").
+///
+/// * `coord` - Equatorial coordinate
+/// * `date` - Date
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.66)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{
+///     apply_aberration, Angle, EquaCoord,
+///     Declination, RightAscension,
+/// };
+///
+/// // θ Persei on 2028 November 13 (Meeus, Example
+/// // 23.a), where Meeus gives Δα = +30.045" and
+/// // Δδ = +6.697" for the aberration.
+/// let coord = EquaCoord {
+///     asc: RightAscension::new(Angle::new(2, 46, 11.331)),
+///     dec: Declination::new(Angle::new(49, 20, 54.54)),
+/// };
+/// let coord: EquaCoord = apply_aberration(
+///     coord,
+///     NaiveDate::from_ymd(2028, 11, 13),
+/// );
+///
+/// assert_eq!(coord.asc.minute(), 46);
+/// assert!(
+///     ((coord.asc.second() - 11.331) * 15.0 - 30.045).abs()
+///         < 1.0
+/// );
+/// assert_eq!(coord.dec.minute(), 21);
+/// assert!(
+///     (coord.dec.second() + 60.0 - 54.54 - 6.697).abs()
+///         < 1.0
+/// );
+/// ```
+pub fn apply_aberration(
+    coord: EquaCoord,
+    date: NaiveDate,
+) -> EquaCoord {
+    let ecliptic: EcliCoord =
+        ecliptic_from_equatorial_with_generic_date(
+            coord, date,
+        );
+
+    let sun_lng: f64 =
+        ecliptic_position_of_the_sun_from_generic_date(
+            date,
+        )
+        .lng;
+
+    let kappa: f64 = CONSTANT_OF_ABERRATION / 3600.0;
+    let lat: f64 = ecliptic.lat.to_radians();
+    let diff: f64 =
+        (sun_lng - ecliptic.lng).to_radians();
+
+    let mut lng: f64 = ecliptic.lng
+        - (kappa * diff.cos() / lat.cos());
+    lng -= 360.0 * (lng / 360.0).floor();
+
+    let lat: f64 = ecliptic.lat
+        - (kappa * diff.sin() * lat.sin());
+
+    equatorial_from_ecliptic_with_generic_date(
+        EcliCoord { lat, lng },
+        date,
+    )
+}
+
+/// Given the true altitude (a) in degrees, the
+/// atmospheric pressure (P) in millibars, and the
+/// temperature (T) in degrees Celsius, returns the
+/// refraction (R) in degrees, by which the object is
+/// seen higher than it is. For altitudes above 15°,
+/// it is proportional to the tangent of the zenith
+/// distance (z), and below, an empirical formula is
+/// used. Returns 0 for objects well below the horizon
+/// (below -2°), which cannot be seen anyway.
+///
+/// R = 0.00452 P tan z / (273 + T)
+/// R = P (0.1594 + 0.0196 a + 0.00002 a^2) / ((273 + T) (1 + 0.505 a + 0.0845 a^2))
+///
+/// * `alt` - True altitude (a) in degrees
+/// * `pressure_mbar` - Atmospheric pressure (P)
+/// * `temp_c` - Temperature (T)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.54-55)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::refraction_from_altitude;
+///
+/// // About 34' at the horizon
+/// assert_approx_eq!(
+///     refraction_from_altitude(0.0, 1012.0, 10.0),
+///     0.57,
+///     1e-2
+/// );
+/// // None at the zenith
+/// assert!(refraction_from_altitude(90.0, 1012.0, 10.0) < 1e-15);
+/// ```
+pub fn refraction_from_altitude(
+    alt: f64,
+    pressure_mbar: f64,
+    temp_c: f64,
+) -> f64 {
+    if alt < -2.0 {
+        return 0.0;
+    }
+
+    let kelvin: f64 = 273.0 + temp_c;
+
+    if alt > 15.0 {
+        let z: f64 = (90.0 - alt).to_radians();
+        0.00452 * pressure_mbar * z.tan() / kelvin
+    } else {
+        pressure_mbar
+            * (0.1594
+                + 0.0196 * alt
+                + 0.000_02 * alt * alt)
+            / (kelvin
+                * (1.0
+                    + 0.505 * alt
+                    + 0.0845 * alt * alt))
+    }
+}
+
+/// Given horizontal coordinate of the true position,
+/// returns that of the apparent position, raised by
+/// the refraction (see `refraction_from_altitude`).
+/// Azimuth (A) stays the same.
+///
+/// * `coord` - Horizontal coordinate
+/// * `pressure_mbar` - Atmospheric pressure (P)
+/// * `temp_c` - Temperature (T)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.54-55)
+pub fn apply_refraction(
+    coord: HorizCoord,
+    pressure_mbar: f64,
+    temp_c: f64,
+) -> HorizCoord {
+    let alt: f64 =
+        decimal_hours_from_angle(coord.alt);
+    let alt_1: f64 = alt
+        + refraction_from_altitude(
+            alt,
+            pressure_mbar,
+            temp_c,
+        );
+
+    HorizCoord {
+        alt: angle_from_decimal_hours(alt_1),
+        azi: coord.azi,
+    }
+}
+
+/// Given the geocentric right ascension (α) and
+/// declination (δ) of a nearby object (such as the
+/// moon), returns those seen from the observer on
+/// the surface of the earth (topocentric α' and δ').
+/// The observer's geocentric latitude (φ') and
+/// distance from the center of the earth (ρ) are
+/// taken at the sea level, with the flattening of
+/// the earth (b/a = 0.996647).
+///
+/// tan Δα = ρ cos φ' sin H / (r cos δ - ρ cos φ' cos H)
+/// tan δ' = cos Δα (r sin δ - ρ sin φ') / (r cos δ - ρ cos φ' cos H)
+///
+/// where α' = α - Δα. Since the denominators are
+/// always positive (r is some 60 earth radii for the
+/// moon), δ' stays within ±90° for any H.
+///
+/// * `coord` - Geocentric equatorial coordinate
+/// * `coord_obs` - Observer's latitude and longitude
+/// * `distance_earth_radii` - Distance of the object (r) in the earth's equatorial radii
+/// * `lst` - LST of the observer
+///
+/// References:
+/// - (Peter Duffett-Smith, p.67)
+/// - (Jean Meeus, "Astronomical Algorithms", p.279)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::coords::{
+///     angle_between_two_celestial_objects_for_equatorial,
+///     apply_parallax_equatorial, Coord, EquaCoord,
+/// };
+/// use sowngwala::moon::{
+///     distance_and_angular_size_from_generic_datetime,
+///     equatorial_position_of_the_moon_from_generic_datetime,
+/// };
+/// use sowngwala::time::{
+///     naive_time_from_decimal_hours, SiderealBaseline,
+/// };
+///
+/// let date = NaiveDate::from_ymd(2022, 7, 13);
+/// let dt: NaiveDateTime = date.and_hms(12, 0, 0);
+/// let tokyo = Coord { lat: 35.6762, lng: 139.6503 };
+///
+/// let geo: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(dt);
+/// let r: f64 =
+///     distance_and_angular_size_from_generic_datetime(dt)
+///         .distance_km
+///         / 6378.14;
+/// let lst = naive_time_from_decimal_hours(
+///     SiderealBaseline::for_date(date).lst_at(12.0, tokyo.lng),
+/// );
+///
+/// let topo: EquaCoord = apply_parallax_equatorial(
+///     EquaCoord { asc: geo.asc, dec: geo.dec },
+///     tokyo,
+///     r,
+///     lst,
+/// );
+///
+/// // Shifted by some tens of arcminutes, but never
+/// // more than the horizontal parallax (about 1°).
+/// let shift: f64 =
+///     angle_between_two_celestial_objects_for_equatorial(
+///         geo, topo,
+///     ) * 60.0;
+/// assert!(shift > 10.0 && shift < 62.0);
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn apply_parallax_equatorial(
+    coord: EquaCoord,
+    coord_obs: Coord,
+    distance_earth_radii: f64,
+    lst: NaiveTime,
+) -> EquaCoord {
+    let r: f64 = distance_earth_radii;
+    let lat: f64 = coord_obs.lat.to_radians();
+
+    // Observer's geocentric coordinates
+    let u: f64 = (0.996_647 * lat.tan()).atan();
+    let rho_sin: f64 = 0.996_647 * u.sin(); // ρ sin φ'
+    let rho_cos: f64 = u.cos(); // ρ cos φ'
+
+    let asc: f64 =
+        decimal_hours_from_angle(coord.asc);
+    let dec: f64 =
+        decimal_hours_from_angle(coord.dec)
+            .to_radians();
+
+    // Hour-angle (H)
+    let h: f64 =
+        ((decimal_hours_from_generic_time(lst)
+            - asc)
+            * 15.0)
+            .to_radians();
+
+    let delta: f64 = (rho_cos * h.sin())
+        .atan2((r * dec.cos()) - (rho_cos * h.cos()));
+
+    let dec_1: f64 = (delta.cos()
+        * ((r * dec.sin()) - rho_sin))
+        .atan2((r * dec.cos()) - (rho_cos * h.cos()));
+
+    let mut asc_1: f64 =
+        asc - (delta.to_degrees() / 15.0);
+    asc_1 -= 24.0 * (asc_1 / 24.0).floor();
+
+    EquaCoord {
+        asc: RightAscension::new(
+            angle_from_decimal_hours(asc_1),
+        ),
+        dec: Declination::new(
+            angle_from_decimal_hours(
+                dec_1.to_degrees(),
+            ),
+        ),
+    }
+}
+
+/// Given coordinates for two celestial objects
+/// expressed in ecliptic coordinate system
+/// (latitude (β) and longitude (λ)), returns
+/// the angle between them.
+///
+/// * `coord_0` - Equatorial coordinate
+/// * `coord_0.asc` - Right ascension (α)
+/// * `coord_0.dec` - Declination (δ)
+/// * `coord_1` - Equatorial coordinate
+/// * `coord_1.asc` - Right ascension (α)
+/// * `coord_1.dec` - Declination (δ)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.51)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::Timelike;
+/// use chrono::naive::{
+///     NaiveDate,
+///     NaiveDateTime,
+/// };
+/// use sowngwala::coords::{
+///   Angle,
+///   Declination,
+///   EquaCoord,
+///   RightAscension,
+///   angle_between_two_celestial_objects_for_equatorial,
+/// };
+///
+/// // right-ascension (for Beta Orionis)
+/// let asc_0: Angle = Angle::new(5, 13, 31.7);
+///
+/// // declination (for Beta Orionis)
+/// let dec_0: Angle = Angle::new(-8, 13, 30.0);
+///
+/// // right-ascension (for Canis Majoris)
+/// let asc_1: Angle = Angle::new(6, 44, 13.4);
+///
+/// // declination (for Canis Majoris)
+/// let dec_1: Angle = Angle::new(-16, 41, 11.0);
+///
+/// let angle: f64 =
+///     angle_between_two_celestial_objects_for_equatorial(
+///         EquaCoord {
+///             asc: RightAscension::new(asc_0),
+///             dec: Declination::new(dec_0),
+///         },
+///         EquaCoord {
+///             asc: RightAscension::new(asc_1),
+///             dec: Declination::new(dec_1),
+///         }
+///     );
+///
+/// assert_approx_eq!(
+///     angle, // 23.67384942216419
+///     23.673_850,
+///     1e-6
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn angle_between_two_celestial_objects_for_equatorial(
+    coord_0: EquaCoord,
+    coord_1: EquaCoord,
+) -> f64 {
+    angle_between_two_celestial_objects(
+        decimal_hours_from_angle(coord_0.asc),
+        decimal_hours_from_angle(coord_0.dec),
+        decimal_hours_from_angle(coord_1.asc),
+        decimal_hours_from_angle(coord_1.dec),
+    )
+}
+
+/// Given coordinates for two celestial objects
+/// expressed in galactic coordinate system
+/// (latitude (b) and longitude (l)), returns
+/// the angle between them.
+///
+/// * `coord_0` - Galactic coordinate
+/// * `coord_0.lat` - Galactic latitude (b)
+/// * `coord_0.lng` - Galactic longitude (l)
+/// * `coord_1` - Galactic coordinate
+/// * `coord_1.lat` - Galactic latitude (b)
+/// * `coord_1.lng` - Galactic longitude (l)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.51)
+#[allow(clippy::many_single_char_names)]
+pub fn angle_between_two_celestial_objects_for_galactic(
+    coord_0: GalacCoord,
+    coord_1: GalacCoord,
+) -> f64 {
+    // Longitudes (in degrees) into hours, as if they
+    // were right ascensions
+    angle_between_two_celestial_objects(
+        coord_0.lng / 15.0,
+        coord_0.lat,
+        coord_1.lng / 15.0,
+        coord_1.lat,
+    )
+}
+
+/// Same as
+/// `angle_between_two_celestial_objects_for_equatorial`,
+/// but returns `Angle` (in degrees).
+///
+/// * `a` - Equatorial coordinate
+/// * `b` - Equatorial coordinate
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.51)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::{
+///   Angle,
+///   Declination,
+///   EquaCoord,
+///   RightAscension,
+///   angular_separation_equatorial,
+/// };
+///
+/// // Beta Orionis and Canis Majoris
+/// let angle: Angle = angular_separation_equatorial(
+///     EquaCoord {
+///         asc: RightAscension::new(Angle::new(5, 13, 31.7)),
+///         dec: Declination::new(Angle::new(-8, 13, 30.0)),
+///     },
+///     EquaCoord {
+///         asc: RightAscension::new(Angle::new(6, 44, 13.4)),
+///         dec: Declination::new(Angle::new(-16, 41, 11.0)),
+///     },
+/// );
+///
+/// assert_eq!(angle.hour(), 23);
+/// assert_eq!(angle.minute(), 40);
+/// assert_approx_eq!(
+///     angle.second(), // 25.858...
+///     25.86,
+///     1e-3
+/// );
+/// ```
+pub fn angular_separation_equatorial(
+    a: EquaCoord,
+    b: EquaCoord,
+) -> Angle {
+    angle_from_decimal_hours(
+        angle_between_two_celestial_objects_for_equatorial(
+            a, b,
+        ),
+    )
+}
+
+/// Given two equatorial coordinates and `t` (0.0 for
+/// `a`, and 1.0 for `b`), returns the coordinate in
+/// between along the great circle (spherical linear
+/// interpolation), such as for animating a telescope
+/// slewing from `a` to `b`. It goes through the unit
+/// vectors (see `EquaCoord::to_cartesian`). When `a`
+/// and `b` are the same, returns `a`, and when they
+/// are the opposite (where any great circle goes
+/// through both), the one through the north
+/// celestial pole is taken (or through α = 0h when
+/// `a` is on the pole).
+///
+/// * `a` - Equatorial coordinate
+/// * `b` - Equatorial coordinate
+/// * `t` - Fraction from `a` to `b`
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{
+///   Angle,
+///   Declination,
+///   EquaCoord,
+///   RightAscension,
+///   slerp_equatorial,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// let a = EquaCoord {
+///     asc: RightAscension::new(Angle::new(0, 0, 0.0)),
+///     dec: Declination::new(Angle::new(0, 0, 0.0)),
+/// };
+/// let b = EquaCoord {
+///     asc: RightAscension::new(Angle::new(6, 0, 0.0)),
+///     dec: Declination::new(Angle::new(0, 0, 0.0)),
+/// };
+///
+/// // Half way along the celestial equator
+/// let mid: EquaCoord = slerp_equatorial(a, b, 0.5);
+/// assert!((decimal_hours_from_angle(mid.asc) - 3.0).abs() < 1e-9);
+/// assert!(decimal_hours_from_angle(mid.dec).abs() < 1e-9);
+/// ```
+pub fn slerp_equatorial(
+    a: EquaCoord,
+    b: EquaCoord,
+    t: f64,
+) -> EquaCoord {
+    let va: [f64; 3] = a.to_cartesian();
+    let vb: [f64; 3] = b.to_cartesian();
+
+    let dot: f64 = (va[0] * vb[0]
+        + va[1] * vb[1]
+        + va[2] * vb[2])
+        .clamp(-1.0, 1.0);
+    let omega: f64 = dot.acos();
+
+    // The same direction
+    if omega < 1e-12 {
+        return a;
+    }
+
+    let v: [f64; 3] = if PI - omega < 1e-12 {
+        // The opposite direction (going through `u`,
+        // which is perpendicular to `va`)
+        let pole: [f64; 3] =
+            if va[2].abs() > 1.0 - 1e-12 {
+                [1.0, 0.0, 0.0]
+            } else {
+                [0.0, 0.0, 1.0]
+            };
+        let k: f64 = pole[0] * va[0]
+            + pole[1] * va[1]
+            + pole[2] * va[2];
+        let mut u: [f64; 3] = [
+            pole[0] - k * va[0],
+            pole[1] - k * va[1],
+            pole[2] - k * va[2],
+        ];
+        let norm: f64 =
+            (u[0] * u[0] + u[1] * u[1] + u[2] * u[2])
+                .sqrt();
+        u.iter_mut().for_each(|x| *x /= norm);
+
+        let (sin, cos) = (t * PI).sin_cos();
+        [
+            cos * va[0] + sin * u[0],
+            cos * va[1] + sin * u[1],
+            cos * va[2] + sin * u[2],
+        ]
+    } else {
+        let k0: f64 =
+            ((1.0 - t) * omega).sin() / omega.sin();
+        let k1: f64 = (t * omega).sin() / omega.sin();
+        [
+            k0 * va[0] + k1 * vb[0],
+            k0 * va[1] + k1 * vb[1],
+            k0 * va[2] + k1 * vb[2],
+        ]
+    };
+
+    EquaCoord::from_cartesian(v)
+}
+
+/// Same as
+/// `angle_between_two_celestial_objects_for_galactic`,
+/// but returns `Angle` (in degrees).
+///
+/// * `a` - Galactic coordinate
+/// * `b` - Galactic coordinate
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.51)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{
+///   Angle,
+///   GalacCoord,
+///   angular_separation_galactic,
+/// };
+///
+/// // The galactic center, and the north galactic pole
+/// let angle: Angle = angular_separation_galactic(
+///     GalacCoord { lat: 0.0, lng: 0.0 },
+///     GalacCoord { lat: 90.0, lng: 0.0 },
+/// );
+/// assert_eq!(angle, Angle::new(90, 0, 0.0));
+/// ```
+pub fn angular_separation_galactic(
+    a: GalacCoord,
+    b: GalacCoord,
+) -> Angle {
+    angle_from_decimal_hours(
+        angle_between_two_celestial_objects_for_galactic(
+            a, b,
+        ),
+    )
+}
+
+pub use crate::math::angle_between_two_celestial_objects;
+
+/// Given two points on the earth (`Coord`, latitude
+/// and longitude in decimal degrees, positive for
+/// North and East, as used for the observer in
+/// rise and set), returns the angle between them
+/// along the great circle in degrees. The haversine
+/// formula is used, which stays accurate for the
+/// points very close to each other.
+///
+/// * `a` - Latitude and longitude
+/// * `b` - Latitude and longitude
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{angular_separation, Coord};
+///
+/// let north_pole = Coord { lat: 90.0, lng: 0.0 };
+/// let equator = Coord { lat: 0.0, lng: 123.0 };
+///
+/// let d: f64 = angular_separation(north_pole, equator);
+/// assert!((d - 90.0).abs() < 1e-9);
+/// ```
+pub fn angular_separation(a: Coord, b: Coord) -> f64 {
+    let lat_a: f64 = a.lat.to_radians();
+    let lat_b: f64 = b.lat.to_radians();
+    let d_lat: f64 = (b.lat - a.lat).to_radians();
+    let d_lng: f64 = (b.lng - a.lng).to_radians();
+
+    let h: f64 = (d_lat / 2.0).sin().powi(2)
+        + (lat_a.cos()
+            * lat_b.cos()
+            * (d_lng / 2.0).sin().powi(2));
+
+    (2.0 * h.sqrt().min(1.0).asin()).to_degrees()
+}
+
+/// Given two points on the earth (see
+/// `angular_separation`) and the radius of the
+/// sphere, returns the distance between them along
+/// the great circle in km.
+///
+/// * `a` - Latitude and longitude
+/// * `b` - Latitude and longitude
+/// * `radius_km` - Radius (such as 6371.0 for the mean radius of the earth)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{great_circle_distance_km, Coord};
+///
+/// let tokyo = Coord { lat: 35.6762, lng: 139.6503 };
+/// let london = Coord { lat: 51.5074, lng: -0.1278 };
+///
+/// let d: f64 = great_circle_distance_km(tokyo, london, 6371.0);
+/// assert!((d - 9_559.0).abs() < 10.0);
+/// ```
+pub fn great_circle_distance_km(
+    a: Coord,
+    b: Coord,
+    radius_km: f64,
+) -> f64 {
+    angular_separation(a, b).to_radians() * radius_km
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> (i32, i32, f64) {
+        let angle: Angle = s.parse().unwrap();
+        (angle.hour(), angle.minute(), angle.second())
+    }
+
+    #[test]
+    fn parallax_on_the_meridian_shifts_only_declination(
+    ) {
+        let coord = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                6, 0, 0.0,
+            )),
+            dec: Declination::new(Angle::new(
+                0, 0, 0.0,
+            )),
+        };
+        let obs = Coord {
+            lat: 45.0,
+            lng: 0.0,
+        };
+        let lst = NaiveTime::from_hms(6, 0, 0);
+
+        let topo: EquaCoord =
+            apply_parallax_equatorial(
+                coord, obs, 60.0, lst,
+            );
+
+        let asc: f64 =
+            decimal_hours_from_angle(topo.asc);
+        let dec: f64 =
+            decimal_hours_from_angle(topo.dec);
+
+        assert!((asc - 6.0).abs() < 1e-12);
+
+        // The object sinks toward the south by about
+        // asin(ρ sin(φ' - δ) / r), which is some 40'.
+        assert!(dec < 0.0);
+        assert!(
+            (dec * 60.0 + 40.4).abs() < 0.5,
+            "{}",
+            dec * 60.0
+        );
+    }
+
+    #[test]
+    fn aberration_on_the_ecliptic_is_only_in_longitude(
+    ) {
+        let date = NaiveDate::from_ymd(2022, 3, 20);
+
+        for &lng in [0.0, 90.0, 180.0, 270.0].iter() {
+            let coord: EquaCoord =
+                equatorial_from_ecliptic_with_generic_date(
+                    EcliCoord { lat: 0.0, lng },
+                    date,
+                );
+            let coord: EquaCoord =
+                apply_aberration(coord, date);
+            let ecliptic: EcliCoord =
+                ecliptic_from_equatorial_with_generic_date(
+                    coord, date,
+                );
+
+            let sun_lng: f64 =
+                ecliptic_position_of_the_sun_from_generic_date(
+                    date,
+                )
+                .lng;
+            let expected: f64 = -20.5
+                * (sun_lng - lng).to_radians().cos();
+
+            let mut d_lng: f64 = ecliptic.lng - lng;
+            if d_lng > 180.0 {
+                d_lng -= 360.0;
+            }
+
+            assert!(
+                ecliptic.lat.abs() * 3600.0 < 0.01
+            );
+            assert!(
+                (d_lng * 3600.0 - expected).abs()
+                    < 0.01,
+                "{}: {} vs {}",
+                lng,
+                d_lng * 3600.0,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_angle_carries_and_borrows() {
+        let (angle, day_excess) =
+            normalize_angle(Angle::new(10, 59, 90.0));
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (11, 0, 30.0)
+        );
+        assert_eq!(day_excess, 0.0);
+
+        let (angle, day_excess) =
+            normalize_angle(Angle::new(0, 0, -90.0));
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (23, 58, 30.0)
+        );
+        assert_eq!(day_excess, -1.0);
+
+        let t: NaiveTime =
+            Angle::new(0, 0, 90.0).into();
+        assert_eq!(t, NaiveTime::from_hms(0, 1, 30));
+    }
+
+    #[test]
+    fn angular_separation_of_identical_and_antipodal_points(
+    ) {
+        let points = [
+            Coord { lat: 0.0, lng: 0.0 },
+            Coord {
+                lat: 51.4769,
+                lng: -0.0005,
+            },
+            Coord {
+                lat: -33.8688,
+                lng: 151.2093,
+            },
+            Coord {
+                lat: 90.0,
+                lng: 0.0,
+            },
+        ];
+
+        for a in points.iter() {
+            let antipode = Coord {
+                lat: -a.lat,
+                lng: a.lng + 180.0,
+            };
+
+            assert_eq!(
+                angular_separation(*a, *a),
+                0.0
+            );
+            assert!(
+                (angular_separation(*a, antipode)
+                    - 180.0)
+                    .abs()
+                    < 1e-9
+            );
+            assert!(
+                (great_circle_distance_km(
+                    *a, antipode, 6371.0
+                ) - 6371.0 * PI)
+                    .abs()
+                    < 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn equa_coord_round_trips_through_hour_angle() {
+        let utc: DateTime<Utc> =
+            crate::time::build_utc(
+                1980,
+                4,
+                22,
+                14,
+                36,
+                51,
+                670_000_000,
+            );
+
+        for &(asc, lng, east) in [
+            ((18, 32, 21.0), 64.0, false),
+            ((0, 12, 5.5), 139.65, true),
+            ((23, 59, 59.0), 0.0, true),
+        ]
+        .iter()
+        {
+            let dir = || {
+                if east {
+                    Direction::East
+                } else {
+                    Direction::West
+                }
+            };
+            let (h, m, sec) = asc;
+            let coord = EquaCoord {
+                asc: RightAscension::new(Angle::new(
+                    h, m, sec,
+                )),
+                dec: Declination::new(Angle::new(
+                    -8, 13, 30.0,
+                )),
+            };
+            let asc_0 =
+                decimal_hours_from_angle(coord.asc);
+
+            let coord = equa_coord_from_equa_coord2(
+                equa_coord2_from_equa_coord(
+                    coord,
+                    utc,
+                    lng,
+                    dir(),
+                ),
+                utc,
+                lng,
+                dir(),
+            );
+            let asc_1 =
+                decimal_hours_from_angle(coord.asc);
+
+            // Within a tenth of a second (mod 24h)
+            let diff = (asc_1 - asc_0 + 12.0)
+                .rem_euclid(24.0)
+                - 12.0;
+            assert!(
+                diff.abs() * 3600.0 < 0.1,
+                "{}",
+                diff
+            );
+
+            let dec: Angle = coord.dec.into();
+            assert_eq!(dec.hour(), -8);
+            assert_eq!(dec.minute(), 13);
+            assert_eq!(dec.second(), 30.0);
+        }
+    }
+
+    #[test]
+    fn transit_altitude_matches_the_horizon_at_zero_hour_angle(
+    ) {
+        let date = NaiveDate::from_ymd(2022, 1, 1);
+        let coord = Coord {
+            lat: 35.68,
+            lng: 139.65,
+        };
+        // South of the zenith, north of the zenith,
+        // and below the horizon.
+        for &dec in [-16.716, 60.0, -70.0].iter() {
+            let (_utc, alt) = transit_from_date(
+                RightAscension::new(Angle::new(
+                    6, 45, 8.9,
+                )),
+                Declination::new(
+                    angle_from_decimal_hours(dec),
+                ),
+                date,
+                coord,
+            );
+            let horiz = horizon_from_equatorial(
+                EquaCoord2 {
+                    ha: HourAngle::new(Angle::new(
+                        0, 0, 0.0,
+                    )),
+                    dec: Declination::new(
+                        angle_from_decimal_hours(dec),
+                    ),
+                },
+                coord.lat,
+            );
+            assert!(
+                (decimal_hours_from_angle(alt)
+                    - decimal_hours_from_angle(
+                        horiz.alt
+                    ))
+                .abs()
+                    < 1e-6,
+                "{}",
+                dec
+            );
+        }
+    }
+
+    #[test]
+    fn rise_set_azimuth_on_the_celestial_equator() {
+        use approx_eq::assert_approx_eq;
+
+        for &lat in [0.0, 35.68, -51.5].iter() {
+            let (rise, set) = rise_set_azimuth(
+                Declination::new(Angle::new(
+                    0, 0, 0.0,
+                )),
+                lat,
+            )
+            .unwrap();
+            assert_approx_eq!(
+                decimal_hours_from_angle(rise),
+                90.0,
+                1e-12
+            );
+            assert_approx_eq!(
+                decimal_hours_from_angle(set),
+                270.0,
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn rise_set_azimuth_is_none_when_it_does_not_rise_or_set(
+    ) {
+        // Circumpolar
+        assert!(rise_set_azimuth(
+            Declination::new(Angle::new(45, 0, 0.0)),
+            60.0
+        )
+        .is_none());
+        // Never rises
+        assert!(rise_set_azimuth(
+            Declination::new(Angle::new(-45, 0, 0.0)),
+            60.0
+        )
+        .is_none());
+        // Rises and sets
+        assert!(rise_set_azimuth(
+            Declination::new(Angle::new(20, 0, 0.0)),
+            60.0
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn visibility_at_the_poles_and_the_equator() {
+        let dec = |d: i32| {
+            Declination::new(Angle::new(d, 0, 0.0))
+        };
+
+        // North pole
+        assert_eq!(
+            visibility(dec(1), 90.0),
+            Visibility::Circumpolar
+        );
+        assert_eq!(
+            visibility(dec(-1), 90.0),
+            Visibility::NeverRises
+        );
+
+        // South pole
+        assert_eq!(
+            visibility(dec(-1), -90.0),
+            Visibility::Circumpolar
+        );
+        assert_eq!(
+            visibility(dec(1), -90.0),
+            Visibility::NeverRises
+        );
+
+        // Everything rises and sets at the equator.
+        for d in [-89, -45, 0, 45, 89].iter() {
+            assert_eq!(
+                visibility(dec(*d), 0.0),
+                Visibility::Sometimes
+            );
+        }
+
+        // Same as `rise_set_azimuth`
+        assert_eq!(
+            visibility(dec(45), 60.0),
+            Visibility::Circumpolar
+        );
+        assert_eq!(
+            visibility(dec(-45), 60.0),
+            Visibility::NeverRises
+        );
+        assert_eq!(
+            visibility(dec(20), 60.0),
+            Visibility::Sometimes
+        );
+    }
+
+    #[test]
+    fn geocentric_ecliptic_from_orthogonal_vectors() {
+        let ecli = |lat: f64, lng: f64| EcliCoord {
+            lat,
+            lng,
+        };
+
+        // Planet at (0, 0, 1) AU, the earth at
+        // (1, 0, 0): 45° above the ecliptic, toward
+        // the longitude of 180°.
+        let (coord, distance) =
+            geocentric_ecliptic_with_distance_from_heliocentric(
+                ecli(90.0, 0.0),
+                1.0,
+                ecli(0.0, 0.0),
+                1.0,
+            );
+        assert!((coord.lng - 180.0).abs() < 1e-9);
+        assert!((coord.lat - 45.0).abs() < 1e-9);
+        assert!(
+            (distance - 2.0_f64.sqrt()).abs() < 1e-12
+        );
+
+        // Outer planet at (-2, 0, 0) AU (opposite side
+        // of the sun), the earth at (0, 1, 0).
+        let (coord, distance) =
+            geocentric_ecliptic_with_distance_from_heliocentric(
+                ecli(0.0, 180.0),
+                2.0,
+                ecli(0.0, 90.0),
+                1.0,
+            );
+        // Vector (-2, -1, 0)
+        assert!(
+            (coord.lng
+                - (180.0
+                    + (0.5_f64).atan().to_degrees()))
+            .abs()
+                < 1e-9
+        );
+        assert_eq!(coord.lat, 0.0);
+        assert!(
+            (distance - 5.0_f64.sqrt()).abs() < 1e-12
+        );
+
+        // At opposition, the distance is the difference
+        // of the radii.
+        let (coord, distance) =
+            geocentric_ecliptic_with_distance_from_heliocentric(
+                ecli(0.0, 30.0),
+                5.2,
+                ecli(0.0, 30.0),
+                1.0,
+            );
+        assert!((coord.lng - 30.0).abs() < 1e-9);
+        assert!((distance - 4.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn arcseconds_round_trip() {
+        // Spanning several degrees
+        let angle = Angle::from_arcseconds(45_296.25);
+        assert_eq!(angle.hour(), 12);
+        assert_eq!(angle.minute(), 34);
+        assert_eq!(angle.second(), 56.25);
+        assert_eq!(angle.to_arcseconds(), 45_296.25);
+
+        // Negative, and less than an arcminute
+        let angle = Angle::from_arcseconds(-0.75);
+        assert_eq!(angle.hour(), 0);
+        assert_eq!(angle.minute(), 0);
+        assert_eq!(angle.second(), -0.75);
+        assert_eq!(angle.to_arcseconds(), -0.75);
+
+        // Negative, and less than a degree
+        let angle = Angle::from_arcseconds(-754.5);
+        assert_eq!(angle.hour(), 0);
+        assert_eq!(angle.minute(), -12);
+        assert_eq!(angle.second(), 34.5);
+        assert_eq!(angle.to_arcseconds(), -754.5);
+
+        for &total in
+            [0.0, 59.999, 3600.0, -1_234_567.5].iter()
+        {
+            assert!(
+                (Angle::from_arcseconds(total)
+                    .to_arcseconds()
+                    - total)
+                    .abs()
+                    < 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn angles_compare_over_a_mixed_sign_slice() {
+        let angles = [
+            Angle::new(0, 0, 0.0),
+            Angle::new(0, 0, -30.0),
+            Angle::new(-1, 0, 0.0),
+            Angle::new(0, -59, 59.0),
+            Angle::new(0, 0, 30.0),
+            Angle::new(1, 0, 0.0),
+        ];
+
+        assert!(angles[1] < angles[0]);
+        assert!(angles[2] < angles[3]);
+        assert!(angles[3] < angles[1]);
+        assert!(angles[4] > angles[0]);
+        assert!(angles[4] < angles[5]);
+
+        let mut sorted = angles.to_vec();
+        sorted.sort_by(|a, b| {
+            a.partial_cmp(b).unwrap()
+        });
+        let arcseconds: Vec<f64> = sorted
+            .iter()
+            .map(|a| a.to_arcseconds())
+            .collect();
+        assert_eq!(
+            arcseconds,
+            vec![
+                -3600.0, -3599.0, -30.0, 0.0, 30.0,
+                3600.0
+            ]
+        );
+
+        assert_eq!(
+            max_altitude(&angles).unwrap(),
+            Angle::new(1, 0, 0.0)
+        );
+        assert_eq!(
+            max_altitude(&angles[..4]).unwrap(),
+            Angle::new(0, 0, 0.0)
+        );
+        assert_eq!(
+            angles
+                .iter()
+                .min_by(|a, b| a
+                    .partial_cmp(b)
+                    .unwrap())
+                .unwrap()
+                .to_arcseconds(),
+            -3600.0
+        );
+        assert!(max_altitude(&[]).is_none());
+        assert!(max_altitude(&[
+            Angle::new(0, 0, 1.0),
+            Angle::new(0, 0, f64::NAN)
+        ])
+        .is_none());
+    }
+
+    #[test]
+    fn observer_defaults_and_overridden_atmosphere() {
+        let observer = Observer::at(51.4769, -0.0005);
+        assert_eq!(observer.coord.lat, 51.4769);
+        assert_eq!(observer.coord.lng, -0.0005);
+        assert_eq!(
+            observer.rise_set_correction(),
+            0.0
+        );
+        assert_eq!(
+            observer.refraction(10.0),
+            refraction_from_altitude(
+                10.0, 1012.0, 10.0
+            )
+        );
+
+        // Lower pressure bends the light less, and
+        // colder air more.
+        let thin = Observer {
+            pressure_mbar: 700.0,
+            ..observer
+        };
+        let cold = Observer {
+            temp_c: -20.0,
+            ..observer
+        };
+        assert!(
+            thin.refraction(10.0)
+                < observer.refraction(10.0)
+        );
+        assert!(
+            cold.refraction(10.0)
+                > observer.refraction(10.0)
+        );
+        assert!(thin.rise_set_correction() < 0.0);
+        assert!(cold.rise_set_correction() > 0.0);
+
+        // The height adds the dip.
+        let high = Observer {
+            height_m: 100.0,
+            ..observer
+        };
+        assert_eq!(
+            high.rise_set_correction(),
+            dip_correction(100.0)
+        );
+
+        let horiz = HorizCoord {
+            alt: Angle::new(10, 0, 0.0),
+            azi: Angle::new(180, 0, 0.0),
+        };
+        let apparent = cold.apply_refraction(horiz);
+        assert!(
+            (decimal_hours_from_angle(apparent.alt)
+                - 10.0
+                - cold.refraction(10.0))
+            .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn negative_angle_to_duration_and_back() {
+        let angle = Angle::new(-3, 25, 12.345_678_9);
+        let duration: Duration = angle.into();
+        assert!(duration < Duration::zero());
+        assert_eq!(
+            duration,
+            -(Duration::hours(3)
+                + Duration::minutes(25)
+                + Duration::nanoseconds(
+                    12_345_678_900
+                ))
+        );
+
+        let angle_1 =
+            Angle::try_from(duration).unwrap();
+        assert_eq!(angle_1.hour(), -3);
+        assert_eq!(angle_1.minute(), 25);
+        assert!(
+            (angle_1.second() - 12.345_678_9).abs()
+                < 1e-9
+        );
+
+        // The sign on the seconds
+        let angle = Angle::new(0, 0, -30.5);
+        let duration: Duration = angle.into();
+        assert_eq!(
+            duration,
+            Duration::milliseconds(-30_500)
+        );
+        let angle_1 =
+            Angle::try_from(duration).unwrap();
+        assert_eq!(angle_1.hour(), 0);
+        assert_eq!(angle_1.minute(), 0);
+        assert_eq!(angle_1.second(), -30.5);
+    }
+
+    #[test]
+    fn rise_set_constructors_set_the_status() {
+        let t = NaiveTime::from_hms(6, 30, 0);
+
+        let normal = RiseSet::new(Some(t), Some(t));
+        assert_eq!(
+            normal.status,
+            RiseSetStatus::Normal
+        );
+        assert_eq!(normal.rise, Some(t));
+        assert_eq!(normal.set, Some(t));
+
+        let circumpolar = RiseSet::circumpolar();
+        assert_eq!(
+            circumpolar.status,
+            RiseSetStatus::Circumpolar
+        );
+        assert!(circumpolar.rise.is_none());
+        assert!(circumpolar.set.is_none());
+
+        let never_rises = RiseSet::never_rises();
+        assert_eq!(
+            never_rises.status,
+            RiseSetStatus::NeverRises
+        );
+        assert!(never_rises.rise.is_none());
+        assert!(never_rises.set.is_none());
+    }
+
+    #[test]
+    fn precomputed_obliquity_matches_the_date() {
+        let date = NaiveDate::from_ymd(1980, 4, 22);
+        let obliquity =
+            mean_obliquity_of_the_epliptic(date);
+
+        for &(lat, lng) in [
+            (4.875, 139.686),
+            (-30.0, 300.0),
+            (0.0, 0.0),
+        ]
+        .iter()
+        {
+            let equa_0 =
+                equatorial_from_ecliptic_with_obliquity(
+                    EcliCoord { lat, lng },
+                    obliquity,
+                );
+            let equa_1 =
+                equatorial_from_ecliptic_with_generic_date(
+                    EcliCoord { lat, lng },
+                    date,
+                );
+            let asc =
+                decimal_hours_from_angle(equa_0.asc);
+            let dec =
+                decimal_hours_from_angle(equa_0.dec);
+            assert_eq!(
+                asc.to_bits(),
+                decimal_hours_from_angle(equa_1.asc)
+                    .to_bits()
+            );
+            assert_eq!(
+                dec.to_bits(),
+                decimal_hours_from_angle(equa_1.dec)
+                    .to_bits()
+            );
+
+            let ecli_0 =
+                ecliptic_from_equatorial_with_obliquity(
+                    equa_0, obliquity,
+                );
+            let ecli_1 =
+                ecliptic_from_equatorial_with_generic_date(
+                    equa_1, date,
+                );
+            assert_eq!(
+                ecli_0.lat.to_bits(),
+                ecli_1.lat.to_bits()
+            );
+            assert_eq!(
+                ecli_0.lng.to_bits(),
+                ecli_1.lng.to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn galactic_coordinates_for_j2000() {
+        let epoch = GalacticEpoch::J2000;
+        let (pole_asc, pole_dec, _) = epoch.pole();
+
+        // The north galactic pole
+        let galac =
+            galactic_from_equatorial_with_epoch(
+                EquaCoord {
+                    asc: RightAscension::new(
+                        angle_from_decimal_hours(
+                            pole_asc / 15.0,
+                        ),
+                    ),
+                    dec: Declination::new(
+                        angle_from_decimal_hours(
+                            pole_dec,
+                        ),
+                    ),
+                },
+                epoch,
+            );
+        assert!((galac.lat - 90.0).abs() < 1e-6);
+
+        // The north celestial pole is at l = 122.93192°.
+        let galac =
+            galactic_from_equatorial_with_epoch(
+                EquaCoord {
+                    asc: RightAscension::new(
+                        Angle::new(0, 0, 0.0),
+                    ),
+                    dec: Declination::new(
+                        Angle::new(89, 59, 59.999),
+                    ),
+                },
+                epoch,
+            );
+        assert!(
+            (galac.lng - 122.931_92).abs() < 1e-3
+        );
+        assert!((galac.lat - pole_dec).abs() < 1e-3);
+
+        // Deneb (J2000.0) at l = 84.285°, b = 1.998°
+        let coord = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                20, 41, 25.915,
+            )),
+            dec: Declination::new(Angle::new(
+                45, 16, 49.22,
+            )),
+        };
+        let asc = decimal_hours_from_angle(coord.asc);
+        let dec = decimal_hours_from_angle(coord.dec);
+        let galac =
+            galactic_from_equatorial_with_epoch(
+                coord, epoch,
+            );
+        assert!((galac.lng - 84.285).abs() < 2e-3);
+        assert!((galac.lat - 1.998).abs() < 2e-3);
+
+        // Round trip
+        let equa =
+            equatorial_from_galactic_with_epoch(
+                galac, epoch,
+            );
+        assert!(
+            (decimal_hours_from_angle(equa.asc)
+                - asc)
+                .abs()
+                < 1e-9
+        );
+        assert!(
+            (decimal_hours_from_angle(equa.dec)
+                - dec)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn galactic_longitude_for_b1950_is_below_360() {
+        // Before adding the node (33°), atan2 lies in
+        // [327°, 360°) for l in [0°, 33°), which used
+        // to come out as l + 360°.
+        for lng in
+            [0.5, 10.0, 32.5, 327.5, 345.0, 359.5]
+        {
+            let equa = equatorial_from_galactic(
+                GalacCoord { lat: 10.0, lng },
+            );
+            let galac =
+                galactic_from_equatorial(equa);
+            assert!(
+                (0.0..360.0).contains(&galac.lng),
+                "{}",
+                galac.lng
+            );
+            assert!((galac.lng - lng).abs() < 1e-6);
+            assert!((galac.lat - 10.0).abs() < 1e-6);
+        }
+
+        // The galactic center (B1950) at l = 0°, b = 0°
+        // (Duffett-Smith's pole and node round it to
+        // 0.1°), just below 360° or above 0°.
+        let galac =
+            galactic_from_equatorial(EquaCoord {
+                asc: RightAscension::new(Angle::new(
+                    17, 42, 24.0,
+                )),
+                dec: Declination::new(Angle::new(
+                    -28, 55, 0.0,
+                )),
+            });
+        assert!((0.0..360.0).contains(&galac.lng));
+        assert!(
+            galac.lng < 0.5 || galac.lng > 359.5,
+            "{}",
+            galac.lng
+        );
+    }
+
+    #[test]
+    fn coord_new_folds_directions_into_signs() {
+        let coord = Coord::new(
+            33.87,
+            Direction::South,
+            64.0,
+            Direction::West,
+        );
+        assert_eq!(coord.latitude_signed(), -33.87);
+        assert_eq!(coord.longitude_signed(), -64.0);
+
+        let coord = Coord::new(
+            35.68,
+            Direction::North,
+            139.65,
+            Direction::East,
+        );
+        assert_eq!(coord.latitude_signed(), 35.68);
+        assert_eq!(coord.longitude_signed(), 139.65);
+
+        // The directions decide the signs.
+        let coord = Coord::new(
+            -10.0,
+            Direction::North,
+            -20.0,
+            Direction::East,
+        );
+        assert_eq!(coord.latitude_signed(), 10.0);
+        assert_eq!(coord.longitude_signed(), 20.0);
+
+        let (lng, dir) = Coord::new(
+            52.0,
+            Direction::North,
+            64.0,
+            Direction::West,
+        )
+        .lng_with_direction();
+        assert_eq!(lng, 64.0);
+        assert!(matches!(dir, Direction::West));
+    }
+
+    #[test]
+    #[should_panic]
+    fn coord_new_rejects_east_for_latitude() {
+        Coord::new(
+            10.0,
+            Direction::East,
+            20.0,
+            Direction::East,
+        );
+    }
+
+    #[test]
+    fn coord_try_new_rejects_wrong_directions() {
+        assert_eq!(
+            Coord::try_new(
+                10.0,
+                Direction::East,
+                20.0,
+                Direction::East,
+            )
+            .err(),
+            Some(SowngwalaError::InvalidDirection)
+        );
+        assert_eq!(
+            Coord::try_new(
+                10.0,
+                Direction::North,
+                20.0,
+                Direction::South,
+            )
+            .err(),
+            Some(SowngwalaError::InvalidDirection)
+        );
+    }
+
+    #[test]
+    fn angle_report_is_consistent() {
+        let angle = Angle::new(23, 13, 10.0);
+        let report: AngleReport = angle.report();
+
+        let degrees: f64 =
+            23.0 + 13.0 / 60.0 + 10.0 / 3600.0;
+
+        assert_eq!(report.sexagesimal, "23°13′10″");
+        assert!(
+            (report.decimal_degrees - degrees).abs()
+                < 1e-12
+        );
+        assert!(
+            (report.decimal_hours * 15.0
+                - report.decimal_degrees)
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            (report.radians.to_degrees()
+                - report.decimal_degrees)
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            (report.total_arcsec - 83_590.0).abs()
+                < 1e-9
+        );
+
+        let parsed: Angle =
+            report.sexagesimal.parse().unwrap();
+        assert_eq!(parsed.hour(), 23);
+        assert_eq!(parsed.minute(), 13);
+        assert_eq!(parsed.second(), 10.0);
+    }
+
+    #[test]
+    fn angle_from_str_with_each_delimiter_style() {
+        assert_eq!(parse("18:31:27"), (18, 31, 27.0));
+        assert_eq!(
+            parse("18h31m27s"),
+            (18, 31, 27.0)
+        );
+        assert_eq!(
+            parse("18h 31m 27.5s"),
+            (18, 31, 27.5)
+        );
+        assert_eq!(
+            parse("-8° 13′ 30″"),
+            (-8, 13, 30.0)
+        );
+        assert_eq!(
+            parse("-8d13'30\""),
+            (-8, 13, 30.0)
+        );
+        assert_eq!(
+            parse("23 13 10.5"),
+            (23, 13, 10.5)
+        );
+        assert_eq!(parse(" +23 13 "), (23, 13, 0.0));
+        assert_eq!(parse("-0:13:10"), (0, -13, 10.0));
+        assert_eq!(parse("-0:0:30"), (0, 0, -30.0));
+    }
+
+    #[test]
+    fn angle_from_str_with_malformed_inputs() {
+        assert_eq!(
+            "18:3x:27".parse::<Angle>().unwrap_err(),
+            AngleParseError::InvalidNumber(
+                "3x".into()
+            )
+        );
+        assert_eq!(
+            "18.5:30:00"
+                .parse::<Angle>()
+                .unwrap_err(),
+            AngleParseError::InvalidNumber(
+                "18.5".into()
+            )
+        );
+        assert_eq!(
+            "1:2:3:4".parse::<Angle>().unwrap_err(),
+            AngleParseError::TooManyComponents(4)
+        );
+        assert_eq!(
+            "".parse::<Angle>().unwrap_err(),
+            AngleParseError::TooManyComponents(0)
+        );
+        assert_eq!(
+            "18:31:60".parse::<Angle>().unwrap_err(),
+            AngleParseError::OutOfRange("60".into())
+        );
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn equatorial_coordinate_survives_json_round_trip(
+    ) {
+        use super::*;
+        use approx_eq::assert_approx_eq;
+
+        let coord_0 = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                9, 34, 53.582_162,
+            )),
+            dec: Declination::new(Angle::new(
+                19, 32, 14.100_993,
+            )),
+        };
+
+        let json: String =
+            serde_json::to_string(&coord_0).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"asc\":{\"hour\":9,\"minute\":34,\"second\":53.582162},\"dec\":{\"hour\":19,\"minute\":32,\"second\":14.100993}}"
+        );
+
+        let coord: EquaCoord =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(coord.asc.hour(), 9);
+        assert_eq!(coord.asc.minute(), 34);
+        assert_approx_eq!(
+            coord.asc.second(),
+            coord_0.asc.second(),
+            1e-12
+        );
+        assert_eq!(coord.dec.hour(), 19);
+        assert_eq!(coord.dec.minute(), 32);
+        assert_approx_eq!(
+            coord.dec.second(),
+            coord_0.dec.second(),
+            1e-12
+        );
+
+        // The sign from `with_sign` is written only
+        // when set.
+        let dec_0 = Declination::new(
+            Angle::with_sign(-1, 0, 6, 0.0),
+        );
+        let json: String =
+            serde_json::to_string(&dec_0).unwrap();
+
+        assert_eq!(
+            json,
+            "{\"hour\":0,\"minute\":6,\"second\":0.0,\"negative\":true}"
+        );
+
+        let dec: Declination =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(dec.sign(), -1);
+        assert_eq!(dec.minute(), -6);
+    }
+
+    #[test]
+    fn ecliptic_and_equatorial_round_trip() {
+        let date = NaiveDate::from_ymd(1980, 4, 22);
+        let arcsec: f64 = 1.0 / 3600.0;
+
+        // Leaving out the poles (β = ±90°) where
+        // longitude (λ) is undefined.
+        for i in -17..=17 {
+            for j in 0..72 {
+                let lat: f64 =
+                    (i as f64) * 5.0 + 0.37;
+                let lng: f64 =
+                    (j as f64) * 5.0 + 0.21;
+                let coord: EcliCoord =
+                    ecliptic_from_equatorial_with_generic_date(
+                        equatorial_from_ecliptic_with_generic_date(
+                            EcliCoord { lat, lng },
+                            date,
+                        ),
+                        date,
+                    );
+
+                let lng_diff: f64 =
+                    crate::utils::normalize_angle(
+                        coord.lng - lng,
+                        360.0,
+                    );
+
+                assert!(
+                    (coord.lat - lat).abs()
+                        < arcsec * 0.1,
+                    "({}, {}) -> {:?}",
+                    lat,
+                    lng,
+                    coord
+                );
+                assert!(
+                    lng_diff.abs() < arcsec * 0.1,
+                    "({}, {}) -> {:?}",
+                    lat,
+                    lng,
+                    coord
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn angular_separation_as_angle_matches_f64() {
+        let equa =
+            |h: i32, m: i32, s: f64, d: i32| {
+                EquaCoord {
+                    asc: RightAscension::new(
+                        Angle::new(h, m, s),
+                    ),
+                    dec: Declination::new(
+                        Angle::new(d, 0, 0.0),
+                    ),
+                }
+            };
+        let degrees: f64 =
+            angle_between_two_celestial_objects_for_equatorial(
+                equa(5, 13, 31.7, -8),
+                equa(6, 44, 13.4, -16),
+            );
+        let angle: Angle =
+            angular_separation_equatorial(
+                equa(5, 13, 31.7, -8),
+                equa(6, 44, 13.4, -16),
+            );
+        assert!(
+            (decimal_hours_from_angle(angle)
+                - degrees)
+                .abs()
+                < 1e-9
+        );
+
+        let galac = |lat: f64, lng: f64| GalacCoord {
+            lat,
+            lng,
+        };
+
+        // 90° apart along the galactic equator
+        let degrees: f64 =
+            angle_between_two_celestial_objects_for_galactic(
+                galac(0.0, 30.0),
+                galac(0.0, 120.0),
+            );
+        let angle: Angle =
+            angular_separation_galactic(
+                galac(0.0, 30.0),
+                galac(0.0, 120.0),
+            );
+        assert!((degrees - 90.0).abs() < 1e-9);
+        assert!(
+            (decimal_hours_from_angle(angle)
+                - degrees)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn cartesian_round_trips_for_each_coordinate() {
+        let close =
+            |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        let equa = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                18, 32, 21.0,
+            )),
+            dec: Declination::new(Angle::new(
+                -23, 13, 10.0,
+            )),
+        };
+        let back = EquaCoord::from_cartesian(
+            equa.to_cartesian(),
+        );
+        assert!(close(
+            decimal_hours_from_angle(back.asc),
+            decimal_hours_from_angle(equa.asc)
+        ));
+        assert!(close(
+            decimal_hours_from_angle(back.dec),
+            decimal_hours_from_angle(equa.dec)
+        ));
+
+        let ecli = EcliCoord {
+            lat: 4.875_278,
+            lng: 139.686_111,
+        };
+        let back = EcliCoord::from_cartesian(
+            ecli.to_cartesian(),
+        );
+        assert!(close(back.lat, ecli.lat));
+        assert!(close(back.lng, ecli.lng));
+
+        let galac = GalacCoord {
+            lat: -51.66,
+            lng: 350.5,
+        };
+        let back = GalacCoord::from_cartesian(
+            galac.to_cartesian(),
+        );
+        assert!(close(back.lat, galac.lat));
+        assert!(close(back.lng, galac.lng));
+
+        let horiz = HorizCoord {
+            alt: Angle::new(19, 20, 3.64),
+            azi: Angle::new(283, 16, 15.7),
+        };
+        let back = HorizCoord::from_cartesian(
+            horiz.to_cartesian(),
+        );
+        assert!(close(
+            decimal_hours_from_angle(back.alt),
+            decimal_hours_from_angle(horiz.alt)
+        ));
+        assert!(close(
+            decimal_hours_from_angle(back.azi),
+            decimal_hours_from_angle(horiz.azi)
+        ));
+    }
+
+    #[test]
+    fn cartesian_dot_product_is_cos_of_separation() {
+        let equa =
+            |h: i32, m: i32, s: f64, d: i32| {
+                EquaCoord {
+                    asc: RightAscension::new(
+                        Angle::new(h, m, s),
+                    ),
+                    dec: Declination::new(
+                        Angle::new(d, 0, 0.0),
+                    ),
+                }
+            };
+        let a = equa(5, 13, 31.7, -8);
+        let b = equa(6, 44, 13.4, -16);
+
+        let [x0, y0, z0] = a.to_cartesian();
+        let [x1, y1, z1] = b.to_cartesian();
+        let dot: f64 = x0 * x1 + y0 * y1 + z0 * z1;
+
+        let separation: f64 =
+            angle_between_two_celestial_objects_for_equatorial(
+                a, b,
+            );
+        assert!(
+            (dot - separation.to_radians().cos())
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn slerp_equatorial_along_the_great_circle() {
+        let equa =
+            |h: i32, m: i32, s: f64, d: i32| {
+                EquaCoord {
+                    asc: RightAscension::new(
+                        Angle::new(h, m, s),
+                    ),
+                    dec: Declination::new(
+                        Angle::new(d, 0, 0.0),
+                    ),
+                }
+            };
+        let decimal = |coord: &EquaCoord| {
+            (
+                decimal_hours_from_angle(coord.asc),
+                decimal_hours_from_angle(coord.dec),
+            )
+        };
+        let close = |a: (f64, f64), b: (f64, f64)| {
+            (a.0 - b.0).abs() < 1e-9
+                && (a.1 - b.1).abs() < 1e-9
+        };
+        let a = || equa(5, 13, 31.7, -8);
+        let b = || equa(6, 44, 13.4, -16);
+
+        assert!(close(
+            decimal(&slerp_equatorial(a(), b(), 0.0)),
+            decimal(&a())
+        ));
+        assert!(close(
+            decimal(&slerp_equatorial(a(), b(), 1.0)),
+            decimal(&b())
+        ));
+
+        // The midpoint is half way from both.
+        let separation: f64 =
+            angle_between_two_celestial_objects_for_equatorial(
+                a(),
+                b(),
+            );
+        let sep_a: f64 =
+            angle_between_two_celestial_objects_for_equatorial(
+                slerp_equatorial(a(), b(), 0.5),
+                a(),
+            );
+        let sep_b: f64 =
+            angle_between_two_celestial_objects_for_equatorial(
+                slerp_equatorial(a(), b(), 0.5),
+                b(),
+            );
+        assert!(
+            (sep_a - separation / 2.0).abs() < 1e-6
+        );
+        assert!(
+            (sep_b - separation / 2.0).abs() < 1e-6
+        );
+
+        // The same coordinates
+        assert!(close(
+            decimal(&slerp_equatorial(a(), a(), 0.5)),
+            decimal(&a())
+        ));
+
+        // The opposite coordinates go through the pole.
+        let mid: EquaCoord = slerp_equatorial(
+            equa(0, 0, 0.0, 0),
+            equa(12, 0, 0.0, 0),
+            0.5,
+        );
+        assert!(
+            (decimal(&mid).1 - 90.0).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn apparent_and_mean_places_differ_by_arcseconds()
+    {
+        let date = NaiveDate::from_ymd(1987, 4, 10);
+        let (d_psi, d_eps): (f64, f64) =
+            nutation(date);
+
+        // On the ecliptic at the vernal equinox, the
+        // right ascension shifts by Δψ cos ε, and the
+        // declination by Δψ sin ε.
+        let coord =
+            || EcliCoord { lat: 0.0, lng: 0.0 };
+        let apparent: EquaCoord =
+            equatorial_from_ecliptic_apparent(
+                coord(),
+                date,
+            );
+        let mean: EquaCoord =
+            equatorial_from_ecliptic_with_generic_date(
+                coord(),
+                date,
+            );
+
+        let oblique: f64 =
+            (mean_obliquity_of_the_epliptic(date)
+                + d_eps)
+                .to_radians();
+        let mut d_asc: f64 =
+            (decimal_hours_from_angle(apparent.asc)
+                - decimal_hours_from_angle(mean.asc))
+                * 15.0;
+        if d_asc > 180.0 {
+            d_asc -= 360.0;
+        }
+        let d_dec: f64 =
+            decimal_hours_from_angle(apparent.dec)
+                - decimal_hours_from_angle(mean.dec);
+
+        // About -3.5" and -1.5" (Δψ is about -3.8")
+        assert!(
+            (d_asc - d_psi * oblique.cos()).abs()
+                * 3600.0
+                < 0.01
+        );
+        assert!(
+            (d_dec - d_psi * oblique.sin()).abs()
+                * 3600.0
+                < 0.01
+        );
+        assert!(d_asc.abs() * 3600.0 > 1.0);
+        assert!(d_asc.abs() * 3600.0 < 20.0);
+    }
+
+    #[test]
+    fn round_seconds_carries_over() {
+        let angle = Angle::new(23, 59, 59.9999)
+            .round_seconds(0);
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (24, 0, 0.0)
+        );
+
+        // Not rounded up
+        let angle =
+            Angle::new(23, 59, 59.4).round_seconds(0);
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (23, 59, 59.0)
+        );
+
+        // Negative angles keep the sign on the first
+        // nonzero component.
+        let angle = Angle::new(-8, 59, 59.96)
+            .round_seconds(1);
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (-9, 0, 0.0)
+        );
+        let angle = Angle::new(0, -2, 41.996)
+            .round_seconds(2);
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (0, -2, 42.0)
+        );
+        let angle =
+            Angle::new(0, 0, -59.96).round_seconds(1);
+        assert_eq!(
+            (
+                angle.hour(),
+                angle.minute(),
+                angle.second()
+            ),
+            (0, -1, 0.0)
+        );
+    }
+
+    #[test]
+    fn round_seconds_does_not_overflow() {
+        let angle =
+            Angle::new(12, 30, 15.123_456_789);
+
+        // Clamped to 9 decimal places
+        let rounded = angle.round_seconds(u32::MAX);
+        assert_eq!(
+            rounded.second(),
+            angle.round_seconds(9).second()
+        );
+        assert_eq!(
+            (rounded.hour(), rounded.minute()),
+            (12, 30)
+        );
+
+        // Too large for nanoseconds, returned as it is
+        let angle = Angle::new(i32::MAX, 0, 0.5);
+        let rounded = angle.round_seconds(9);
+        assert_eq!(
+            (
+                rounded.hour(),
+                rounded.minute(),
+                rounded.second()
+            ),
+            (i32::MAX, 0, 0.5)
+        );
+        let rounded = Angle::new(0, 0, f64::NAN)
+            .round_seconds(2);
+        assert!(rounded.second().is_nan());
+    }
+
+    #[test]
+    fn best_transit_tonight_sorts_by_altitude() {
+        use approx_eq::assert_approx_eq;
+
+        let date = NaiveDate::from_ymd(2022, 1, 1);
+        let coord = Coord {
+            lat: 35.68,
+            lng: 139.65,
+        };
+        let object = |name: &str,
+                      asc: f64,
+                      dec: f64| {
+            (
+                name.to_string(),
+                EquaCoord {
+                    asc: RightAscension::new(
+                        angle_from_decimal_hours(asc),
+                    ),
+                    dec: Declination::new(
+                        angle_from_decimal_hours(dec),
+                    ),
+                },
+            )
+        };
+        let objects = vec![
+            object("low", 2.0, -30.0),
+            object("below", 4.0, -70.0),
+            object("zenith", 6.0, 35.68),
+            object("north", 8.0, 60.0),
+        ];
+
+        let best = best_transit_tonight(
+            &objects, date, coord,
+        );
+        let names: Vec<&str> = best
+            .iter()
+            .map(|t| t.0.as_str())
+            .collect();
+
+        // Never above the horizon at the transit
+        assert_eq!(
+            names,
+            vec!["zenith", "north", "low"]
+        );
+
+        // 90° - |φ - δ|
+        for (t, &expected) in best
+            .iter()
+            .zip([90.0, 65.68, 24.32].iter())
+        {
+            assert_approx_eq!(
+                decimal_hours_from_angle(t.2),
+                expected,
+                1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn angle_plus_duration_carries_over_at_24h() {
+        let angle = Angle::new(22, 15, 30.5);
+
+        let angle_1 = angle
+            + Duration::hours(3)
+            + Duration::milliseconds(250);
+        assert_eq!(angle_1.hour(), 1);
+        assert_eq!(angle_1.minute(), 15);
+        assert!(
+            (angle_1.second() - 30.75).abs() < 1e-9
+        );
+        assert_eq!(angle_1.day_excess(), 1.0);
+
+        // The day excess accumulates, and subtracting
+        // it back returns to the original.
+        let angle_2 = angle_1 + Duration::hours(48);
+        assert_eq!(angle_2.day_excess(), 3.0);
+
+        let angle_3 = angle_2
+            - Duration::hours(51)
+            - Duration::milliseconds(250);
+        assert_eq!(angle_3, angle);
+        assert_eq!(angle_3.day_excess(), 0.0);
+
+        // Adding a negative duration is subtracting.
+        assert_eq!(
+            angle + Duration::minutes(-30),
+            angle - Duration::minutes(30)
+        );
+    }
+
+    #[test]
+    fn approx_eq_of_coordinates_within_tolerance() {
+        let equa =
+            |asc_sec: f64, dec_sec: f64| EquaCoord {
+                asc: RightAscension::new(Angle::new(
+                    6, 45, asc_sec,
+                )),
+                dec: Declination::new(Angle::new(
+                    -16, 42, dec_sec,
+                )),
+            };
+        let coord = equa(8.9, 58.0);
+
+        // 0.5" accepted, and 2" rejected at 1"
+        assert!(
+            coord.approx_eq(&equa(8.9, 58.5), 1.0)
+        );
+        assert!(
+            !coord.approx_eq(&equa(8.9, 56.0), 1.0)
+        );
+        // 0.1 second of time is 1.5"
+        assert!(
+            !coord.approx_eq(&equa(9.0, 58.0), 1.0)
+        );
+        assert!(
+            coord.approx_eq(&equa(9.0, 58.0), 2.0)
+        );
+
+        // Across 0h
+        let a = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                23, 59, 59.98,
+            )),
+            dec: Declination::new(Angle::new(
+                0, 0, 0.0,
+            )),
+        };
+        let b = EquaCoord {
+            asc: RightAscension::new(Angle::new(
+                0, 0, 0.0,
+            )),
+            dec: Declination::new(Angle::new(
+                0, 0, 0.0,
+            )),
+        };
+        assert!(a.approx_eq(&b, 1.0));
+
+        let horiz = |azi_sec: f64| HorizCoord {
+            alt: Angle::new(45, 0, 0.0),
+            azi: Angle::new(359, 59, azi_sec),
+        };
+        assert!(horiz(59.5).approx_eq(
+            &HorizCoord {
+                alt: Angle::new(45, 0, 0.0),
+                azi: Angle::new(0, 0, 0.0),
+            },
+            1.0
+        ));
+        assert!(
+            !horiz(58.0).approx_eq(&horiz(56.0), 1.0)
+        );
+
+        let ecli = EcliCoord {
+            lat: 1.0,
+            lng: 120.0,
+        };
+        assert!(ecli.approx_eq(
+            &EcliCoord {
+                lat: 1.0 + 0.5 / 3600.0,
+                lng: 120.0,
+            },
+            1.0
+        ));
+        assert!(!ecli.approx_eq(
+            &EcliCoord {
+                lat: 1.0,
+                lng: 120.0 + 2.0 / 3600.0,
+            },
+            1.0
+        ));
+    }
+
+    #[test]
+    fn parallax_keeps_declination_far_from_the_meridian(
+    ) {
+        let tokyo = Coord {
+            lat: 35.6762,
+            lng: 139.6503,
+        };
+        // The moon at some 60 earth radii
+        for &(asc, dec) in
+            [(6.0, -27.0), (18.0, 27.0), (0.0, 0.0)]
+                .iter()
+        {
+            // H from 0h to 23h, including 12h (the
+            // object under the observer's feet)
+            for hour in 0..24 {
+                let lst = NaiveTime::from_hms(
+                    ((asc as u32) + hour) % 24,
+                    0,
+                    0,
+                );
+                let topo = apply_parallax_equatorial(
+                    EquaCoord {
+                        asc: RightAscension::new(
+                            angle_from_decimal_hours(
+                                asc,
+                            ),
+                        ),
+                        dec: Declination::new(
+                            angle_from_decimal_hours(
+                                dec,
+                            ),
+                        ),
+                    },
+                    tokyo,
+                    60.0,
+                    lst,
+                );
+                let dec_1: f64 =
+                    decimal_hours_from_angle(
+                        topo.dec,
+                    );
+                assert!(dec_1.abs() <= 90.0);
+                assert!(
+                    (dec_1 - dec).abs() < 1.0,
+                    "H = {}h: {} vs {}",
+                    hour,
+                    dec_1,
+                    dec
+                );
+
+                let mut d_asc: f64 =
+                    decimal_hours_from_angle(
+                        topo.asc,
+                    ) - asc;
+                d_asc -=
+                    24.0 * (d_asc / 24.0).round();
+                assert!(d_asc.abs() < 0.1);
+            }
+        }
+    }
 }
@@ -226,6 +226,92 @@ fn get_after_ad2150(year: f64) -> f64 {
     -20.0 + 32.0 * u.powi(2)
 }
 
+/// The first year of `DELTA_T_TABLE`.
+const DELTA_T_TABLE_START: f64 = 1973.0;
+
+/// Observed ΔT (in seconds) at the beginning of each
+/// year from 1973 to 2024 (IERS).
+const DELTA_T_TABLE: [f64; 52] = [
+    43.37, 44.49, 45.48, 46.46,
+    47.52, // 1973-1977
+    48.53, 49.59, 50.54, 51.38,
+    52.17, // 1978-1982
+    52.96, 53.79, 54.34, 54.87,
+    55.32, // 1983-1987
+    55.82, 56.30, 56.86, 57.57,
+    58.31, // 1988-1992
+    59.12, 59.98, 60.78, 61.63,
+    62.30, // 1993-1997
+    62.97, 63.47, 63.83, 64.09,
+    64.30, // 1998-2002
+    64.47, 64.57, 64.69, 64.85,
+    65.15, // 2003-2007
+    65.46, 65.78, 66.07, 66.32,
+    66.60, // 2008-2012
+    66.91, 67.28, 67.64, 68.10,
+    68.59, // 2013-2017
+    68.97, 69.22, 69.36, 69.36,
+    69.29, // 2018-2022
+    69.20, 69.18, // 2023-2024
+];
+
+/// Given a decimal year, returns ΔT (in seconds)
+/// linearly interpolated from the observed values
+/// (1973 to 2024). Returns `None` outside the table,
+/// so that the caller can fall back to the polynomial
+/// expressions.
+///
+/// * `year` - Decimal year (ex. 2000.5)
+///
+/// References:
+/// - IERS, Bulletins
+///   (https://www.iers.org/IERS/EN/Publications/Bulletins/bulletins.html)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::delta_t::delta_t_from_table;
+///
+/// assert_approx_eq!(
+///     delta_t_from_table(2000.0).unwrap(),
+///     63.83,
+///     1e-6
+/// );
+/// assert_approx_eq!(
+///     delta_t_from_table(2000.5).unwrap(),
+///     63.96,
+///     1e-6
+/// );
+/// assert_eq!(delta_t_from_table(1900.0), None);
+/// assert_eq!(delta_t_from_table(2100.0), None);
+/// ```
+pub fn delta_t_from_table(year: f64) -> Option<f64> {
+    let x = year - DELTA_T_TABLE_START;
+    let last = (DELTA_T_TABLE.len() - 1) as f64;
+    if !(0.0..=last).contains(&x) {
+        return None;
+    }
+    let i = (x.floor() as usize)
+        .min(DELTA_T_TABLE.len() - 2);
+    let frac = x - (i as f64);
+
+    Some(
+        DELTA_T_TABLE[i]
+            + (DELTA_T_TABLE[i + 1]
+                - DELTA_T_TABLE[i])
+                * frac,
+    )
+}
+
+/// Given a date, returns ΔT (in seconds). Prefers the
+/// observed values (`delta_t_from_table`) when the
+/// year is in the table, and falls back to the
+/// polynomial expressions otherwise. The decimal year
+/// is that of `decimal_year_from_generic_date`
+/// (the middle of the month).
+///
+/// * `date` - Datelike
+///
 /// Example:
 /// ```rust
 /// use approx_eq::assert_approx_eq;
@@ -235,21 +321,24 @@ fn get_after_ad2150(year: f64) -> f64 {
 /// let date = NaiveDate::from_ymd(1986, 1, 1);
 /// let delta_t = delta_t_from_generic_date(date);
 ///
+/// // Between 54.87 (1986) and 55.32 (1987) in the
+/// // table, at the middle of January (1986.04)
 /// assert_approx_eq!(
-///     delta_t, // 54.89627599023825
-///     54.87,
-///     1e-3
+///     delta_t, // 54.88875000000003
+///     54.889,
+///     1e-5
 /// );
 /// ```
 pub fn delta_t_from_generic_date<T>(date: T) -> f64
 where
     T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     let year = decimal_year_from_generic_date(date);
 
+    if let Some(delta_t) = delta_t_from_table(year) {
+        return delta_t;
+    }
+
     if year < -500.0 {
         get_before_bc500(year)
     } else if year < 500.0 {
@@ -282,3 +371,62 @@ where
         get_after_ad2150(year)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::naive::NaiveDate;
+
+    #[test]
+    fn table_matches_observed_values() {
+        for &(y, expected) in [
+            (1973.0, 43.37),
+            (1990.0, 56.86),
+            (2000.0, 63.83),
+            (2010.0, 66.07),
+            (2024.0, 69.18),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                delta_t_from_table(y),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn table_is_preferred_within_its_range() {
+        for y in 1973..2024 {
+            let date = NaiveDate::from_ymd(y, 7, 1);
+            let year =
+                decimal_year_from_generic_date(date);
+            assert_eq!(
+                delta_t_from_generic_date(date),
+                delta_t_from_table(year).unwrap()
+            );
+        }
+
+        // July 2000 (2000.54), between 63.83 and 64.09
+        let date = NaiveDate::from_ymd(2000, 7, 1);
+        assert!(
+            (delta_t_from_generic_date(date) - 63.97)
+                .abs()
+                < 0.01
+        );
+    }
+
+    #[test]
+    fn polynomials_are_used_outside_the_table() {
+        assert_eq!(delta_t_from_table(1972.9), None);
+        assert_eq!(delta_t_from_table(2024.1), None);
+
+        let date = NaiveDate::from_ymd(1950, 1, 1);
+        assert_eq!(
+            delta_t_from_generic_date(date),
+            get_ad1941_to_ad1961(
+                decimal_year_from_generic_date(date)
+            )
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+
+use crate::sun::KeplerError;
+use crate::time::TimeError;
+use crate::units::UnitError;
+use crate::utils::OverflowError;
+
+/// Errors for the whole crate. Each module has its
+/// own errors (`KeplerError`, `TimeError`, and so on),
+/// which all convert into this one, so that `?` works
+/// across the modules.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::error::SowngwalaError;
+/// use sowngwala::utils::{try_overflow, OverflowError};
+///
+/// fn minutes(value: f64) -> Result<f64, SowngwalaError> {
+///     let (minutes, _hours) = try_overflow(value, 60.0)?;
+///     Ok(minutes)
+/// }
+///
+/// assert_eq!(minutes(75.0), Ok(15.0));
+/// assert_eq!(
+///     minutes(f64::INFINITY),
+///     Err(SowngwalaError::Overflow(
+///         OverflowError::NotFinite(f64::INFINITY)
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SowngwalaError {
+    /// Kepler's equation did not converge.
+    Kepler(KeplerError),
+    /// Local times could not be converted.
+    Time(TimeError),
+    /// Units could not be built.
+    Unit(UnitError),
+    /// The value could not carry over.
+    Overflow(OverflowError),
+    /// The argument is `NaN` or infinite.
+    NotFinite(f64),
+    /// The step (for iterating over a range) is 0.
+    ZeroStep,
+    /// The direction does not go with the axis, such
+    /// as East for a latitude.
+    InvalidDirection,
+}
+
+impl fmt::Display for SowngwalaError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            SowngwalaError::Kepler(e) => e.fmt(f),
+            SowngwalaError::Time(e) => e.fmt(f),
+            SowngwalaError::Unit(e) => e.fmt(f),
+            SowngwalaError::Overflow(e) => e.fmt(f),
+            SowngwalaError::NotFinite(v) => {
+                write!(f, "Not a finite value: {}", v)
+            }
+            SowngwalaError::ZeroStep => {
+                write!(f, "Step must not be 0")
+            }
+            SowngwalaError::InvalidDirection => write!(
+                f,
+                "Latitude is either North or South, and longitude either East or West"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SowngwalaError {
+    fn source(
+        &self,
+    ) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match self {
+            SowngwalaError::Kepler(e) => Some(e),
+            SowngwalaError::Time(e) => Some(e),
+            SowngwalaError::Unit(e) => Some(e),
+            SowngwalaError::Overflow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<KeplerError> for SowngwalaError {
+    fn from(e: KeplerError) -> Self {
+        SowngwalaError::Kepler(e)
+    }
+}
+
+impl From<TimeError> for SowngwalaError {
+    fn from(e: TimeError) -> Self {
+        SowngwalaError::Time(e)
+    }
+}
+
+impl From<UnitError> for SowngwalaError {
+    fn from(e: UnitError) -> Self {
+        SowngwalaError::Unit(e)
+    }
+}
+
+impl From<OverflowError> for SowngwalaError {
+    fn from(e: OverflowError) -> Self {
+        SowngwalaError::Overflow(e)
+    }
+}
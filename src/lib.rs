@@ -1,9 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 extern crate chrono;
 
+#[cfg(feature = "std")]
 pub mod constants;
+#[cfg(feature = "std")]
 pub mod coords;
+#[cfg(feature = "std")]
 pub mod delta_t;
+#[cfg(feature = "std")]
+pub mod error;
+pub mod math;
+#[cfg(feature = "std")]
 pub mod moon;
+#[cfg(feature = "std")]
+pub mod planet;
+#[cfg(feature = "std")]
 pub mod sun;
+#[cfg(feature = "std")]
 pub mod time;
+#[cfg(feature = "std")]
+pub mod units;
+#[cfg(feature = "std")]
 pub mod utils;
+#[cfg(feature = "std")]
+pub mod vsop87;
+#[cfg(feature = "wasm")]
+pub mod wasm;
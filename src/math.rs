@@ -0,0 +1,329 @@
+use core::f64::consts::PI;
+use core::fmt;
+
+// The floating point functions come from `std`, or
+// from `libm` when `std` feature is off (`no_std`).
+macro_rules! float_fn {
+    ($name:ident, $libm:ident) => {
+        #[cfg(feature = "std")]
+        #[inline]
+        pub(crate) fn $name(x: f64) -> f64 {
+            x.$name()
+        }
+
+        #[cfg(not(feature = "std"))]
+        #[inline]
+        pub(crate) fn $name(x: f64) -> f64 {
+            libm::$libm(x)
+        }
+    };
+}
+
+float_fn!(sin, sin);
+float_fn!(cos, cos);
+float_fn!(tan, tan);
+float_fn!(asin, asin);
+float_fn!(acos, acos);
+float_fn!(floor, floor);
+float_fn!(abs, fabs);
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+const KEPLER_ACCURACY: f64 = 1e-6; // (ε)
+const KEPLER_MAX_ITERATIONS: u32 = 1000;
+
+/// Errors for solving Kepler's equation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KeplerError {
+    /// The residual (E - e sin E - M) did not get
+    /// within the tolerance after the iterations.
+    DidNotConverge { iterations: u32, residual: f64 },
+}
+
+impl fmt::Display for KeplerError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            KeplerError::DidNotConverge {
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "Kepler's equation did not converge after {} iterations (residual: {})",
+                iterations, residual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeplerError {}
+
+// Newton's iteration for `solve_kepler_with()`,
+// which gives up once the residual is not finite
+// (for `NaN` or infinite arguments), since it would
+// never get within the tolerance.
+fn _kepler_aux(
+    mean_anom: f64,
+    eccentricity: f64,
+    ecc_0: f64,
+    limits: (u32, f64),
+) -> Result<f64, KeplerError> {
+    let (max_iterations, tolerance) = limits;
+    let mut ecc: f64 = ecc_0;
+    let mut counter: u32 = 0;
+
+    loop {
+        let delta = ecc
+            - (eccentricity * sin(ecc))
+            - mean_anom;
+        if abs(delta) <= tolerance {
+            return Ok(ecc);
+        }
+        if counter >= max_iterations
+            || !delta.is_finite()
+        {
+            return Err(
+                KeplerError::DidNotConverge {
+                    iterations: counter,
+                    residual: delta,
+                },
+            );
+        }
+        ecc -=
+            delta / (1.0 - (eccentricity * cos(ecc)));
+        counter += 1;
+    }
+}
+
+/// Given the mean anomaly (M) in radians and the
+/// eccentricity (e) of the orbit, returns the
+/// eccentric anomaly (E) in radians solving Kepler's
+/// equation (E - e sin E = M) with Newton's method.
+/// The iteration stops once |E - e sin E - M| is
+/// within 1e-6 radians (ε), or gives up with
+/// `KeplerError::DidNotConverge` after 1000
+/// iterations. For a very eccentric orbit (e > 0.8),
+/// it starts from π instead of M. See
+/// `solve_kepler_with` to change the limits.
+///
+/// * `mean_anom` - Mean anomaly (M) in radians
+/// * `eccentricity` - Eccentricity (e)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.90)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::math::solve_kepler;
+///
+/// let ecc: f64 = solve_kepler(3.5, 0.2).unwrap();
+/// assert!((ecc - 0.2 * ecc.sin() - 3.5).abs() < 1e-6);
+/// ```
+pub fn solve_kepler(
+    mean_anom: f64,
+    eccentricity: f64,
+) -> Result<f64, KeplerError> {
+    solve_kepler_with(
+        mean_anom,
+        eccentricity,
+        None,
+        None,
+    )
+}
+
+/// Same as `solve_kepler`, but with the maximum
+/// number of iterations and the tolerance (ε). `None`
+/// falls back to the defaults (1000 and 1e-6).
+///
+/// * `mean_anom` - Mean anomaly (M) in radians
+/// * `eccentricity` - Eccentricity (e)
+/// * `max_iterations` - Maximum number of iterations
+/// * `tolerance` - Tolerance (ε) in radians
+///
+/// Example:
+/// ```rust
+/// use sowngwala::math::{solve_kepler_with, KeplerError};
+///
+/// let ecc: f64 =
+///     solve_kepler_with(3.5, 0.2, None, Some(1e-12))
+///         .unwrap();
+/// assert!((ecc - 0.2 * ecc.sin() - 3.5).abs() < 1e-12);
+///
+/// match solve_kepler_with(3.5, 0.2, Some(0), None) {
+///     Err(KeplerError::DidNotConverge {
+///         iterations, ..
+///     }) => assert_eq!(iterations, 0),
+///     _ => panic!(),
+/// }
+/// ```
+pub fn solve_kepler_with(
+    mean_anom: f64,
+    eccentricity: f64,
+    max_iterations: Option<u32>,
+    tolerance: Option<f64>,
+) -> Result<f64, KeplerError> {
+    let ecc_0: f64 = if eccentricity > 0.8 {
+        PI
+    } else {
+        mean_anom
+    };
+    _kepler_aux(
+        mean_anom,
+        eccentricity,
+        ecc_0,
+        (
+            max_iterations
+                .unwrap_or(KEPLER_MAX_ITERATIONS),
+            tolerance.unwrap_or(KEPLER_ACCURACY),
+        ),
+    )
+}
+
+/// Given latitude (β) and longitude (λ) of ecliptic
+/// coordinate in degrees, and the obliquity of the
+/// ecliptic (ε) in degrees, returns right ascension
+/// (α) in decimal hours and declination (δ) in
+/// decimal degrees. The same as
+/// `coords::equatorial_from_ecliptic_with_obliquity`,
+/// but in plain `f64` (which builds under `no_std`).
+///
+/// * `lat` - Ecliptic latitude (β)
+/// * `lng` - Ecliptic longitude (λ)
+/// * `obliquity_deg` - Obliquity of the ecliptic (ε) in degrees
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.40)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::math::equatorial_from_ecliptic;
+///
+/// // The ecliptic longitude of 90° is 6h, at the
+/// // declination of the obliquity itself.
+/// let (asc, dec) =
+///     equatorial_from_ecliptic(0.0, 90.0, 23.44);
+/// assert!((asc - 6.0).abs() < 1e-12);
+/// assert!((dec - 23.44).abs() < 1e-12);
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn equatorial_from_ecliptic(
+    lat: f64,
+    lng: f64,
+    obliquity_deg: f64,
+) -> (f64, f64) {
+    let oblique = obliquity_deg.to_radians();
+
+    let oblique_cos = cos(oblique);
+    let oblique_sin = sin(oblique);
+
+    let lat_cos = cos(lat.to_radians());
+    let lat_sin = sin(lat.to_radians());
+    let lat_tan = tan(lat.to_radians());
+    let lng_cos = cos(lng.to_radians());
+    let lng_sin = sin(lng.to_radians());
+
+    let decline_0 = (lat_sin * oblique_cos)
+        + (lat_cos * oblique_sin * lng_sin);
+    let decline = asin(decline_0).to_degrees();
+
+    let y = (lng_sin * oblique_cos)
+        - (lat_tan * oblique_sin);
+    let x = lng_cos;
+
+    let mut asc = atan2(y, x).to_degrees();
+    asc -= 360.0 * floor(asc / 360.0);
+    asc /= 15.0;
+
+    (asc, decline)
+}
+
+/// Given right ascension (α) in decimal hours and
+/// declination (δ) in decimal degrees for two
+/// celestial objects, returns the angle between them
+/// in degrees.
+///
+/// * `asc_0` - Right ascension (α) of the one
+/// * `dec_0` - Declination (δ) of the one
+/// * `asc_1` - Right ascension (α) of the other
+/// * `dec_1` - Declination (δ) of the other
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.51)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::math::angle_between_two_celestial_objects;
+///
+/// // Beta Orionis, and Canis Majoris
+/// let angle: f64 = angle_between_two_celestial_objects(
+///     5.0 + (13.0 + 31.7 / 60.0) / 60.0,
+///     -(8.0 + (13.0 + 30.0 / 60.0) / 60.0),
+///     6.0 + (44.0 + 13.4 / 60.0) / 60.0,
+///     -(16.0 + (41.0 + 11.0 / 60.0) / 60.0),
+/// );
+/// assert!((angle - 23.673_850).abs() < 1e-6);
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn angle_between_two_celestial_objects(
+    asc_0: f64,
+    dec_0: f64,
+    asc_1: f64,
+    dec_1: f64,
+) -> f64 {
+    let tmp = ((asc_0 - asc_1) * 15.0).to_radians();
+    let dec_0 = dec_0.to_radians();
+    let dec_1 = dec_1.to_radians();
+    let d_cos = (sin(dec_0) * sin(dec_1))
+        + (cos(dec_0) * cos(dec_1) * cos(tmp));
+
+    acos(d_cos).to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equatorial_from_ecliptic_matches_duffett_smith(
+    ) {
+        // 1980-04-22 (p.40), for which ε is 23.441853°
+        let (asc, dec) = equatorial_from_ecliptic(
+            4.0 + (52.0 + 31.0 / 60.0) / 60.0,
+            139.0 + (41.0 + 10.0 / 60.0) / 60.0,
+            23.441_853,
+        );
+
+        // 9h 34m 53.6s, and 19° 32′ 14.2″
+        let asc_0: f64 =
+            9.0 + (34.0 + 53.6 / 60.0) / 60.0;
+        let dec_0: f64 =
+            19.0 + (32.0 + 14.2 / 60.0) / 60.0;
+
+        assert!(
+            abs(asc - asc_0) * 15.0 * 3600.0 < 1.0
+        );
+        assert!(abs(dec - dec_0) * 3600.0 < 1.0);
+    }
+
+    #[test]
+    fn solve_kepler_without_std() {
+        let ecc: f64 =
+            solve_kepler(3.5, 0.2).unwrap();
+        assert!(
+            abs(ecc - 0.2 * sin(ecc) - 3.5) < 1e-6
+        );
+    }
+}
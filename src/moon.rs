@@ -1,28 +1,57 @@
 use crate::constants::{
+    EARTH_EQUATORIAL_RADIUS_KM,
+    ECCENTRICITY_OF_MOON_ORBIT,
     INCLINATION_OF_THE_MOON_ORBIT,
+    LUNAR_ECLIPTIC_LIMIT,
     MEAN_LONGITUDE_OF_PERIGEE_AT_THE_EPOCH,
     MEAN_LONGITUDE_OF_THE_NODE_AT_THE_EPOCH,
+    MOON_ALTITUDE_AT_RISE_AND_SET,
+    MOON_ANGULAR_SIZE_AT_DISTANCE_A_FROM_THE_EARTH,
     MOON_MEAN_LONGITUDE_AT_THE_EPOCH,
+    MOON_PHASE_NAME_TOLERANCE,
+    PARALLAX_AT_DISTANCE_A_FROM_THE_EARTH,
+    SEMI_MAJOR_AXIS_OF_MOON_ORBIT,
+    SOLAR_ECLIPTIC_LIMIT, SYNODIC_MONTH,
+    SYZYGY_TOLERANCE,
 };
 use crate::coords::Angle;
 use crate::coords::{
+    apply_parallax_equatorial, dip_correction,
+    equatorial_from_ecliptic_apparent,
     equatorial_from_ecliptic_with_generic_date,
-    EcliCoord, EquaCoord,
+    equatorial_from_horizon, horizon_from_equatorial,
+    hour_angle_from_utc, Coord, EcliCoord, EquaCoord,
+    EquaCoord2, HorizCoord, HourAngle, Observer,
+    RightAscension, RiseSet,
+};
+use crate::sun::{
+    _longitude_and_mean_anomaly_aux, SunElements,
 };
-use crate::delta_t::delta_t_from_generic_date;
-use crate::sun::sun_longitude_and_mean_anomaly;
 use crate::time::{
-    day_number_from_generic_date, days_since_1990,
+    angle_from_decimal_hours,
+    day_number_from_generic_date, days_since_epoch,
     decimal_hours_from_angle,
+    decimal_hours_from_generic_time,
+    julian_centuries_from_generic_datetime,
     naive_date_from_generic_datetime,
+    naive_time_from_decimal_hours, tt_from_ut,
+    utc_from_fixed, SiderealBaseline,
+};
+use chrono::naive::{
+    NaiveDate, NaiveDateTime, NaiveTime,
+};
+use chrono::offset::{FixedOffset, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Timelike,
 };
-use chrono::{Datelike, Timelike};
 
 /// Given the specific date and time, returns right
 /// ascension (α) and declination (δ) of equatorial
-/// coordinate.
+/// coordinate. The date and time are assumed to be
+/// in UT (for the local time with its zone, see
+/// `equatorial_position_of_the_moon_from_fixed`).
 ///
-/// * `dt` - DateTime
+/// * `dt` - DateTime (UT)
 ///
 /// Reference:
 /// - (Peter Duffett-Smith, p.144)
@@ -52,8 +81,8 @@ use chrono::{Datelike, Timelike};
 ///     equatorial_position_of_the_moon_from_generic_datetime(
 ///         dt
 ///     );
-/// let asc: Angle = coord.asc;
-/// let dec: Angle = coord.dec;
+/// let asc: Angle = coord.asc.into();
+/// let dec: Angle = coord.dec.into();
 ///
 /// assert_eq!(asc.hour(), 22);
 /// assert_eq!(asc.minute(), 33);
@@ -93,49 +122,1205 @@ where
     T: Datelike,
     T: Timelike,
     T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     let date = naive_date_from_generic_datetime(dt);
+
+    equatorial_from_ecliptic_with_generic_date(
+        ecliptic_position_of_the_moon_from_generic_datetime(
+            dt,
+        ),
+        date,
+    )
+}
+
+/// Given the specific date and time, returns latitude
+/// (βm) and longitude (λm) of ecliptic coordinate for
+/// the moon. This is what
+/// `equatorial_position_of_the_moon_from_generic_datetime`
+/// converts into equatorial coordinate. The date and
+/// time are assumed to be in UT.
+///
+/// * `dt` - DateTime (UT)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.144)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::coords::{
+///     equatorial_from_ecliptic_with_generic_date,
+///     EcliCoord, EquaCoord,
+/// };
+/// use sowngwala::moon::{
+///     ecliptic_position_of_the_moon_from_generic_datetime,
+///     equatorial_position_of_the_moon_from_generic_datetime,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(1979, 2, 26).and_hms(16, 0, 0);
+///
+/// let coord: EcliCoord =
+///     ecliptic_position_of_the_moon_from_generic_datetime(dt);
+///
+/// assert!((coord.lng - 337.01).abs() < 0.01); // 337.0098...
+/// assert!((coord.lat - 0.99).abs() < 0.01); // 0.9920...
+///
+/// // The same as the equatorial one
+/// let equa_0: EquaCoord =
+///     equatorial_from_ecliptic_with_generic_date(
+///         coord,
+///         dt.date(),
+///     );
+/// let equa_1: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(dt);
+/// assert_eq!(
+///     decimal_hours_from_angle(equa_0.asc),
+///     decimal_hours_from_angle(equa_1.asc)
+/// );
+/// assert_eq!(
+///     decimal_hours_from_angle(equa_0.dec),
+///     decimal_hours_from_angle(equa_1.dec)
+/// );
+/// ```
+pub fn ecliptic_position_of_the_moon_from_generic_datetime<
+    T,
+>(
+    dt: T,
+) -> EcliCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let orbit = moon_orbit_from_generic_datetime(dt);
+
+    EcliCoord {
+        lat: orbit.lat,
+        lng: orbit.lng,
+    }
+}
+
+/// Orbital elements of the moon at the epoch, along
+/// with the sun's (see `SunElements`) whose
+/// `epoch_year` is the epoch for the both. The
+/// default is the one at the epoch 1990.0 used
+/// throughout.
+///
+/// * `lng_at_epoch` - Moon's mean longitude at the epoch (l o)
+/// * `lng_of_perigee` - Mean longitude of the perigee at the epoch (P o)
+/// * `lng_of_node` - Mean longitude of the node at the epoch (N o)
+/// * `sun` - Orbital elements of the sun
+#[derive(Debug, Copy, Clone)]
+pub struct MoonElements {
+    pub lng_at_epoch: f64,
+    pub lng_of_perigee: f64,
+    pub lng_of_node: f64,
+    pub sun: SunElements,
+}
+
+impl Default for MoonElements {
+    fn default() -> Self {
+        MoonElements {
+            lng_at_epoch: MOON_MEAN_LONGITUDE_AT_THE_EPOCH,
+            lng_of_perigee:
+                MEAN_LONGITUDE_OF_PERIGEE_AT_THE_EPOCH,
+            lng_of_node:
+                MEAN_LONGITUDE_OF_THE_NODE_AT_THE_EPOCH,
+            sun: SunElements::default(),
+        }
+    }
+}
+
+/// Same as
+/// `ecliptic_position_of_the_moon_from_generic_datetime`,
+/// but with the orbital elements at any epoch (see
+/// `MoonElements`), counting the days from the epoch
+/// (see `days_since_epoch`).
+///
+/// * `dt` - DateTime (UT)
+/// * `elements` - Orbital elements of the moon
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.144)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::coords::EcliCoord;
+/// use sowngwala::moon::{
+///     ecliptic_position_of_the_moon_from_generic_datetime,
+///     ecliptic_position_of_the_moon_with_elements,
+///     MoonElements,
+/// };
+/// use sowngwala::sun::SunElements;
+///
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(1979, 2, 26).and_hms(16, 0, 0);
+///
+/// // The default (1990.0) is the same as the legacy.
+/// let coord: EcliCoord =
+///     ecliptic_position_of_the_moon_with_elements(
+///         dt,
+///         MoonElements::default(),
+///     );
+/// let legacy: EcliCoord =
+///     ecliptic_position_of_the_moon_from_generic_datetime(dt);
+/// assert_eq!(coord.lng, legacy.lng);
+/// assert_eq!(coord.lat, legacy.lat);
+///
+/// // The elements at the epoch 2010.0 (Duffett-Smith,
+/// // 4th edition) give almost the same position.
+/// let elements = MoonElements {
+///     lng_at_epoch: 91.929_336,
+///     lng_of_perigee: 130.143_076,
+///     lng_of_node: 291.682_547,
+///     sun: SunElements {
+///         epoch_year: 2010,
+///         lng_at_epoch: 279.557_208,
+///         lng_of_perigee: 283.112_438,
+///         eccentricity: 0.016_705,
+///     },
+/// };
+/// let coord: EcliCoord =
+///     ecliptic_position_of_the_moon_with_elements(
+///         dt, elements,
+///     );
+/// assert!((coord.lng - 337.01).abs() < 0.1);
+/// assert!((coord.lat - 0.99).abs() < 0.1);
+/// ```
+pub fn ecliptic_position_of_the_moon_with_elements<
+    T,
+>(
+    dt: T,
+    elements: MoonElements,
+) -> EcliCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let orbit = _moon_orbit_aux(dt, &elements);
+
+    EcliCoord {
+        lat: orbit.lat,
+        lng: orbit.lng,
+    }
+}
+
+/// Same as
+/// `equatorial_position_of_the_moon_from_generic_datetime`,
+/// but for the local time with its zone, which is
+/// converted into UTC first (see `utc_from_fixed`).
+///
+/// * `fixed` - Local DateTime
+///
+/// Example:
+/// ```rust
+/// use chrono::DateTime;
+/// use chrono::naive::NaiveDate;
+/// use chrono::offset::FixedOffset;
+/// use sowngwala::coords::EquaCoord;
+/// use sowngwala::moon::{
+///     equatorial_position_of_the_moon_from_fixed,
+///     equatorial_position_of_the_moon_from_generic_datetime,
+/// };
+/// use sowngwala::time::{
+///     build_fixed, decimal_hours_from_angle,
+/// };
+///
+/// // 1979-02-27 01:00 (+09:00) is 1979-02-26 16:00 (UT).
+/// let fixed: DateTime<FixedOffset> =
+///     build_fixed(1979, 2, 27, 1, 0, 0, 0, 9);
+///
+/// let coord_0: EquaCoord =
+///     equatorial_position_of_the_moon_from_fixed(fixed);
+/// let coord_1: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(
+///         NaiveDate::from_ymd(1979, 2, 26).and_hms(16, 0, 0),
+///     );
+/// assert_eq!(
+///     decimal_hours_from_angle(coord_0.asc),
+///     decimal_hours_from_angle(coord_1.asc)
+/// );
+/// assert_eq!(
+///     decimal_hours_from_angle(coord_0.dec),
+///     decimal_hours_from_angle(coord_1.dec)
+/// );
+/// ```
+pub fn equatorial_position_of_the_moon_from_fixed(
+    fixed: DateTime<FixedOffset>,
+) -> EquaCoord {
+    let utc: DateTime<Utc> = utc_from_fixed(fixed);
+    equatorial_position_of_the_moon_from_generic_datetime(
+        utc,
+    )
+}
+
+/// Same as
+/// `ecliptic_position_of_the_moon_from_generic_datetime`,
+/// but for the local time with its zone, which is
+/// converted into UTC first (see `utc_from_fixed`).
+///
+/// * `fixed` - Local DateTime
+pub fn ecliptic_position_of_the_moon_from_fixed(
+    fixed: DateTime<FixedOffset>,
+) -> EcliCoord {
+    let utc: DateTime<Utc> = utc_from_fixed(fixed);
+    ecliptic_position_of_the_moon_from_generic_datetime(
+        utc,
+    )
+}
+
+// Periodic terms for the moon's longitude (Σl) in
+// 10^-6 degrees: [D, M, M', F, coefficient of sine]
+// (Jean Meeus, "Astronomical Algorithms", Table 47.A)
+#[rustfmt::skip]
+const MOON_LONGITUDE_TERMS: [[f64; 5]; 59] = [
+    [0.0, 0.0, 1.0, 0.0, 6_288_774.0],
+    [2.0, 0.0, -1.0, 0.0, 1_274_027.0],
+    [2.0, 0.0, 0.0, 0.0, 658_314.0],
+    [0.0, 0.0, 2.0, 0.0, 213_618.0],
+    [0.0, 1.0, 0.0, 0.0, -185_116.0],
+    [0.0, 0.0, 0.0, 2.0, -114_332.0],
+    [2.0, 0.0, -2.0, 0.0, 58_793.0],
+    [2.0, -1.0, -1.0, 0.0, 57_066.0],
+    [2.0, 0.0, 1.0, 0.0, 53_322.0],
+    [2.0, -1.0, 0.0, 0.0, 45_758.0],
+    [0.0, 1.0, -1.0, 0.0, -40_923.0],
+    [1.0, 0.0, 0.0, 0.0, -34_720.0],
+    [0.0, 1.0, 1.0, 0.0, -30_383.0],
+    [2.0, 0.0, 0.0, -2.0, 15_327.0],
+    [0.0, 0.0, 1.0, 2.0, -12_528.0],
+    [0.0, 0.0, 1.0, -2.0, 10_980.0],
+    [4.0, 0.0, -1.0, 0.0, 10_675.0],
+    [0.0, 0.0, 3.0, 0.0, 10_034.0],
+    [4.0, 0.0, -2.0, 0.0, 8_548.0],
+    [2.0, 1.0, -1.0, 0.0, -7_888.0],
+    [2.0, 1.0, 0.0, 0.0, -6_766.0],
+    [1.0, 0.0, -1.0, 0.0, -5_163.0],
+    [1.0, 1.0, 0.0, 0.0, 4_987.0],
+    [2.0, -1.0, 1.0, 0.0, 4_036.0],
+    [2.0, 0.0, 2.0, 0.0, 3_994.0],
+    [4.0, 0.0, 0.0, 0.0, 3_861.0],
+    [2.0, 0.0, -3.0, 0.0, 3_665.0],
+    [0.0, 1.0, -2.0, 0.0, -2_689.0],
+    [2.0, 0.0, -1.0, 2.0, -2_602.0],
+    [2.0, -1.0, -2.0, 0.0, 2_390.0],
+    [1.0, 0.0, 1.0, 0.0, -2_348.0],
+    [2.0, -2.0, 0.0, 0.0, 2_236.0],
+    [0.0, 1.0, 2.0, 0.0, -2_120.0],
+    [0.0, 2.0, 0.0, 0.0, -2_069.0],
+    [2.0, -2.0, -1.0, 0.0, 2_048.0],
+    [2.0, 0.0, 1.0, -2.0, -1_773.0],
+    [2.0, 0.0, 0.0, 2.0, -1_595.0],
+    [4.0, -1.0, -1.0, 0.0, 1_215.0],
+    [0.0, 0.0, 2.0, 2.0, -1_110.0],
+    [3.0, 0.0, -1.0, 0.0, -892.0],
+    [2.0, 1.0, 1.0, 0.0, -810.0],
+    [4.0, -1.0, -2.0, 0.0, 759.0],
+    [0.0, 2.0, -1.0, 0.0, -713.0],
+    [2.0, 2.0, -1.0, 0.0, -700.0],
+    [2.0, 1.0, -2.0, 0.0, 691.0],
+    [2.0, -1.0, 0.0, -2.0, 596.0],
+    [4.0, 0.0, 1.0, 0.0, 549.0],
+    [0.0, 0.0, 4.0, 0.0, 537.0],
+    [4.0, -1.0, 0.0, 0.0, 520.0],
+    [1.0, 0.0, -2.0, 0.0, -487.0],
+    [2.0, 1.0, 0.0, -2.0, -399.0],
+    [0.0, 0.0, 2.0, -2.0, -381.0],
+    [1.0, 1.0, 1.0, 0.0, 351.0],
+    [3.0, 0.0, -2.0, 0.0, -340.0],
+    [4.0, 0.0, -3.0, 0.0, 330.0],
+    [2.0, -1.0, 2.0, 0.0, 327.0],
+    [0.0, 2.0, 1.0, 0.0, -323.0],
+    [1.0, 1.0, -1.0, 0.0, 299.0],
+    [2.0, 0.0, 3.0, 0.0, 294.0],
+];
+
+// Periodic terms for the moon's latitude (Σb) in
+// 10^-6 degrees: [D, M, M', F, coefficient of sine]
+// (Jean Meeus, "Astronomical Algorithms", Table 47.B)
+#[rustfmt::skip]
+const MOON_LATITUDE_TERMS: [[f64; 5]; 30] = [
+    [0.0, 0.0, 0.0, 1.0, 5_128_122.0],
+    [0.0, 0.0, 1.0, 1.0, 280_602.0],
+    [0.0, 0.0, 1.0, -1.0, 277_693.0],
+    [2.0, 0.0, 0.0, -1.0, 173_237.0],
+    [2.0, 0.0, -1.0, 1.0, 55_413.0],
+    [2.0, 0.0, -1.0, -1.0, 46_271.0],
+    [2.0, 0.0, 0.0, 1.0, 32_573.0],
+    [0.0, 0.0, 2.0, 1.0, 17_198.0],
+    [2.0, 0.0, 1.0, -1.0, 9_266.0],
+    [0.0, 0.0, 2.0, -1.0, 8_822.0],
+    [2.0, -1.0, 0.0, -1.0, 8_216.0],
+    [2.0, 0.0, -2.0, -1.0, 4_324.0],
+    [2.0, 0.0, 1.0, 1.0, 4_200.0],
+    [2.0, 1.0, 0.0, -1.0, -3_359.0],
+    [2.0, -1.0, -1.0, 1.0, 2_463.0],
+    [2.0, -1.0, 0.0, 1.0, 2_211.0],
+    [2.0, -1.0, -1.0, -1.0, 2_065.0],
+    [0.0, 1.0, -1.0, -1.0, -1_870.0],
+    [4.0, 0.0, -1.0, -1.0, 1_828.0],
+    [0.0, 1.0, 0.0, 1.0, -1_794.0],
+    [0.0, 0.0, 0.0, 3.0, -1_749.0],
+    [0.0, 1.0, -1.0, 1.0, -1_565.0],
+    [1.0, 0.0, 0.0, 1.0, -1_491.0],
+    [0.0, 1.0, 1.0, 1.0, -1_475.0],
+    [0.0, 1.0, 1.0, -1.0, -1_410.0],
+    [0.0, 1.0, 0.0, -1.0, -1_344.0],
+    [1.0, 0.0, 0.0, -1.0, -1_335.0],
+    [0.0, 0.0, 3.0, 1.0, 1_107.0],
+    [4.0, 0.0, 0.0, -1.0, 1_021.0],
+    [4.0, 0.0, -1.0, 1.0, 833.0],
+];
+
+/// Given the specific date and time (in UT), returns
+/// the moon's apparent right ascension (α) and
+/// declination (δ) computed by the periodic terms of
+/// ELP-2000/82 (as abridged by Meeus). Where the
+/// method of Duffett-Smith (see
+/// `equatorial_position_of_the_moon_from_generic_datetime`)
+/// applies a fixed sequence of corrections (the
+/// evection, the annual equation, and so on), each
+/// using the result of the previous one, here all
+/// the terms are evaluated at once for the time in
+/// TT (see `tt_from_ut`), so that the time-dependent
+/// arguments (the sun's mean anomaly (M) and the mean
+/// elongation (D), which carry the sun's longitude,
+/// and those of the moon) are those of TT. Only the
+/// 59 terms in longitude of
+/// Meeus's Table 47.A, and the largest 30 of the 60
+/// terms in latitude of Table 47.B are included,
+/// which are good to some arcseconds (versus a few
+/// arcminutes for the former). The nutation in
+/// longitude and the apparent obliquity are applied
+/// (see `equatorial_from_ecliptic_apparent`). The
+/// former is kept for its speed.
+///
+/// * `dt` - DateTime (UT)
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", pp.337-344)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::coords::EquaCoord;
+/// use sowngwala::moon::{
+///     equatorial_position_high_precision,
+///     equatorial_position_of_the_moon_from_generic_datetime,
+/// };
+/// use sowngwala::time::{
+///     decimal_hours_from_angle, ut_from_tt,
+/// };
+///
+/// // Meeus, Example 47.a gives α = 134.688470° and
+/// // δ = 13.768368° for 1992 April 12 at 0h TD.
+/// let ut: NaiveDateTime = ut_from_tt(
+///     NaiveDate::from_ymd(1992, 4, 12).and_hms(0, 0, 0),
+/// );
+/// let high: EquaCoord = equatorial_position_high_precision(ut);
+/// let low: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(ut);
+///
+/// let asc_err = |coord: &EquaCoord| {
+///     (decimal_hours_from_angle(coord.asc) * 15.0 - 134.688_470)
+///         .abs()
+/// };
+/// let dec_err = |coord: &EquaCoord| {
+///     (decimal_hours_from_angle(coord.dec) - 13.768_368).abs()
+/// };
+///
+/// assert!(asc_err(&high) < 0.001);
+/// assert!(dec_err(&high) < 0.001);
+/// assert!(asc_err(&high) < asc_err(&low));
+/// assert!(dec_err(&high) < dec_err(&low));
+///
+/// // Duffett-Smith gives α = 22h 33m 29s and
+/// // δ = -8° 2' 42" for 1979-02-26 16:00 (UT), which
+/// // are closer to this one (22h 33m 30.9s and
+/// // -8° 2' 36.4").
+/// let ut: NaiveDateTime =
+///     NaiveDate::from_ymd(1979, 2, 26).and_hms(16, 0, 0);
+/// let high: EquaCoord = equatorial_position_high_precision(ut);
+/// let low: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(ut);
+///
+/// let book_asc: f64 = 22.0 + 33.0 / 60.0 + 29.0 / 3600.0;
+/// let book_dec: f64 = -(8.0 + 2.0 / 60.0 + 42.0 / 3600.0);
+/// let dist = |coord: &EquaCoord| {
+///     (
+///         (decimal_hours_from_angle(coord.asc) - book_asc).abs(),
+///         (decimal_hours_from_angle(coord.dec) - book_dec).abs(),
+///     )
+/// };
+/// assert!(dist(&high).0 < dist(&low).0);
+/// assert!(dist(&high).1 < dist(&low).1);
+/// ```
+pub fn equatorial_position_high_precision<T>(
+    dt: T,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let tt: NaiveDateTime = tt_from_ut(dt);
+    let (lng, lat): (f64, f64) =
+        _moon_ecliptic_high_precision(tt);
+
+    equatorial_from_ecliptic_apparent(
+        EcliCoord { lat, lng },
+        tt.date(),
+    )
+}
+
+// Ecliptic longitude (λ) and latitude (β) of the moon
+// for `equatorial_position_high_precision()` at the
+// given time (in TT).
+#[allow(clippy::many_single_char_names)]
+fn _moon_ecliptic_high_precision(
+    tt: NaiveDateTime,
+) -> (f64, f64) {
+    let t: f64 =
+        julian_centuries_from_generic_datetime(tt);
+
+    // Mean longitude (L'), mean elongation (D), the
+    // sun's mean anomaly (M), the moon's mean anomaly
+    // (M'), and the argument of latitude (F)
+    let l: f64 = 218.316_447_7
+        + 481_267.881_234_21 * t
+        - 0.001_578_6 * t * t
+        + t * t * t / 538_841.0
+        - t * t * t * t / 65_194_000.0;
+    let d: f64 = 297.850_192_1
+        + 445_267.111_403_4 * t
+        - 0.001_881_9 * t * t
+        + t * t * t / 545_868.0
+        - t * t * t * t / 113_065_000.0;
+    let m: f64 = 357.529_109_2 + 35_999.050_290_9 * t
+        - 0.000_153_6 * t * t
+        + t * t * t / 24_490_000.0;
+    let mm: f64 = 134.963_396_4
+        + 477_198.867_505_5 * t
+        + 0.008_741_4 * t * t
+        + t * t * t / 69_699.0
+        - t * t * t * t / 14_712_000.0;
+    let f: f64 = 93.272_095_0 + 483_202.017_523_3 * t
+        - 0.003_653_9 * t * t
+        - t * t * t / 3_526_000.0
+        + t * t * t * t / 863_310_000.0;
+
+    // Action of Venus (A1), Jupiter (A2), and the
+    // flattening of the earth (A3)
+    let a1: f64 = 119.75 + 131.849 * t;
+    let a2: f64 = 53.09 + 479_264.29 * t;
+    let a3: f64 = 313.45 + 481_266.484 * t;
+
+    // The decreasing eccentricity of the earth's
+    // orbit (E)
+    let e: f64 =
+        1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+
+    let sum = |terms: &[[f64; 5]]| -> f64 {
+        terms
+            .iter()
+            .map(|[td, tm, tmm, tf, coeff]| {
+                let arg: f64 = td * d
+                    + tm * m
+                    + tmm * mm
+                    + tf * f;
+                coeff
+                    * e.powi(tm.abs() as i32)
+                    * arg.to_radians().sin()
+            })
+            .sum()
+    };
+
+    let sin = |deg: f64| deg.to_radians().sin();
+
+    let sum_l: f64 = sum(&MOON_LONGITUDE_TERMS)
+        + 3958.0 * sin(a1)
+        + 1962.0 * sin(l - f)
+        + 318.0 * sin(a2);
+    let sum_b: f64 = sum(&MOON_LATITUDE_TERMS)
+        - 2235.0 * sin(l)
+        + 382.0 * sin(a3)
+        + 175.0 * sin(a1 - f)
+        + 175.0 * sin(a1 + f)
+        + 127.0 * sin(l - mm)
+        - 115.0 * sin(l + mm);
+
+    let mut lng: f64 = l + sum_l / 1_000_000.0;
+    lng -= 360.0 * (lng / 360.0).floor();
+    let lat: f64 = sum_b / 1_000_000.0;
+
+    (lng, lat)
+}
+
+/// The geometry of the sun and the moon to see if
+/// eclipses are possible.
+///
+/// * `elongation` - Angle between the sun and the moon (0° to 180°)
+/// * `moon_lat` - Moon's ecliptic latitude (βm), or how far it is from the node
+/// * `solar_eclipse_possible` - Near the new moon, and within the solar ecliptic limit
+/// * `lunar_eclipse_possible` - Near the full moon, and within the lunar ecliptic limit
+#[derive(Debug, Copy, Clone)]
+pub struct EclipseGeometry {
+    pub elongation: f64,
+    pub moon_lat: f64,
+    pub solar_eclipse_possible: bool,
+    pub lunar_eclipse_possible: bool,
+}
+
+/// Given the specific date and time, computes the sun
+/// and the moon at once, and returns `EclipseGeometry`.
+/// Eclipses are regarded as possible when the moon
+/// is near the syzygy (`SYZYGY_TOLERANCE`) while its
+/// ecliptic latitude is within the ecliptic limit
+/// (`SOLAR_ECLIPTIC_LIMIT` and `LUNAR_ECLIPTIC_LIMIT`).
+/// This is a rough screening, and does not tell
+/// whether eclipses actually happen.
+///
+/// * `dt` - DateTime
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.144, 159-160)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::moon::{eclipse_geometry, EclipseGeometry};
+///
+/// // Total solar eclipse (August 21, 2017)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2017, 8, 21).and_hms(18, 25, 0);
+/// let geometry: EclipseGeometry = eclipse_geometry(dt);
+/// assert!(geometry.elongation < 1.0);
+/// assert!(geometry.solar_eclipse_possible);
+/// assert!(!geometry.lunar_eclipse_possible);
+///
+/// // Total lunar eclipse (November 8, 2022)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 11, 8).and_hms(11, 0, 0);
+/// let geometry: EclipseGeometry = eclipse_geometry(dt);
+/// assert!(geometry.elongation > 179.0);
+/// assert!(!geometry.solar_eclipse_possible);
+/// assert!(geometry.lunar_eclipse_possible);
+///
+/// // New moon, but no eclipse (January 2, 2022)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 1, 2).and_hms(18, 33, 0);
+/// let geometry: EclipseGeometry = eclipse_geometry(dt);
+/// assert!(geometry.elongation < 6.0);
+/// assert!(!geometry.solar_eclipse_possible);
+/// ```
+pub fn eclipse_geometry(
+    dt: NaiveDateTime,
+) -> EclipseGeometry {
+    let orbit = moon_orbit_from_generic_datetime(dt);
+
+    let lat: f64 = orbit.lat.to_radians();
+    let lng_diff: f64 =
+        (orbit.lng - orbit.sun_lng).to_radians();
+
+    let elongation: f64 = (lng_diff.cos()
+        * lat.cos())
+    .acos()
+    .to_degrees();
+
+    let moon_lat: f64 = orbit.lat;
+
+    EclipseGeometry {
+        elongation,
+        moon_lat,
+        solar_eclipse_possible: elongation
+            < SYZYGY_TOLERANCE
+            && moon_lat.abs() < SOLAR_ECLIPTIC_LIMIT,
+        lunar_eclipse_possible: elongation
+            > 180.0 - SYZYGY_TOLERANCE
+            && moon_lat.abs() < LUNAR_ECLIPTIC_LIMIT,
+    }
+}
+
+/// The moon's phase.
+///
+/// * `age_days` - Days since the last new moon
+/// * `illuminated_fraction` - Fraction of the disk illuminated (F), from 0.0 to 1.0
+/// * `phase_angle` - Angle between the sun and the earth as seen from the moon (0° at the full moon)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonPhase {
+    pub age_days: f64,
+    pub illuminated_fraction: f64,
+    pub phase_angle: f64,
+}
+
+/// Given the specific date and time, returns the
+/// moon's phase (`MoonPhase`). The moon's age (D)
+/// is the moon's true orbital longitude (l'') minus
+/// the sun's longitude (λ), and the illuminated
+/// fraction is `(1 - cos D) / 2`.
+///
+/// * `dt` - DateTime
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.154-155)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::moon::{phase_from_generic_datetime, MoonPhase};
+///
+/// // New moon (January 2, 2022, 18:33)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 1, 2).and_hms(18, 33, 0);
+/// let phase: MoonPhase = phase_from_generic_datetime(dt);
+/// assert!(phase.illuminated_fraction < 0.01);
+/// assert!(phase.phase_angle > 170.0);
+/// assert!(
+///     phase.age_days < 0.5 || phase.age_days > 29.0
+/// );
+///
+/// // Full moon (January 17, 2022, 23:48)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 1, 17).and_hms(23, 48, 0);
+/// let phase: MoonPhase = phase_from_generic_datetime(dt);
+/// assert!(phase.illuminated_fraction > 0.99);
+/// assert!(phase.phase_angle < 10.0);
+/// assert!((phase.age_days - 14.8).abs() < 1.0);
+/// ```
+pub fn phase_from_generic_datetime<T>(
+    dt: T,
+) -> MoonPhase
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let orbit = moon_orbit_from_generic_datetime(dt);
+
+    // Moon's age (D)
+    let mut d: f64 = orbit.true_lng - orbit.sun_lng;
+    d -= 360.0 * (d / 360.0).floor();
+
+    let d_cos: f64 = d.to_radians().cos();
+
+    MoonPhase {
+        age_days: d / (360.0 / SYNODIC_MONTH),
+        illuminated_fraction: (1.0 - d_cos) / 2.0,
+        phase_angle: 180.0
+            - d_cos.acos().to_degrees(),
+    }
+}
+
+/// Names for the moon's phase.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MoonPhaseName {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+/// Given the specific date and time, returns the name
+/// of the moon's phase (`MoonPhaseName`).
+///
+/// The synodic month is divided into eighths by the
+/// moon's age. The new moon, the first quarter, the
+/// full moon, and the last quarter are the exact
+/// moments (the age of 0, 1/4, 1/2, and 3/4 of the
+/// synodic month), and are only given within a day
+/// (`MOON_PHASE_NAME_TOLERANCE`) of them. The rest of
+/// each quarter is given to the crescent or gibbous:
+///
+/// | Age (days)       | Name             |
+/// |------------------|------------------|
+/// | 29.53 - 1 to 1   | `NewMoon`        |
+/// | 1 to 7.38 - 1    | `WaxingCrescent` |
+/// | 7.38 ± 1         | `FirstQuarter`   |
+/// | 7.38 + 1 to 14.77 - 1 | `WaxingGibbous` |
+/// | 14.77 ± 1        | `FullMoon`       |
+/// | 14.77 + 1 to 22.15 - 1 | `WaningGibbous` |
+/// | 22.15 ± 1        | `LastQuarter`    |
+/// | 22.15 + 1 to 29.53 - 1 | `WaningCrescent` |
+///
+/// * `dt` - DateTime
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::moon::{
+///     phase_name_from_generic_datetime,
+///     MoonPhaseName,
+/// };
+///
+/// // New moon on January 2, full moon on January 17,
+/// // and the quarters on January 9 and 25 (2022).
+/// let expected = [
+///     ((1, 2, 18), MoonPhaseName::NewMoon),
+///     ((1, 5, 12), MoonPhaseName::WaxingCrescent),
+///     ((1, 9, 18), MoonPhaseName::FirstQuarter),
+///     ((1, 13, 12), MoonPhaseName::WaxingGibbous),
+///     ((1, 17, 23), MoonPhaseName::FullMoon),
+///     ((1, 21, 12), MoonPhaseName::WaningGibbous),
+///     ((1, 25, 13), MoonPhaseName::LastQuarter),
+///     ((1, 29, 12), MoonPhaseName::WaningCrescent),
+/// ];
+///
+/// for ((month, day, hour), name) in expected {
+///     let dt: NaiveDateTime =
+///         NaiveDate::from_ymd(2022, month, day)
+///             .and_hms(hour, 0, 0);
+///     assert_eq!(phase_name_from_generic_datetime(dt), name);
+/// }
+/// ```
+pub fn phase_name_from_generic_datetime<T>(
+    dt: T,
+) -> MoonPhaseName
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let age: f64 =
+        phase_from_generic_datetime(dt).age_days;
+    let quarter: f64 = SYNODIC_MONTH / 4.0;
+
+    // The nearest of the exact phases
+    let nearest: f64 = (age / quarter).round();
+
+    if (age - nearest * quarter).abs()
+        <= MOON_PHASE_NAME_TOLERANCE
+    {
+        return match (nearest as u32) % 4 {
+            0 => MoonPhaseName::NewMoon,
+            1 => MoonPhaseName::FirstQuarter,
+            2 => MoonPhaseName::FullMoon,
+            _ => MoonPhaseName::LastQuarter,
+        };
+    }
+
+    match ((age / quarter).floor() as u32) % 4 {
+        0 => MoonPhaseName::WaxingCrescent,
+        1 => MoonPhaseName::WaxingGibbous,
+        2 => MoonPhaseName::WaningGibbous,
+        _ => MoonPhaseName::WaningCrescent,
+    }
+}
+
+/// Given the date and the observer's location,
+/// returns the moonrise and the moonset in UT
+/// (`RiseSet`). The moon moves some 13° a day, so
+/// unlike the sun, its position is recomputed along
+/// the way. The moon's altitude is sampled every hour
+/// across the day, and where it crosses the altitude
+/// of the rise and the set (`MOON_ALTITUDE_AT_RISE_AND_SET`,
+/// which accounts for the horizontal parallax, the
+/// refraction, and the semidiameter), the time is
+/// narrowed down by bisection to within a second.
+/// LST is taken from `SiderealBaseline`. Either is
+/// `None` when the moon does not rise (or set) on
+/// the day, which happens about once a month. When
+/// neither happens, the status tells whether the
+/// moon stays above or below the horizon all day.
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.151)
+/// - (Jean Meeus, "Astronomical Algorithms", p.102)
+///
+/// Example:
+/// ```rust
+/// use chrono::Timelike;
+/// use chrono::naive::{NaiveDate, NaiveTime};
+/// use sowngwala::coords::{Coord, RiseSet};
+/// use sowngwala::moon::rise_set_from_date;
+///
+/// // New York on June 10, 2022. The moon sets at
+/// // 06:45 and rises at 20:06 (UT) using Meeus's
+/// // lunar theory (ch.47).
+/// let date = NaiveDate::from_ymd(2022, 6, 10);
+/// let coord = Coord { lat: 40.7128, lng: -74.0060 };
+///
+/// let rise_set: RiseSet = rise_set_from_date(date, coord);
+/// let rise: NaiveTime = rise_set.rise.unwrap();
+/// let set: NaiveTime = rise_set.set.unwrap();
+///
+/// let minutes = |t: NaiveTime| -> i32 {
+///     (t.hour() * 60 + t.minute()) as i32
+/// };
+/// assert!((minutes(rise) - (20 * 60 + 6)).abs() <= 2);
+/// assert!((minutes(set) - (6 * 60 + 45)).abs() <= 2);
+/// ```
+pub fn rise_set_from_date(
+    date: NaiveDate,
+    coord: Coord,
+) -> RiseSet {
+    rise_set_from_date_with_height(date, coord, 0.0)
+}
+
+/// Same as `rise_set_from_date`, but for the observer
+/// at the given height (on a mountaintop, or in the
+/// air), lowering the altitude of the rise and the
+/// set by the dip of the horizon (`dip_correction`).
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+/// * `height_m` - Observer's height (in meters)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Coord;
+/// use sowngwala::moon::{
+///     rise_set_from_date,
+///     rise_set_from_date_with_height,
+/// };
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 10);
+/// let coord = Coord { lat: 40.7128, lng: -74.0060 };
+///
+/// let ground = rise_set_from_date(date, coord);
+/// let summit =
+///     rise_set_from_date_with_height(date, coord, 1_000.0);
+///
+/// assert!(summit.rise.unwrap() < ground.rise.unwrap());
+/// assert!(summit.set.unwrap() > ground.set.unwrap());
+/// ```
+pub fn rise_set_from_date_with_height(
+    date: NaiveDate,
+    coord: Coord,
+    height_m: f64,
+) -> RiseSet {
+    _rise_set_from_date_aux(
+        date,
+        coord,
+        MOON_ALTITUDE_AT_RISE_AND_SET
+            - dip_correction(height_m),
+    )
+}
+
+/// Same as `rise_set_from_date`, but for `Observer`,
+/// taking the observer's height and atmosphere into
+/// account (see `Observer::rise_set_correction`).
+///
+/// * `date` - Date
+/// * `observer` - Observer
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Observer;
+/// use sowngwala::moon::{
+///     rise_set_for_observer, rise_set_from_date,
+/// };
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 10);
+/// let observer = Observer::at(40.7128, -74.0060);
+///
+/// let standard = rise_set_for_observer(date, observer);
+/// let default = rise_set_from_date(date, observer.coord);
+/// assert_eq!(standard.rise, default.rise);
+///
+/// let cold = rise_set_for_observer(
+///     date,
+///     Observer { temp_c: -30.0, ..observer },
+/// );
+/// assert!(cold.rise.unwrap() < standard.rise.unwrap());
+/// ```
+pub fn rise_set_for_observer(
+    date: NaiveDate,
+    observer: Observer,
+) -> RiseSet {
+    _rise_set_from_date_aux(
+        date,
+        observer.coord,
+        MOON_ALTITUDE_AT_RISE_AND_SET
+            - observer.rise_set_correction(),
+    )
+}
+
+/// See `rise_set_from_date` for the specs. `target`
+/// is the altitude (in degrees) of the moon's center
+/// at the rise and the set.
+fn _rise_set_from_date_aux(
+    date: NaiveDate,
+    coord: Coord,
+    target: f64,
+) -> RiseSet {
+    let baseline = SiderealBaseline::for_date(date);
+
+    // Altitude above (or below) that of the rise and
+    // the set at UT in decimal hours.
+    let altitude = |ut: f64| -> f64 {
+        let dt: NaiveDateTime =
+            date.and_hms_opt(0, 0, 0).unwrap()
+                + Duration::nanoseconds(
+                    (ut * 3_600_000_000_000.0) as i64,
+                );
+        let coord_0: EquaCoord =
+            equatorial_position_of_the_moon_from_generic_datetime(
+                dt,
+            );
+        let asc: f64 =
+            decimal_hours_from_angle(coord_0.asc);
+        let dec: f64 =
+            decimal_hours_from_angle(coord_0.dec)
+                .to_radians();
+
+        let lst: f64 = baseline.lst_at(ut, coord.lng);
+        let h: f64 =
+            ((lst - asc) * 15.0).to_radians();
+        let lat: f64 = coord.lat.to_radians();
+
+        let alt: f64 = ((lat.sin() * dec.sin())
+            + (lat.cos() * dec.cos() * h.cos()))
+        .asin()
+        .to_degrees();
+
+        alt - target
+    };
+
+    let mut rise: Option<NaiveTime> = None;
+    let mut set: Option<NaiveTime> = None;
+
+    let mut alt_0: f64 = altitude(0.0);
+
+    for hour in 1..=24 {
+        let ut_1: f64 = hour as f64;
+        let alt_1: f64 = altitude(ut_1);
+
+        let rising: bool =
+            alt_0 < 0.0 && alt_1 >= 0.0;
+        let setting: bool =
+            alt_0 >= 0.0 && alt_1 < 0.0;
+
+        if (rising && rise.is_none())
+            || (setting && set.is_none())
+        {
+            let mut lo: f64 = ut_1 - 1.0;
+            let mut hi: f64 = ut_1;
+
+            // 1 hour / 2^12 is less than a second.
+            for _ in 0..12 {
+                let mid: f64 = (lo + hi) / 2.0;
+                if (altitude(mid) >= 0.0) == rising {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+
+            // It may be 24h at the very end.
+            let ut: f64 = hi.min(24.0 - 1e-9);
+            let t = naive_time_from_decimal_hours(ut);
+
+            if rising {
+                rise = Some(t);
+            } else {
+                set = Some(t);
+            }
+        }
+
+        alt_0 = alt_1;
+    }
+
+    if rise.is_none() && set.is_none() {
+        // No crossing, so the altitude at 24h tells
+        // where it has been all day.
+        return if alt_0 >= 0.0 {
+            RiseSet::circumpolar()
+        } else {
+            RiseSet::never_rises()
+        };
+    }
+
+    RiseSet::new(rise, set)
+}
+
+/// The moon's distance and apparent size.
+///
+/// * `distance_km` - Distance from the earth (ρ) in km
+/// * `horizontal_parallax` - Horizontal parallax (π)
+/// * `angular_diameter` - Angular diameter (θ)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonDistance {
+    pub distance_km: f64,
+    pub horizontal_parallax: Angle,
+    pub angular_diameter: Angle,
+}
+
+/// Given the specific date and time, returns the
+/// moon's distance, horizontal parallax, and angular
+/// diameter (`MoonDistance`). They come from the
+/// moon's corrected anomaly (M'm) and the equation of
+/// the center (Ec), which are shared with
+/// `equatorial_position_of_the_moon_from_generic_datetime`.
+///
+/// ρ = a (1 - e^2) / (1 + e cos(M'm + Ec))
+/// θ = θ0 a / ρ
+/// π = π0 a / ρ
+///
+/// The orbit here is an ellipse with the evection
+/// and the annual equation applied to the anomaly,
+/// but not the variation, so that the distance may
+/// be off by several thousand km at the extremes
+/// (about 2% in the angular diameter).
+///
+/// * `dt` - DateTime
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.148)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::moon::{
+///     distance_and_angular_size_from_generic_datetime,
+///     MoonDistance,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // Perigee (July 13, 2022, at 357,264 km)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 7, 13).and_hms(9, 0, 0);
+/// let moon: MoonDistance =
+///     distance_and_angular_size_from_generic_datetime(dt);
+/// let diameter: f64 =
+///     decimal_hours_from_angle(moon.angular_diameter) * 60.0;
+///
+/// assert!((moon.distance_km - 357_264.0).abs() < 7_000.0);
+/// assert!((diameter - 33.0).abs() < 0.5); // 32.88'
+/// assert_eq!(moon.horizontal_parallax.hour(), 1);
+///
+/// // Apogee (July 26, 2022, at 406,273 km)
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 7, 26).and_hms(10, 0, 0);
+/// let moon: MoonDistance =
+///     distance_and_angular_size_from_generic_datetime(dt);
+/// let diameter: f64 =
+///     decimal_hours_from_angle(moon.angular_diameter) * 60.0;
+///
+/// assert!((moon.distance_km - 406_273.0).abs() < 7_000.0);
+/// assert!((diameter - 29.0).abs() < 0.5); // 29.47'
+/// assert_eq!(moon.horizontal_parallax.minute(), 54);
+/// ```
+pub fn distance_and_angular_size_from_generic_datetime<
+    T,
+>(
+    dt: T,
+) -> MoonDistance
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let orbit = moon_orbit_from_generic_datetime(dt);
+
+    let e: f64 = ECCENTRICITY_OF_MOON_ORBIT;
+    let v: f64 =
+        (orbit.anomaly + orbit.center).to_radians();
+
+    // Distance in the unit of the semi-major axis
+    let rho: f64 =
+        (1.0 - e * e) / (1.0 + e * v.cos());
+
+    MoonDistance {
+        distance_km: rho * SEMI_MAJOR_AXIS_OF_MOON_ORBIT,
+        horizontal_parallax: angle_from_decimal_hours(
+            PARALLAX_AT_DISTANCE_A_FROM_THE_EARTH / rho,
+        ),
+        angular_diameter: angle_from_decimal_hours(
+            MOON_ANGULAR_SIZE_AT_DISTANCE_A_FROM_THE_EARTH
+                / rho,
+        ),
+    }
+}
+
+/// Intermediate results for the moon's position
+/// which are shared among the moon's functions.
+/// All in degrees.
+struct MoonOrbit {
+    lat: f64,      // Ecliptic latitude (βm)
+    lng: f64,      // Ecliptic longitude (λm)
+    true_lng: f64, // Moon's true orbital longitude (l'')
+    sun_lng: f64,  // Sun's longitude (λ)
+    anomaly: f64,  // Moon's corrected anomaly (M'm)
+    center: f64,   // Equation of the center (Ec)
+}
+
+/// See `equatorial_position_of_the_moon_from_generic_datetime`
+/// for the specs.
+fn moon_orbit_from_generic_datetime<T>(
+    dt: T,
+) -> MoonOrbit
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    _moon_orbit_aux(dt, &MoonElements::default())
+}
+
+/// See `moon_orbit_from_generic_datetime` for the
+/// specs, with the days counted from the epoch of
+/// `elements`.
+#[allow(clippy::many_single_char_names)]
+fn _moon_orbit_aux<T>(
+    dt: T,
+    elements: &MoonElements,
+) -> MoonOrbit
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let tt: NaiveDateTime = tt_from_ut(dt);
+    let date: NaiveDate = tt.date();
     let day_number =
         day_number_from_generic_date(date) as f64;
-    let delta_t: f64 =
-        delta_t_from_generic_date(date);
-
-    let angle = Angle::new(
-        dt.hour() as i32,
-        dt.minute() as i32,
-        (dt.second() as f64) + delta_t,
-    );
 
-    let hours: f64 = decimal_hours_from_angle(angle);
+    let hours: f64 =
+        decimal_hours_from_generic_time(tt);
     let days_jan_0: f64 = day_number + (hours / 24.0);
 
-    // Days since 1990 (d)
-    let days: f64 = days_since_1990(date.year())
-        as f64
+    // Days since the epoch (d)
+    let days: f64 = days_since_epoch(
+        date.year(),
+        elements.sun.epoch_year,
+    ) as f64
         + days_jan_0;
 
     // Sun's longitude (λ) and Sun's mean anomaly (M)
     let (sun_lng, sun_mean_anom): (f64, f64) =
-        sun_longitude_and_mean_anomaly(days);
+        _longitude_and_mean_anomaly_aux(
+            days,
+            &elements.sun,
+        );
 
     // Moon's mean longitude (l)
-    let mut l: f64 = 13.176_396_6 * days
-        + MOON_MEAN_LONGITUDE_AT_THE_EPOCH;
+    let mut l: f64 =
+        13.176_396_6 * days + elements.lng_at_epoch;
     l -= 360.0 * (l / 360.0).floor();
 
     // Moon's mean anomaly (Mm)
     let mut mm: f64 = l
         - (0.111_404_1 * days)
-        - MEAN_LONGITUDE_OF_PERIGEE_AT_THE_EPOCH;
+        - elements.lng_of_perigee;
 
     mm -= 360.0 * (mm / 360.0).floor();
 
     // Acending node's mean longitude (N).
     let mut n: f64 =
-        MEAN_LONGITUDE_OF_THE_NODE_AT_THE_EPOCH
-            - (0.052_953_9 * days);
+        elements.lng_of_node - (0.052_953_9 * days);
 
     n -= 360.0 * (n / 360.0).floor();
 
@@ -196,8 +1381,418 @@ where
     .asin()
     .to_degrees();
 
-    equatorial_from_ecliptic_with_generic_date(
-        EcliCoord { lat, lng },
-        date,
+    MoonOrbit {
+        lat,
+        lng,
+        true_lng: l,
+        sun_lng,
+        anomaly: mm,
+        center: ec,
+    }
+}
+
+/// Given the specific date and time (in UT) and the
+/// observer's location, returns the apparent right
+/// ascension (α) and declination (δ) of the moon as
+/// seen by the observer (for pointing a telescope).
+/// The pipeline goes:
+///
+/// 1. Geocentric α and δ
+///    (`equatorial_position_of_the_moon_from_generic_datetime`)
+/// 2. Diurnal parallax with the moon's distance
+///    (`apply_parallax_equatorial`), giving the
+///    topocentric α' and δ'
+/// 3. Into horizontal coordinate with the hour-angle
+///    (H = LST - α') (`horizon_from_equatorial`)
+/// 4. Refraction for the standard atmosphere
+///    (`apply_refraction`)
+/// 5. Back into equatorial coordinate
+///    (`equatorial_from_horizon`), and α = LST - H
+///
+/// * `dt` - DateTime (UT)
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.54-55, p.67, p.144)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::coords::{
+///     angle_between_two_celestial_objects_for_equatorial,
+///     Coord, EquaCoord,
+/// };
+/// use sowngwala::moon::{
+///     equatorial_position_of_the_moon_from_generic_datetime,
+///     topocentric_equatorial,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// let dt: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 7, 13).and_hms(12, 0, 0);
+/// let tokyo = Coord { lat: 35.6762, lng: 139.6503 };
+///
+/// let geo: EquaCoord =
+///     equatorial_position_of_the_moon_from_generic_datetime(dt);
+/// let topo: EquaCoord = topocentric_equatorial(dt, tokyo);
+///
+/// // The parallax pulls the moon down (toward the
+/// // south from Tokyo) by tens of arcminutes.
+/// let geo_dec = decimal_hours_from_angle(geo.dec);
+/// let topo_dec = decimal_hours_from_angle(topo.dec);
+/// assert!(topo_dec < geo_dec);
+///
+/// let shift: f64 =
+///     angle_between_two_celestial_objects_for_equatorial(
+///         geo, topo,
+///     ) * 60.0;
+/// assert!(shift > 10.0 && shift < 62.0);
+///
+/// // Also when the moon is far from the meridian
+/// // (and below the horizon).
+/// for &hour in [0, 3, 6, 21].iter() {
+///     let dt: NaiveDateTime =
+///         NaiveDate::from_ymd(2022, 7, 13).and_hms(hour, 0, 0);
+///     let geo: EquaCoord =
+///         equatorial_position_of_the_moon_from_generic_datetime(dt);
+///     let topo: EquaCoord = topocentric_equatorial(dt, tokyo);
+///
+///     let d_dec: f64 = decimal_hours_from_angle(topo.dec)
+///         - decimal_hours_from_angle(geo.dec);
+///     let mut d_asc: f64 = decimal_hours_from_angle(topo.asc)
+///         - decimal_hours_from_angle(geo.asc);
+///     d_asc -= 24.0 * (d_asc / 24.0).round();
+///
+///     assert!(d_dec.abs() < 1.0, "{}h: {}", hour, d_dec);
+///     assert!(d_asc.abs() < 0.1, "{}h: {}", hour, d_asc);
+/// }
+/// ```
+pub fn topocentric_equatorial<T>(
+    dt: T,
+    coord: Coord,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    topocentric_equatorial_for_observer(
+        dt,
+        Observer::at(coord.lat, coord.lng),
+    )
+}
+
+/// Same as `topocentric_equatorial`, but for
+/// `Observer`, using the observer's atmosphere for
+/// the refraction.
+///
+/// * `dt` - DateTime (UT)
+/// * `observer` - Observer
+pub fn topocentric_equatorial_for_observer<T>(
+    dt: T,
+    observer: Observer,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let coord: Coord = observer.coord;
+    let (apparent, lst): (HorizCoord, f64) =
+        _topocentric_horizontal_aux(dt, observer);
+
+    // 5. Equatorial
+    let equa: EquaCoord2 =
+        equatorial_from_horizon(apparent, coord.lat);
+    let mut asc: f64 =
+        lst - decimal_hours_from_angle(equa.ha);
+    asc -= 24.0 * (asc / 24.0).floor();
+
+    EquaCoord {
+        asc: RightAscension::new(
+            angle_from_decimal_hours(asc),
+        ),
+        dec: equa.dec,
+    }
+}
+
+// Steps 1 to 4 of `topocentric_equatorial` (see the
+// specs), which returns the apparent horizontal
+// coordinate, and LST (in decimal hours).
+fn _topocentric_horizontal_aux<T>(
+    dt: T,
+    observer: Observer,
+) -> (HorizCoord, f64)
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let coord: Coord = observer.coord;
+    let date: NaiveDate =
+        naive_date_from_generic_datetime(dt);
+    let ut: f64 = decimal_hours_from_generic_time(dt);
+    let lst: f64 = SiderealBaseline::for_date(date)
+        .lst_at(ut, coord.lng);
+
+    // 1. Geocentric
+    let geo: EquaCoord =
+        equatorial_position_of_the_moon_from_generic_datetime(
+            dt,
+        );
+
+    // 2. Parallax
+    let r: f64 =
+        distance_and_angular_size_from_generic_datetime(dt)
+            .distance_km
+            / EARTH_EQUATORIAL_RADIUS_KM;
+    let topo: EquaCoord = apply_parallax_equatorial(
+        geo,
+        coord,
+        r,
+        naive_time_from_decimal_hours(lst),
+    );
+
+    // 3. Horizontal
+    let mut ha: f64 =
+        lst - decimal_hours_from_angle(topo.asc);
+    ha -= 24.0 * (ha / 24.0).floor();
+    let horiz: HorizCoord = horizon_from_equatorial(
+        EquaCoord2 {
+            ha: HourAngle::new(
+                angle_from_decimal_hours(ha),
+            ),
+            dec: topo.dec,
+        },
+        coord.lat,
+    );
+
+    // 4. Refraction
+    let apparent: HorizCoord =
+        observer.apply_refraction(horiz);
+
+    (apparent, lst)
+}
+
+/// Given UT and the observer's location, returns the
+/// altitude (a) and azimuth (A) of the moon at the
+/// moment, from the center of the earth (geocentric).
+/// The moon's position (see
+/// `equatorial_position_of_the_moon_from_generic_datetime`)
+/// and its hour-angle (see `hour_angle_from_utc`) are
+/// turned into horizontal coordinate (see
+/// `horizon_from_equatorial`). Neither the parallax
+/// (which lowers the moon by up to a degree) nor
+/// refraction is considered (see
+/// `horizontal_position_for_observer`).
+///
+/// * `utc` - UTC
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.35-37, p.144)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::{Coord, HorizCoord};
+/// use sowngwala::moon::horizontal_position_from_utc;
+/// use sowngwala::time::{build_utc, decimal_hours_from_angle};
+///
+/// // Tokyo, on the day of the full moon (2022-07-13),
+/// // which rises around the sunset, and sets around
+/// // the sunrise.
+/// let tokyo = Coord { lat: 35.6762, lng: 139.6503 };
+///
+/// // Below the horizon at the local noon (03:00 UT)
+/// let noon: HorizCoord = horizontal_position_from_utc(
+///     build_utc(2022, 7, 13, 3, 0, 0, 0),
+///     tokyo,
+/// );
+/// assert!(decimal_hours_from_angle(noon.alt) < 0.0);
+///
+/// // Above the horizon at the local midnight (15:00 UT)
+/// let midnight: HorizCoord = horizontal_position_from_utc(
+///     build_utc(2022, 7, 13, 15, 0, 0, 0),
+///     tokyo,
+/// );
+/// assert!(decimal_hours_from_angle(midnight.alt) > 0.0);
+/// ```
+pub fn horizontal_position_from_utc(
+    utc: DateTime<Utc>,
+    coord: Coord,
+) -> HorizCoord {
+    let equa: EquaCoord =
+        equatorial_position_of_the_moon_from_generic_datetime(
+            utc,
+        );
+    let (lng, dir) = coord.lng_with_direction();
+    let ha: HourAngle =
+        hour_angle_from_utc(utc, equa.asc, lng, dir);
+
+    horizon_from_equatorial(
+        EquaCoord2 { ha, dec: equa.dec },
+        coord.lat,
     )
 }
+
+/// Same as `horizontal_position_from_utc`, but as
+/// seen by `Observer` on the surface of the earth
+/// (topocentric), with the parallax, and the
+/// refraction in the observer's atmosphere (see
+/// `topocentric_equatorial_for_observer`).
+///
+/// * `utc` - UTC
+/// * `observer` - Observer
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::Observer;
+/// use sowngwala::moon::{
+///     horizontal_position_for_observer,
+///     horizontal_position_from_utc,
+/// };
+/// use sowngwala::time::{build_utc, decimal_hours_from_angle};
+///
+/// let observer = Observer::at(35.6762, 139.6503);
+/// let utc = build_utc(2022, 7, 13, 15, 0, 0, 0);
+///
+/// let geo = horizontal_position_from_utc(utc, observer.coord);
+/// let topo = horizontal_position_for_observer(utc, observer);
+///
+/// // The parallax lowers the moon by about a degree.
+/// let diff: f64 = decimal_hours_from_angle(geo.alt)
+///     - decimal_hours_from_angle(topo.alt);
+/// assert!(diff > 0.5 && diff < 1.0);
+///
+/// // Below the horizon (at the local noon, and far
+/// // from the meridian) as well, where it stays on
+/// // the same side of the horizon and the azimuth.
+/// for &hour in [0, 3, 6, 21].iter() {
+///     let utc = build_utc(2022, 7, 13, hour, 0, 0, 0);
+///     let geo = horizontal_position_from_utc(utc, observer.coord);
+///     let topo = horizontal_position_for_observer(utc, observer);
+///
+///     let geo_alt: f64 = decimal_hours_from_angle(geo.alt);
+///     let topo_alt: f64 = decimal_hours_from_angle(topo.alt);
+///     assert!(geo_alt < 0.0 && topo_alt < 0.0, "{}h", hour);
+///     assert!((geo_alt - topo_alt).abs() < 1.5, "{}h", hour);
+///
+///     let mut d_azi: f64 = decimal_hours_from_angle(topo.azi)
+///         - decimal_hours_from_angle(geo.azi);
+///     d_azi -= 360.0 * (d_azi / 360.0).round();
+///     assert!(d_azi.abs() < 1.0, "{}h: {}", hour, d_azi);
+/// }
+/// ```
+pub fn horizontal_position_for_observer(
+    utc: DateTime<Utc>,
+    observer: Observer,
+) -> HorizCoord {
+    _topocentric_horizontal_aux(utc, observer).0
+}
+
+/// Given a date, returns the moment (in UT) of the
+/// next moon's phase of the given name after the date
+/// (from 0h). The new moon, the first quarter, the
+/// full moon, and the last quarter are when the moon's
+/// age (D) is 0°, 90°, 180°, and 270°, and the
+/// crescents and the gibbous moons are taken at the
+/// middle of them (45°, 135°, 225°, and 315°). Day by
+/// day, the moon's age is checked if it has passed the
+/// target, and the moment is narrowed down by
+/// bisection to within a second. When the date (at 0h)
+/// is exactly at the phase, the next one is returned.
+/// Should the target not be crossed within 31 days
+/// (which does not happen for the moon's age), the day
+/// closest to the target is returned instead.
+///
+/// * `after` - Date
+/// * `phase` - Name of the phase
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.154-155)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::moon::{next_phase, MoonPhaseName};
+///
+/// // The first full moon of 2022 was on January 17
+/// // at 23:48 (UT).
+/// let dt: NaiveDateTime = next_phase(
+///     NaiveDate::from_ymd(2022, 1, 1),
+///     MoonPhaseName::FullMoon,
+/// );
+/// let expected: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 1, 17).and_hms(23, 48, 0);
+/// assert!((dt - expected).num_minutes().abs() <= 5);
+/// ```
+pub fn next_phase(
+    after: NaiveDate,
+    phase: MoonPhaseName,
+) -> NaiveDateTime {
+    let target: f64 = match phase {
+        MoonPhaseName::NewMoon => 0.0,
+        MoonPhaseName::WaxingCrescent => 45.0,
+        MoonPhaseName::FirstQuarter => 90.0,
+        MoonPhaseName::WaxingGibbous => 135.0,
+        MoonPhaseName::FullMoon => 180.0,
+        MoonPhaseName::WaningGibbous => 225.0,
+        MoonPhaseName::LastQuarter => 270.0,
+        MoonPhaseName::WaningCrescent => 315.0,
+    };
+
+    // Moon's age (D) from the target in (-180, 180]
+    let diff = |dt: NaiveDateTime| -> f64 {
+        let age: f64 =
+            phase_from_generic_datetime(dt).age_days
+                * (360.0 / SYNODIC_MONTH);
+        let mut d: f64 = age - target;
+        d -= 360.0 * (d / 360.0).floor();
+        if d > 180.0 {
+            d -= 360.0;
+        }
+        d
+    };
+
+    let mut lo: NaiveDateTime =
+        after.and_hms_opt(0, 0, 0).unwrap();
+    let mut d_0: f64 = diff(lo);
+
+    // The day closest to the target so far
+    let mut closest: (NaiveDateTime, f64) =
+        (lo, d_0.abs());
+
+    // The moon's age increases about 12° a day, so it
+    // passes the target within 31 days.
+    for _ in 0..31 {
+        let hi: NaiveDateTime =
+            lo + Duration::days(1);
+        let d_1: f64 = diff(hi);
+
+        if d_0 < 0.0 && d_1 >= 0.0 {
+            let mut lo: NaiveDateTime = lo;
+            let mut hi: NaiveDateTime = hi;
+
+            // A day / 2^17 is less than a second.
+            for _ in 0..17 {
+                let mid: NaiveDateTime =
+                    lo + (hi - lo) / 2;
+                if diff(mid) >= 0.0 {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+            return hi;
+        }
+
+        if d_1.abs() < closest.1 {
+            closest = (hi, d_1.abs());
+        }
+
+        lo = hi;
+        d_0 = d_1;
+    }
+
+    closest.0
+}
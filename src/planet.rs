@@ -0,0 +1,566 @@
+use chrono::naive::NaiveDate;
+use chrono::Datelike;
+
+pub use crate::constants::OrbitalElements;
+use crate::constants::{
+    J2000, JUPITER_ELEMENTS, LIGHT_TIME_PER_AU,
+    MARS_ELEMENTS, MERCURY_ELEMENTS,
+    NEPTUNE_ELEMENTS, SATURN_ELEMENTS,
+    URANUS_ELEMENTS, VENUS_ELEMENTS,
+};
+use crate::coords::{
+    equatorial_from_ecliptic_with_generic_date,
+    geocentric_ecliptic_from_heliocentric,
+    geocentric_ecliptic_with_distance_from_heliocentric,
+    EcliCoord, EquaCoord,
+};
+use crate::error::SowngwalaError;
+use crate::sun::{
+    distance_and_angular_size_from_generic_date,
+    ecliptic_position_of_the_sun_from_generic_date,
+    solve_kepler,
+};
+use crate::time::{
+    day_number_from_generic_date, days_since_1990,
+    julian_day_from_generic_date,
+};
+use crate::vsop87::{self, sum_of_series, Vsop87};
+
+/// Planets other than the earth.
+/// (the earth's position is obtained from the sun)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl Planet {
+    /// Orbital elements at the epoch 1990.0.
+    pub fn elements(&self) -> OrbitalElements {
+        match self {
+            Planet::Mercury => MERCURY_ELEMENTS,
+            Planet::Venus => VENUS_ELEMENTS,
+            Planet::Mars => MARS_ELEMENTS,
+            Planet::Jupiter => JUPITER_ELEMENTS,
+            Planet::Saturn => SATURN_ELEMENTS,
+            Planet::Uranus => URANUS_ELEMENTS,
+            Planet::Neptune => NEPTUNE_ELEMENTS,
+        }
+    }
+
+    fn vsop87(&self) -> &'static Vsop87 {
+        match self {
+            Planet::Mercury => &vsop87::MERCURY,
+            Planet::Venus => &vsop87::VENUS,
+            Planet::Mars => &vsop87::MARS,
+            Planet::Jupiter => &vsop87::JUPITER,
+            Planet::Saturn => &vsop87::SATURN,
+            Planet::Uranus => &vsop87::URANUS,
+            Planet::Neptune => &vsop87::NEPTUNE,
+        }
+    }
+}
+
+/// Given a planet and Julian Day, returns the planet's
+/// heliocentric longitude (L), latitude (B), and the
+/// radius vector (R) using the truncated VSOP87.
+/// L and B in degrees, and R in au.
+fn heliocentric_of_planet_from_julian_day(
+    planet: Planet,
+    jd: f64,
+) -> (f64, f64, f64) {
+    let series: &Vsop87 = planet.vsop87();
+
+    // Julian millennia since J2000.0 (τ)
+    let tau: f64 = (jd - J2000) / 365_250.0;
+
+    let mut l: f64 =
+        sum_of_series(series.l, tau).to_degrees();
+    l -= 360.0 * (l / 360.0).floor();
+
+    let b: f64 =
+        sum_of_series(series.b, tau).to_degrees();
+    let r: f64 = sum_of_series(series.r, tau);
+
+    (l, b, r)
+}
+
+/// Given a planet and the date, returns the planet's
+/// heliocentric ecliptic latitude (b) and longitude
+/// (l) using the truncated VSOP87 (the largest terms
+/// only). Coordinates are referred to the ecliptic
+/// and the equinox of the date.
+///
+/// * `planet` - Planet
+/// * `date` - Date
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", p.219)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::EcliCoord;
+/// use sowngwala::planet::{
+///     heliocentric_ecliptic_of_planet,
+///     Planet,
+/// };
+///
+/// // Meeus gives L = 26.11428° and B = -2.62070°
+/// // on 1992-12-20 (0h TD) from the full VSOP87.
+/// let date = NaiveDate::from_ymd(1992, 12, 20);
+/// let coord: EcliCoord =
+///     heliocentric_ecliptic_of_planet(
+///         Planet::Venus,
+///         date,
+///     );
+///
+/// assert!((coord.lng - 26.11428).abs() < 1.0 / 60.0);
+/// assert!((coord.lat + 2.62070).abs() < 1.0 / 60.0);
+///
+/// // The VSOP87 check values (vsop87.chk) give
+/// // L = 0.6334614186 rad (36.29467°) for Jupiter at
+/// // J2000.0 (2000-01-01 12h TD). At 0h, Jupiter is
+/// // half a day (0.08309° a day) behind.
+/// let date = NaiveDate::from_ymd(2000, 1, 1);
+/// let coord: EcliCoord =
+///     heliocentric_ecliptic_of_planet(
+///         Planet::Jupiter,
+///         date,
+///     );
+/// let lng_0: f64 = 36.29467 - 0.08309 / 2.0;
+///
+/// assert!((coord.lng - lng_0).abs() < 1.0 / 60.0);
+/// ```
+pub fn heliocentric_ecliptic_of_planet(
+    planet: Planet,
+    date: NaiveDate,
+) -> EcliCoord {
+    let jd: f64 = julian_day_from_generic_date(date);
+    let (lng, lat, _r) =
+        heliocentric_of_planet_from_julian_day(
+            planet, jd,
+        );
+
+    EcliCoord { lat, lng }
+}
+
+/// Given a planet and the date, returns right
+/// ascension (α) and declination (δ) of the planet
+/// as seen from the earth. The heliocentric position
+/// of the earth is taken from that of the sun (with
+/// the radius vector obtained from the true anomaly),
+/// and is subtracted from the planet's. The planet's
+/// position is corrected for the light-time, but
+/// neither for the aberration nor for the nutation.
+///
+/// * `planet` - Planet
+/// * `date` - Date
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", pp.223-225)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{Angle, EquaCoord};
+/// use sowngwala::planet::{
+///     geocentric_equatorial_of_planet,
+///     Planet,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // Meeus gives α = 21h 04m 41.454s and
+/// // δ = -18° 53' 16.84" on 1992-12-20 (0h TD).
+/// let date = NaiveDate::from_ymd(1992, 12, 20);
+/// let coord: EquaCoord =
+///     geocentric_equatorial_of_planet(
+///         Planet::Venus,
+///         date,
+///     );
+///
+/// let asc: f64 = decimal_hours_from_angle(coord.asc);
+/// let dec: f64 = decimal_hours_from_angle(coord.dec);
+/// let asc_0: f64 = decimal_hours_from_angle(
+///     Angle::new(21, 4, 41.454),
+/// );
+/// let dec_0: f64 = decimal_hours_from_angle(
+///     Angle::new(-18, 53, 16.84),
+/// );
+///
+/// // Within an arcminute
+/// assert!((asc - asc_0).abs() * 15.0 < 1.0 / 60.0);
+/// assert!((dec - dec_0).abs() < 1.0 / 60.0);
+///
+/// // The great conjunction of Jupiter and Saturn,
+/// // 6.1' apart at 2020-12-21 18h. At 0h, Jupiter
+/// // was still a few arcminutes behind.
+/// let date = NaiveDate::from_ymd(2020, 12, 21);
+/// let jupiter: EquaCoord =
+///     geocentric_equatorial_of_planet(
+///         Planet::Jupiter,
+///         date,
+///     );
+/// let saturn: EquaCoord =
+///     geocentric_equatorial_of_planet(
+///         Planet::Saturn,
+///         date,
+///     );
+///
+/// let dec: f64 = decimal_hours_from_angle(jupiter.dec);
+/// let asc_diff: f64 = (decimal_hours_from_angle(jupiter.asc)
+///     - decimal_hours_from_angle(saturn.asc))
+///     * 15.0
+///     * dec.to_radians().cos();
+/// let dec_diff: f64 =
+///     dec - decimal_hours_from_angle(saturn.dec);
+/// let separation: f64 =
+///     (asc_diff.powi(2) + dec_diff.powi(2)).sqrt();
+///
+/// assert!(separation > 6.1 / 60.0);
+/// assert!(separation < 15.0 / 60.0);
+/// ```
+pub fn geocentric_equatorial_of_planet(
+    planet: Planet,
+    date: NaiveDate,
+) -> EquaCoord {
+    let jd: f64 = julian_day_from_generic_date(date);
+    let (l_0, r_0) = earth_from_sun(date);
+
+    let mut lng: f64 = 0.0;
+    let mut lat: f64 = 0.0;
+
+    // Light-time (τ) is found from the distance
+    // of the first round.
+    let mut light_time: f64 = 0.0;
+
+    for _ in 0..2 {
+        let (l, b, r) =
+            heliocentric_of_planet_from_julian_day(
+                planet,
+                jd - light_time,
+            );
+        let (coord, distance) =
+            geocentric_ecliptic_with_distance_from_heliocentric(
+                EcliCoord { lat: b, lng: l },
+                r,
+                EcliCoord { lat: 0.0, lng: l_0 },
+                r_0,
+            );
+
+        lng = coord.lng;
+        lat = coord.lat;
+        light_time = LIGHT_TIME_PER_AU * distance;
+    }
+
+    equatorial_from_ecliptic_with_generic_date(
+        EcliCoord { lat, lng },
+        date,
+    )
+}
+
+/// Given a planet and the date, returns right
+/// ascension (α) and declination (δ) of the planet
+/// using the orbital elements at the epoch 1990.0
+/// (`Planet::elements`). Kepler's equation is solved
+/// for the planet's true anomaly, and the heliocentric
+/// position is projected onto the ecliptic and seen
+/// from the earth. Results are referred to the mean
+/// equinox of 1990.0 (which moves about 50" a year),
+/// and are good to a few arcminutes for a decade or
+/// so around 1990. For the other dates, consider
+/// `geocentric_equatorial_of_planet` (VSOP87).
+///
+/// * `planet` - Planet
+/// * `date` - Date
+///
+/// # Panics
+///
+/// Panics when Kepler's equation does not converge,
+/// which does not happen for the planets' small
+/// eccentricities. See
+/// `try_equatorial_position_from_generic_date` for
+/// the version returning an error instead.
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.121-129)
+/// - (Jean Meeus, "Astronomical Algorithms", p.225)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::EquaCoord;
+/// use sowngwala::planet::{
+///     equatorial_position_from_generic_date,
+///     Planet,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // Venus on 1992 December 20 (Meeus, Example 33.a)
+/// // at 21h 04m 41.454s, and -18° 53′ 16.84″
+/// let date = NaiveDate::from_ymd(1992, 12, 20);
+///
+/// let coord: EquaCoord =
+///     equatorial_position_from_generic_date(
+///         Planet::Venus,
+///         date,
+///     );
+///
+/// let asc: f64 = decimal_hours_from_angle(coord.asc);
+/// let dec: f64 = decimal_hours_from_angle(coord.dec);
+/// let asc_0: f64 = 21.0 + (4.0 + 41.454 / 60.0) / 60.0;
+/// let dec_0: f64 = -(18.0 + (53.0 + 16.84 / 60.0) / 60.0);
+///
+/// // Within a few arcminutes
+/// assert!((asc - asc_0).abs() * 15.0 < 3.0 / 60.0);
+/// assert!((dec - dec_0).abs() < 3.0 / 60.0);
+///
+/// // Mercury on 2003 November 22 (Duffett-Smith,
+/// // 4th edition, §54) at 16h 49m 12s, and
+/// // -24° 30′ 9″. The book uses the elements at the
+/// // epoch 2010.0, and Mercury (with the largest
+/// // eccentricity and the fastest motion) is the
+/// // least accurate of the planets for the method.
+/// // Here, it is 16h 51m 52s, and -24° 38′ 13″.
+/// let date = NaiveDate::from_ymd(2003, 11, 22);
+///
+/// let coord: EquaCoord =
+///     equatorial_position_from_generic_date(
+///         Planet::Mercury,
+///         date,
+///     );
+///
+/// let asc: f64 = decimal_hours_from_angle(coord.asc);
+/// let dec: f64 = decimal_hours_from_angle(coord.dec);
+/// let asc_0: f64 = 16.0 + (49.0 + 12.0 / 60.0) / 60.0;
+/// let dec_0: f64 = -(24.0 + (30.0 + 9.0 / 60.0) / 60.0);
+///
+/// // Within 3 minutes (of time), and 10 arcminutes
+/// assert!((asc - asc_0).abs() < 3.0 / 60.0);
+/// assert!((dec - dec_0).abs() < 10.0 / 60.0);
+/// ```
+pub fn equatorial_position_from_generic_date<T>(
+    planet: Planet,
+    date: T,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    match try_equatorial_position_from_generic_date(
+        planet, date,
+    ) {
+        Ok(coord) => coord,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Same as `equatorial_position_from_generic_date`,
+/// but returns `SowngwalaError::Kepler` when Kepler's
+/// equation does not converge.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::planet::{
+///     equatorial_position_from_generic_date,
+///     try_equatorial_position_from_generic_date,
+///     Planet,
+/// };
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// let date = NaiveDate::from_ymd(1990, 6, 1);
+/// let coord = try_equatorial_position_from_generic_date(
+///     Planet::Mars,
+///     date,
+/// )
+/// .unwrap();
+/// let coord_0 =
+///     equatorial_position_from_generic_date(Planet::Mars, date);
+///
+/// assert_eq!(
+///     decimal_hours_from_angle(coord.asc),
+///     decimal_hours_from_angle(coord_0.asc)
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn try_equatorial_position_from_generic_date<T>(
+    planet: Planet,
+    date: T,
+) -> Result<EquaCoord, SowngwalaError>
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let elements: OrbitalElements = planet.elements();
+
+    // Days since 1990 (d)
+    let days: f64 = days_since_1990(date.year())
+        as f64
+        + day_number_from_generic_date(date) as f64;
+
+    // Mean anomaly (M)
+    let mut n: f64 = (360.0 / 365.242_191)
+        * (days / elements.period);
+    n -= 360.0 * (n / 360.0).floor();
+
+    let mean_anom: f64 = n + elements.lng_at_epoch
+        - elements.lng_of_perihelion;
+
+    let e: f64 = elements.eccentricity;
+
+    // Eccentric anomaly (E), and true anomaly (v)
+    let ecc: f64 =
+        solve_kepler(mean_anom.to_radians(), e)?;
+    let v: f64 = 2.0
+        * (((1.0 + e) / (1.0 - e)).sqrt()
+            * (ecc / 2.0).tan())
+        .atan();
+
+    // Heliocentric longitude (l) and radius vector (r)
+    let l: f64 =
+        v.to_degrees() + elements.lng_of_perihelion;
+    let r: f64 = elements.semi_major_axis
+        * (1.0 - e * e)
+        / (1.0 + e * v.cos());
+
+    // Heliocentric latitude (ψ) and the longitude
+    // projected onto the ecliptic (l')
+    let l_minus_node: f64 =
+        (l - elements.lng_of_node).to_radians();
+    let i: f64 = elements.inclination.to_radians();
+
+    let psi: f64 =
+        (l_minus_node.sin() * i.sin()).asin();
+    let l_1: f64 = (l_minus_node.sin() * i.cos())
+        .atan2(l_minus_node.cos())
+        .to_degrees()
+        + elements.lng_of_node;
+
+    let (l_0, r_0) = earth_from_sun(date);
+    let coord: EcliCoord =
+        geocentric_ecliptic_from_heliocentric(
+            EcliCoord {
+                lat: psi.to_degrees(),
+                lng: l_1,
+            },
+            r,
+            EcliCoord { lat: 0.0, lng: l_0 },
+            r_0,
+        );
+
+    Ok(equatorial_from_ecliptic_with_generic_date(
+        coord, date,
+    ))
+}
+
+/// Given a planet and the date, returns the phase
+/// angle (i) in degrees, and the illuminated fraction
+/// of the disk (k, from 0 to 1). The planet's
+/// distance from the sun (r) and from the earth (Δ)
+/// (see `geocentric_ecliptic_with_distance_from_heliocentric`),
+/// and the earth's distance from the sun (R) make the
+/// triangle, for which the angle at the planet is
+/// the phase angle. Mercury and Venus show crescents
+/// (i near 180°), whereas the outer planets are
+/// always nearly full.
+///
+/// cos i = (r^2 + Δ^2 - R^2) / (2 r Δ)
+/// k = (1 + cos i) / 2
+///
+/// * `planet` - Planet
+/// * `date` - Date
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.129)
+/// - (Jean Meeus, "Astronomical Algorithms", p.283)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::planet::{phase_from_generic_date, Planet};
+///
+/// // Venus at the greatest eastern elongation
+/// // (46.1°) on 2020-03-24 is half lit.
+/// let (i, k) = phase_from_generic_date(
+///     Planet::Venus,
+///     NaiveDate::from_ymd(2020, 3, 24),
+/// );
+/// assert!((i - 90.0).abs() < 3.0);
+/// assert!((k - 0.5).abs() < 0.03);
+///
+/// // And a thin crescent near the inferior
+/// // conjunction on 2020-06-03.
+/// let (i, k) = phase_from_generic_date(
+///     Planet::Venus,
+///     NaiveDate::from_ymd(2020, 6, 3),
+/// );
+/// assert!(i > 175.0);
+/// assert!(k < 0.01);
+///
+/// // Jupiter is always nearly full.
+/// let (_i, k) = phase_from_generic_date(
+///     Planet::Jupiter,
+///     NaiveDate::from_ymd(2020, 3, 24),
+/// );
+/// assert!(k > 0.99);
+/// ```
+pub fn phase_from_generic_date<T>(
+    planet: Planet,
+    date: T,
+) -> (f64, f64)
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let jd: f64 = julian_day_from_generic_date(date);
+    let (l_0, r_0) = earth_from_sun(date);
+    let (l, b, r) =
+        heliocentric_of_planet_from_julian_day(
+            planet, jd,
+        );
+
+    let (_coord, distance) =
+        geocentric_ecliptic_with_distance_from_heliocentric(
+            EcliCoord { lat: b, lng: l },
+            r,
+            EcliCoord { lat: 0.0, lng: l_0 },
+            r_0,
+        );
+
+    let cos_i: f64 = ((r * r)
+        + (distance * distance)
+        - (r_0 * r_0))
+        / (2.0 * r * distance);
+    let cos_i: f64 = cos_i.clamp(-1.0, 1.0);
+
+    (cos_i.acos().to_degrees(), (1.0 + cos_i) / 2.0)
+}
+
+/// Given the date, returns the earth's heliocentric
+/// longitude (L) in degrees and the radius vector (R)
+/// in au. The earth is in the opposite direction of
+/// the sun, at the distance of the sun.
+fn earth_from_sun<T>(date: T) -> (f64, f64)
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let sun_lng: f64 =
+        ecliptic_position_of_the_sun_from_generic_date(
+            date,
+        )
+        .lng;
+
+    let (r, _size) =
+        distance_and_angular_size_from_generic_date(
+            date,
+        );
+
+    (sun_lng + 180.0, r)
+}
@@ -1,92 +1,309 @@
 use chrono::naive::{
     NaiveDate, NaiveDateTime, NaiveTime,
 };
-use chrono::offset::Utc;
-use chrono::{DateTime, Datelike};
+use chrono::offset::{FixedOffset, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Timelike,
+};
 
 use crate::constants::{
     ECCENTRICITY_OF_ORBIT,
     ECLIPTIC_LONGITUDE_AT_1990,
     ECLIPTIC_LONGITUDE_OF_PERIGEE,
+    SUN_ALTITUDE_AT_RISE_AND_SET,
+    SUN_ANGULAR_DIAMETER_AT_1AU,
+};
+
+use crate::error::SowngwalaError;
+
+pub use crate::math::{
+    solve_kepler, solve_kepler_with, KeplerError,
 };
 
 use crate::coords::{
+    dip_correction,
     equatorial_from_ecliptic_with_generic_date,
-    Angle, EcliCoord, EquaCoord,
+    horizon_from_equatorial, hour_angle_from_utc,
+    rise_set_azimuth_at_altitude, Angle, Coord,
+    EcliCoord, EquaCoord, EquaCoord2, HorizCoord,
+    HourAngle, Observer, RiseSet, RiseSetStatus,
 };
 
 use crate::time::{
     angle_from_decimal_hours,
     day_number_from_generic_date, days_since_1990,
-    decimal_hours_from_naive_time, utc_from_gst,
+    days_since_epoch, decimal_hours_from_angle,
+    decimal_hours_from_generic_time,
+    decimal_hours_from_naive_time, fixed_from_utc,
+    gst_from_lst, julian_day_from_generic_date,
+    julian_day_from_generic_datetime,
+    naive_date_from_generic_datetime,
+    naive_time_from_decimal_hours,
+    naive_time_from_generic_datetime, utc_from_fixed,
+    utc_from_gst, utc_from_naive,
 };
 
-const KEPLER_ACCURACY: f64 = 1e-6; // (ε)
+use crate::utils::mean_obliquity_of_the_epliptic;
 
-// Private recursive function for 'find_kepler()'.
-fn _kepler_aux(
+/// Solves Kepler's equation for the sun's orbit
+/// (see `solve_kepler`), which always converges
+/// for the sun's small eccentricity. Returns `NaN`
+/// (instead of panicking) when it does not (which
+/// happens only for `NaN` or infinite `mean_anom`),
+/// for which `try_find_kepler` returns an error.
+pub fn find_kepler(mean_anom: f64) -> f64 {
+    try_find_kepler(mean_anom).unwrap_or(f64::NAN)
+}
+
+/// Same as `find_kepler`, but returns
+/// `SowngwalaError::NotFinite` for `NaN` or infinite
+/// `mean_anom` (in radians), and
+/// `SowngwalaError::Kepler` when it does not converge.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::error::SowngwalaError;
+/// use sowngwala::sun::{find_kepler, try_find_kepler};
+///
+/// assert_eq!(try_find_kepler(3.5), Ok(find_kepler(3.5)));
+/// assert_eq!(
+///     try_find_kepler(f64::NAN).map_err(|e| e.to_string()),
+///     Err("Not a finite value: NaN".to_string())
+/// );
+/// assert!(find_kepler(f64::NAN).is_nan());
+/// ```
+pub fn try_find_kepler(
     mean_anom: f64,
-    ecc: f64,
-    counter: u32,
-) -> f64 {
-    if counter > 1000 {
-        panic!("Dude, this is insane...");
-    }
-    let delta = ecc
-        - (ECCENTRICITY_OF_ORBIT * ecc.sin())
-        - mean_anom;
-    if delta.abs() > KEPLER_ACCURACY {
-        let delta_e = delta
-            / (1.0
-                - (ECCENTRICITY_OF_ORBIT
-                    * ecc.cos()));
-        _kepler_aux(
+) -> Result<f64, SowngwalaError> {
+    if !mean_anom.is_finite() {
+        return Err(SowngwalaError::NotFinite(
             mean_anom,
-            ecc - delta_e,
-            counter + 1,
-        )
-    } else {
-        ecc
+        ));
     }
+    Ok(solve_kepler(
+        mean_anom,
+        ECCENTRICITY_OF_ORBIT,
+    )?)
 }
 
-pub fn find_kepler(mean_anom: f64) -> f64 {
-    _kepler_aux(mean_anom, mean_anom, 0_u32)
+/// Orbital elements of the sun (or the earth) at the
+/// epoch (Jan 0th of `epoch_year`). The default is
+/// the one at the epoch 1990.0 used throughout. For
+/// the moon, see `MoonElements`.
+///
+/// * `epoch_year` - Year of the epoch
+/// * `lng_at_epoch` - Ecliptic longitude at the epoch (ε g)
+/// * `lng_of_perigee` - Ecliptic longitude of the perigee (ω bar g)
+/// * `eccentricity` - Eccentricity of the orbit (e)
+#[derive(Debug, Copy, Clone)]
+pub struct SunElements {
+    pub epoch_year: i32,
+    pub lng_at_epoch: f64,
+    pub lng_of_perigee: f64,
+    pub eccentricity: f64,
 }
 
-/// See 'equatorial_position_of_the_sun_from_date'
-/// for the specs.
-#[allow(clippy::many_single_char_names)]
-pub fn sun_longitude_and_mean_anomaly(
+impl Default for SunElements {
+    fn default() -> Self {
+        SunElements {
+            epoch_year: 1990,
+            lng_at_epoch: ECLIPTIC_LONGITUDE_AT_1990,
+            lng_of_perigee:
+                ECLIPTIC_LONGITUDE_OF_PERIGEE,
+            eccentricity: ECCENTRICITY_OF_ORBIT,
+        }
+    }
+}
+
+/// Given the days since 1990, returns the sun's mean
+/// anomaly (M) and the true anomaly (v) in degrees
+/// (the true motion of the sun in an ellipse) through
+/// the eccentric anomaly (E).
+fn anomalies_of_the_sun(days: f64) -> (f64, f64) {
+    _anomalies_aux(days, &SunElements::default())
+}
+
+/// See `anomalies_of_the_sun` for the specs. `days`
+/// are the days since the epoch of `elements`.
+fn _anomalies_aux(
     days: f64,
+    elements: &SunElements,
 ) -> (f64, f64) {
     let mut n: f64 = (360.0 / 365.242_191) * days;
     n -= 360.0 * (n / 360.0).floor();
 
     // Mean anomaly (M)
-    let mut mean_anom: f64 = n
-        + ECLIPTIC_LONGITUDE_AT_1990
-        - ECLIPTIC_LONGITUDE_OF_PERIGEE;
+    let mut mean_anom: f64 = n + elements
+        .lng_at_epoch
+        - elements.lng_of_perigee;
 
     if mean_anom < 0.0 {
         mean_anom += 360.0;
     }
 
+    // True anomaly (v)
+    let v: f64 = mean_anom
+        + equation_of_center(
+            mean_anom,
+            elements.eccentricity,
+        );
+
+    (mean_anom, v)
+}
+
+/// Same as `ecliptic_position_of_the_sun_from_generic_date`,
+/// but with the orbital elements at any epoch (see
+/// `SunElements`), counting the days from the epoch
+/// (see `days_since_epoch`).
+///
+/// * `date` - Datelike
+/// * `elements` - Orbital elements of the sun
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.91)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::{
+///     ecliptic_position_of_the_sun_from_generic_date,
+///     ecliptic_position_with_elements, SunElements,
+/// };
+///
+/// let date = NaiveDate::from_ymd(1988, 7, 27);
+///
+/// // The default (1990.0) is the same as the legacy.
+/// assert_eq!(
+///     ecliptic_position_with_elements(
+///         date,
+///         SunElements::default()
+///     )
+///     .lng,
+///     ecliptic_position_of_the_sun_from_generic_date(date).lng
+/// );
+///
+/// // The elements at the epoch 2010.0 (Duffett-Smith,
+/// // 4th edition) give almost the same longitude.
+/// let elements = SunElements {
+///     epoch_year: 2010,
+///     lng_at_epoch: 279.557_208,
+///     lng_of_perigee: 283.112_438,
+///     eccentricity: 0.016_705,
+/// };
+/// let lng: f64 =
+///     ecliptic_position_with_elements(date, elements).lng;
+/// assert!((lng - 124.188).abs() < 0.05);
+/// ```
+pub fn ecliptic_position_with_elements<T>(
+    date: T,
+    elements: SunElements,
+) -> EcliCoord
+where
+    T: Datelike,
+{
+    let days: f64 = days_since_epoch(
+        date.year(),
+        elements.epoch_year,
+    ) as f64
+        + day_number_from_generic_date(date) as f64;
+
+    let (_mean_anom, v): (f64, f64) =
+        _anomalies_aux(days, &elements);
+
+    // Sun's longitude (λ)
+    let mut lng: f64 = v + elements.lng_of_perigee;
+    lng -= 360.0 * (lng / 360.0).floor();
+
+    EcliCoord { lat: 0.0, lng }
+}
+
+/// Given the mean anomaly (M) in degrees and the
+/// eccentricity (e) of the orbit, returns the
+/// equation of the center (C = v - M) in degrees,
+/// which is how far the true anomaly (v) is ahead of
+/// (or behind) the mean anomaly. It is exact, going
+/// through the eccentric anomaly (E) from Kepler's
+/// equation (see `solve_kepler`), and returns `NaN`
+/// when it does not converge. For a small
+/// eccentricity, it is about 2e sin M (in radians).
+///
+/// * `mean_anomaly_deg` - Mean anomaly (M) in degrees
+/// * `eccentricity` - Eccentricity (e)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.90)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::sun::equation_of_center;
+///
+/// let e: f64 = 0.001;
+/// let m: f64 = 30.0;
+///
+/// // 2e sin M = 0.001 (radians)
+/// assert_approx_eq!(
+///     equation_of_center(m, e),
+///     (2.0 * e * m.to_radians().sin()).to_degrees(),
+///     1e-2
+/// );
+///
+/// // Nothing for a circular orbit
+/// assert_eq!(equation_of_center(m, 0.0), 0.0);
+/// ```
+pub fn equation_of_center(
+    mean_anomaly_deg: f64,
+    eccentricity: f64,
+) -> f64 {
+    let mean_anom: f64 =
+        mean_anomaly_deg.to_radians();
+
     // Eccentric anomaly (E)
     let ecc: f64 =
-        find_kepler(mean_anom.to_radians());
+        match solve_kepler(mean_anom, eccentricity) {
+            Ok(ecc) => ecc,
+            Err(_) => return f64::NAN,
+        };
 
     // True anomaly (v)
-    // (the true motion of the sun in an ellipse)
-    let mut v: f64 = ((1.0 + ECCENTRICITY_OF_ORBIT)
-        / (1.0 - ECCENTRICITY_OF_ORBIT))
-        .sqrt()
-        * (ecc / 2.0).tan();
-    v = (v.atan() * 2.0).to_degrees();
+    let v: f64 = 2.0
+        * (((1.0 + eccentricity)
+            / (1.0 - eccentricity))
+            .sqrt()
+            * (ecc / 2.0).tan())
+        .atan();
+
+    // Into (-180, 180]
+    let mut c: f64 = (v - mean_anom).to_degrees();
+    c -= 360.0 * (c / 360.0).floor();
+    if c > 180.0 {
+        c -= 360.0;
+    }
+    c
+}
+
+/// See 'equatorial_position_of_the_sun_from_date'
+/// for the specs.
+pub fn sun_longitude_and_mean_anomaly(
+    days: f64,
+) -> (f64, f64) {
+    _longitude_and_mean_anomaly_aux(
+        days,
+        &SunElements::default(),
+    )
+}
+
+/// See `sun_longitude_and_mean_anomaly` for the specs.
+/// `days` are the days since the epoch of `elements`.
+pub(crate) fn _longitude_and_mean_anomaly_aux(
+    days: f64,
+    elements: &SunElements,
+) -> (f64, f64) {
+    let (mean_anom, v): (f64, f64) =
+        _anomalies_aux(days, elements);
 
     // Sun's longitude (λ)
-    let mut lng: f64 =
-        v + ECLIPTIC_LONGITUDE_OF_PERIGEE;
+    let mut lng: f64 = v + elements.lng_of_perigee;
 
     if lng > 360.0 {
         lng -= 360.0;
@@ -107,8 +324,240 @@ pub fn ecliptic_position_of_the_sun_from_generic_date<
 where
     T: Datelike,
     T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
+{
+    let (coord, _mean_anom): (EcliCoord, f64) =
+        sun_ecliptic_and_anomaly_from_generic_date(
+            date,
+        );
+    coord
+}
+
+/// Given a specific date, returns the ecliptic
+/// coordinate of the sun (as
+/// `ecliptic_position_of_the_sun_from_generic_date`
+/// does), along with the sun's mean anomaly (M) in
+/// degrees, which is needed for the equation of the
+/// center, and the distance.
+///
+/// * `date` - Datelike
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.91)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::EcliCoord;
+/// use sowngwala::sun::sun_ecliptic_and_anomaly_from_generic_date;
+///
+/// let date = NaiveDate::from_ymd(1988, 7, 27);
+/// let (coord, mean_anom): (EcliCoord, f64) =
+///     sun_ecliptic_and_anomaly_from_generic_date(date);
+///
+/// assert_approx_eq!(
+///     mean_anom, // 202.12696096619...
+///     202.127,
+///     1e-3
+/// );
+/// assert_approx_eq!(
+///     coord.lng, // 124.18773182997...
+///     124.188,
+///     1e-3
+/// );
+/// ```
+pub fn sun_ecliptic_and_anomaly_from_generic_date<T>(
+    date: T,
+) -> (EcliCoord, f64)
+where
+    T: Datelike,
+{
+    let year: i32 = date.year();
+    let day_number =
+        day_number_from_generic_date(date) as f64;
+    let days: f64 =
+        days_since_1990(year) as f64 + day_number;
+
+    let (lng, mean_anom): (f64, f64) =
+        sun_longitude_and_mean_anomaly(days);
+
+    (EcliCoord { lat: 0.0, lng }, mean_anom)
+}
+
+/// Given a specific date, returns the monthly branch
+/// (地支, 0 to 11) from the sun's ecliptic longitude
+/// (λ) at 0h UT. A branch starts at one of the twelve
+/// "節" solar terms, and lasts 30 degrees (covering
+/// the "中" term in the middle):
+///
+/// ```text
+///  0: 315° 立春 (lichun) + 雨水 (yushui)       寅 (yin)
+///  1: 345° 啓蟄 (jingzhe) + 春分 (chunfen)     卯 (mao)
+///  2:  15° 清明 (qingming) + 穀雨 (guyu)       辰 (chen)
+///  3:  45° 立夏 (lixia) + 小滿 (xiaoman)       巳 (si)
+///  4:  75° 芒種 (mangzhong) + 夏至 (xiazhi)    午 (wu)
+///  5: 105° 小暑 (xiaoshu) + 大暑 (dashu)       未 (wei)
+///  6: 135° 立秋 (liqiu) + 處暑 (chushu)        申 (shen)
+///  7: 165° 白露 (bailu) + 秋分 (qiufen)        酉 (you)
+///  8: 195° 寒露 (hanlu) + 霜降 (shuangjiang)   戌 (xu)
+///  9: 225° 立冬 (lidong) + 小雪 (xiaoxue)      亥 (hai)
+/// 10: 255° 大雪 (daxue) + 冬至 (dongzhi)       子 (zi)
+/// 11: 285° 小寒 (xiaohan) + 大寒 (dahan)       丑 (chou)
+/// ```
+///
+/// * `date` - Date
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::zodiacal_branch_from_generic_date;
+///
+/// // 立夏 (lixia) for 2022 starts on 5/5.
+/// let date = NaiveDate::from_ymd(2022, 5, 6);
+/// assert_eq!(zodiacal_branch_from_generic_date(date), 3);
+/// ```
+pub fn zodiacal_branch_from_generic_date<T>(
+    date: T,
+) -> u8
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let lng: f64 =
+        ecliptic_position_of_the_sun_from_generic_date(date)
+            .lng;
+    let from_lichun: f64 =
+        (lng - 315.0).rem_euclid(360.0);
+
+    ((from_lichun / 30.0).floor() as u8).min(11)
+}
+
+/// The twelve signs of the tropical zodiac, 30
+/// degrees each along the ecliptic from the vernal
+/// equinox.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum ZodiacSign {
+    Aries,
+    Taurus,
+    Gemini,
+    Cancer,
+    Leo,
+    Virgo,
+    Libra,
+    Scorpio,
+    Sagittarius,
+    Capricorn,
+    Aquarius,
+    Pisces,
+}
+
+impl ZodiacSign {
+    /// Given the ecliptic longitude (λ) in degrees,
+    /// returns the sign (Aries for 0° to 30°, and so
+    /// on).
+    pub fn from_ecliptic_longitude(lng: f64) -> Self {
+        const SIGNS: [ZodiacSign; 12] = [
+            ZodiacSign::Aries,
+            ZodiacSign::Taurus,
+            ZodiacSign::Gemini,
+            ZodiacSign::Cancer,
+            ZodiacSign::Leo,
+            ZodiacSign::Virgo,
+            ZodiacSign::Libra,
+            ZodiacSign::Scorpio,
+            ZodiacSign::Sagittarius,
+            ZodiacSign::Capricorn,
+            ZodiacSign::Aquarius,
+            ZodiacSign::Pisces,
+        ];
+        let i = (lng.rem_euclid(360.0) / 30.0).floor()
+            as usize;
+        SIGNS[i.min(11)]
+    }
+}
+
+/// Given a specific date, returns the zodiac sign
+/// where the sun is at 0h UT (tropical, 30 degrees
+/// each).
+///
+/// * `date` - Date
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::{zodiac_sign_from_generic_date, ZodiacSign};
+///
+/// let date = NaiveDate::from_ymd(2022, 3, 21);
+/// assert_eq!(zodiac_sign_from_generic_date(date), ZodiacSign::Aries);
+/// ```
+pub fn zodiac_sign_from_generic_date<T>(
+    date: T,
+) -> ZodiacSign
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    ZodiacSign::from_ecliptic_longitude(
+        ecliptic_position_of_the_sun_from_generic_date(date)
+            .lng,
+    )
+}
+
+/// Given a specific date, returns the distance of the
+/// sun (r) in the unit of the semi-major axis of the
+/// earth's orbit (about 1 au), and the sun's angular
+/// diameter (θ) as `Angle` in degrees. Both come from
+/// the true anomaly (v):
+///
+/// r = (1 - e^2) / (1 + e cos v)
+/// θ = θ0 / r
+///
+/// * `date` - Date
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.90)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Angle;
+/// use sowngwala::sun::distance_and_angular_size_from_generic_date;
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // Perihelion (January 4, 2022)
+/// let (r, size): (f64, Angle) =
+///     distance_and_angular_size_from_generic_date(
+///         NaiveDate::from_ymd(2022, 1, 4),
+///     );
+/// assert!(r < 0.984);
+/// assert_eq!(size.hour(), 0);
+/// assert_eq!(size.minute(), 32);
+/// assert!(size.second() > 30.0);
+///
+/// // Aphelion (July 4, 2022)
+/// let (r_1, size_1): (f64, Angle) =
+///     distance_and_angular_size_from_generic_date(
+///         NaiveDate::from_ymd(2022, 7, 4),
+///     );
+/// assert!(r_1 > 1.016);
+/// assert_eq!(size_1.minute(), 31);
+/// assert!(
+///     decimal_hours_from_angle(size_1)
+///         < decimal_hours_from_angle(size)
+/// );
+/// ```
+pub fn distance_and_angular_size_from_generic_date<
+    T,
+>(
+    date: T,
+) -> (f64, Angle)
+where
+    T: Datelike,
+    T: std::marker::Copy,
 {
     let day_number =
         day_number_from_generic_date(date) as f64;
@@ -116,10 +565,20 @@ where
         as f64
         + day_number;
 
-    let (lng, _mean_anom): (f64, f64) =
-        sun_longitude_and_mean_anomaly(days);
+    let (_mean_anom, v): (f64, f64) =
+        anomalies_of_the_sun(days);
+    let v: f64 = v.to_radians();
 
-    EcliCoord { lat: 0.0, lng }
+    let f: f64 = (1.0
+        + ECCENTRICITY_OF_ORBIT * v.cos())
+        / (1.0 - ECCENTRICITY_OF_ORBIT.powi(2));
+
+    (
+        1.0 / f,
+        angle_from_decimal_hours(
+            SUN_ANGULAR_DIAMETER_AT_1AU * f,
+        ),
+    )
 }
 
 /// Given a specific date, returns right ascension (α)
@@ -130,7 +589,12 @@ where
 /// ECLIPTIC_LONGITUDE_OF_PERIGEE
 ///     --> Omega bar G (ω bar g)
 ///
-/// * `date` - Datelike
+/// The date is assumed to be in UT. For the local
+/// time with its zone, whose date may differ from the
+/// date in UT, see
+/// `equatorial_position_of_the_sun_from_fixed`.
+///
+/// * `date` - Datelike (UT)
 ///
 /// Reference:
 /// - (Peter Duffett-Smith, p.91)
@@ -145,8 +609,8 @@ where
 /// let date: NaiveDate = NaiveDate::from_ymd(1988, 7, 27);
 /// let coord: EquaCoord =
 ///     equatorial_position_of_the_sun_from_generic_date(date);
-/// let asc: Angle = coord.asc;
-/// let dec: Angle = coord.dec;
+/// let asc: Angle = coord.asc.into();
+/// let dec: Angle = coord.dec.into();
 ///
 /// assert_eq!(asc.hour(), 8);
 /// assert_eq!(asc.minute(), 26);
@@ -172,8 +636,6 @@ pub fn equatorial_position_of_the_sun_from_generic_date<
 where
     T: Datelike,
     T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     equatorial_from_ecliptic_with_generic_date(
         ecliptic_position_of_the_sun_from_generic_date(date),
@@ -181,80 +643,2102 @@ where
     )
 }
 
-/// Given the date in GST, returns the EOT.
-/// (Peter Duffett-Smith, pp.98-99)
-#[allow(clippy::many_single_char_names)]
-pub fn equation_of_time_from_gst(
-    gst: NaiveDateTime,
-) -> (Angle, f64) {
-    let date: NaiveDate = gst.date();
-    let coord: EquaCoord =
-        equatorial_position_of_the_sun_from_generic_date(date);
-    let asc_0: Angle = coord.asc;
-    let asc_1: NaiveTime = asc_0.into();
-    let naivetime = NaiveDateTime::new(date, asc_1);
-    let utc: NaiveTime = utc_from_gst(naivetime);
-    let decimal = decimal_hours_from_naive_time(utc);
-    let e = 12.0 - decimal;
-    let mut angle_0 = angle_from_decimal_hours(e);
-    let day_excess = angle_0.calibrate();
-    (angle_0, day_excess)
+/// Given a date range, returns the equatorial
+/// position of the sun for every `step_days` days
+/// from `start` up to (and including) `end`. The
+/// results are the same as calling
+/// `equatorial_position_of_the_sun_from_generic_date`
+/// for each date (useful when plotting the analemma),
+/// but the days since 1990 are only computed once,
+/// and advanced by `step_days` for each date. Both
+/// go through `sun_longitude_and_mean_anomaly` with
+/// the same days, so that the results are exactly
+/// those of the scalar function.
+///
+/// * `start` - The first date
+/// * `end` - The last date
+/// * `step_days` - Days between each date
+///
+/// Returns nothing when `step_days` is 0, for which
+/// `try_positions_over_range` returns an error.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::positions_over_range;
+///
+/// let positions = positions_over_range(
+///     NaiveDate::from_ymd(2021, 1, 1),
+///     NaiveDate::from_ymd(2021, 12, 31),
+///     7,
+/// );
+/// assert_eq!(positions.len(), 53);
+/// assert_eq!(
+///     positions[1].0,
+///     NaiveDate::from_ymd(2021, 1, 8)
+/// );
+/// ```
+pub fn positions_over_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    step_days: u32,
+) -> Vec<(NaiveDate, EquaCoord)> {
+    try_positions_over_range(start, end, step_days)
+        .unwrap_or_default()
 }
 
-#[allow(clippy::many_single_char_names)]
-pub fn equation_of_time_from_utc(
-    utc: DateTime<Utc>,
-) -> (Angle, f64) {
-    equation_of_time_from_gst(utc.naive_utc())
-}
+/// Same as `positions_over_range`, but returns
+/// `SowngwalaError::ZeroStep` when `step_days` is 0.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::error::SowngwalaError;
+/// use sowngwala::sun::try_positions_over_range;
+///
+/// let start = NaiveDate::from_ymd(2021, 1, 1);
+/// let end = NaiveDate::from_ymd(2021, 12, 31);
+///
+/// assert_eq!(
+///     try_positions_over_range(start, end, 7).unwrap().len(),
+///     53
+/// );
+/// assert_eq!(
+///     try_positions_over_range(start, end, 0).err(),
+///     Some(SowngwalaError::ZeroStep)
+/// );
+/// ```
+pub fn try_positions_over_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    step_days: u32,
+) -> Result<Vec<(NaiveDate, EquaCoord)>, SowngwalaError>
+{
+    if step_days == 0 {
+        return Err(SowngwalaError::ZeroStep);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::coords::EcliCoord;
-    use chrono::naive::NaiveDate;
+    let step = Duration::days(step_days as i64);
+    let mut positions = Vec::new();
+    let mut date = start;
+    let mut days: i64 = days_since_1990(start.year())
+        as i64
+        + day_number_from_generic_date(start) as i64;
 
-    #[test]
-    fn see_if_you_can_find_monthly_zhi() {
-        // 立夏 (Li-xia) for 2022 starts on 5/5.
-        // So, it should result in `3` for 5/6.
+    while date <= end {
+        let (lng, _mean_anom): (f64, f64) =
+            sun_longitude_and_mean_anomaly(
+                days as f64,
+            );
 
-        let date: NaiveDate =
-            NaiveDate::from_ymd(2022, 5, 6);
+        let coord =
+            equatorial_from_ecliptic_with_generic_date(
+                EcliCoord { lat: 0.0, lng },
+                date,
+            );
+        positions.push((date, coord));
+
+        date += step;
+        days += step_days as i64;
+    }
 
-        let ecliptic: EcliCoord =
-            ecliptic_position_of_the_sun_from_generic_date(date);
-
-        let lng: f64 = ecliptic.lng;
-
-        let branch: usize =
-            if (315.0..345.0).contains(&lng) {
-                0 // 立春 (lichun) + 雨水 (yushui) ---> 寅 (yin)
-            } else if !(15.0..345.0).contains(&lng) {
-                1 // 啓蟄 (jingzhe) + 春分 (chunfen) ---> 卯 (mao)
-            } else if (15.0..45.0).contains(&lng) {
-                2 // 清明 (qingming) + 穀雨 (guyu) ---> 辰 (chen)
-            } else if (45.0..75.0).contains(&lng) {
-                3 // 立夏 (lixia) + 小滿 (xiaoman) ---> 巳 (si)
-            } else if (75.0..105.0).contains(&lng) {
-                4 // 芒種 (mangzhong) + 夏至 (xiazhi) ---> 午 (wu)
-            } else if (105.0..135.0).contains(&lng) {
-                5 // 小暑 (xiaoshu) + 大暑 (dashu) ---> 未 (wei)
-            } else if (135.0..165.0).contains(&lng) {
-                6 // 立秋 (liqiu) + 處暑 (chushu) ---> 申 (shen)
-            } else if (165.0..195.0).contains(&lng) {
-                7 // 白露 (bailu) + 秋分 (qiufen) ---> 酉 (you)
-            } else if (195.0..225.0).contains(&lng) {
-                8 // 寒露 (hanlu) + 霜降 (shuangjiang) ---> 戌 (xu)
-            } else if (225.0..255.0).contains(&lng) {
-                9 // 立冬 (lidong) + 小雪 (xiaoxue) ---> 亥 (hai)
-            } else if (255.0..285.0).contains(&lng) {
-                10 // 大雪 (daxue) + 冬至 (dongzhi) ---> 子 (zi)
-            } else {
-                // lng >= 285.0 || lng < 315.0
-                11 // 小寒 (xiaohan) + 大寒 (dahan) ---> 丑 (chou)
-            };
-
-        assert_eq!(branch, 3);
+    Ok(positions)
+}
+
+/// Given a specific datetime (in UT), returns the
+/// ecliptic longitude (λ) of the sun. Unlike
+/// `ecliptic_position_of_the_sun_from_generic_date`,
+/// the time of the day is taken into account by
+/// adding the day fraction to the days since 1990.
+///
+/// * `dt` - DateTime (UT)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.91)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::EcliCoord;
+/// use sowngwala::sun::{
+///     ecliptic_position_of_the_sun_from_generic_date,
+///     ecliptic_position_of_the_sun_from_generic_datetime,
+/// };
+///
+/// let date = NaiveDate::from_ymd(1988, 7, 27);
+///
+/// // At 0h UT, it should be the same as the date.
+/// let coord_0: EcliCoord =
+///     ecliptic_position_of_the_sun_from_generic_date(date);
+/// let coord_1: EcliCoord =
+///     ecliptic_position_of_the_sun_from_generic_datetime(
+///         date.and_hms(0, 0, 0),
+///     );
+/// assert_eq!(coord_0.lng, coord_1.lng);
+///
+/// // The sun moves about 0.5 degree in 12 hours.
+/// let coord_2: EcliCoord =
+///     ecliptic_position_of_the_sun_from_generic_datetime(
+///         date.and_hms(12, 0, 0),
+///     );
+/// assert_approx_eq!(
+///     coord_2.lng - coord_1.lng, // 0.4770...
+///     0.48,
+///     1e-2
+/// );
+/// ```
+pub fn ecliptic_position_of_the_sun_from_generic_datetime<
+    T,
+>(
+    dt: T,
+) -> EcliCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let date = naive_date_from_generic_datetime(dt);
+    let day_number =
+        day_number_from_generic_date(date) as f64;
+    let hours: f64 = decimal_hours_from_generic_time(
+        naive_time_from_generic_datetime(dt),
+    );
+    let days: f64 = days_since_1990(date.year())
+        as f64
+        + day_number
+        + (hours / 24.0);
+
+    let (lng, _mean_anom): (f64, f64) =
+        sun_longitude_and_mean_anomaly(days);
+
+    EcliCoord { lat: 0.0, lng }
+}
+
+/// Given the target ecliptic longitude (λ) and a
+/// date, returns the datetime (in UT) when the sun
+/// crosses the longitude, nearest to the date (0h).
+/// Solar terms are when λ is a multiple of 15°, and
+/// the spring equinox is when λ = 0°. Starting from
+/// the date, the time is corrected by the remaining
+/// angle (wrapped to ±180°, so targets near 0° are
+/// fine) over the mean daily motion of the sun, until
+/// the correction gets less than a second. The sun's
+/// longitude is that of
+/// `ecliptic_position_of_the_sun_from_generic_datetime`,
+/// so that the crossing agrees with it at the same
+/// instant. Mind that the result is only as good as
+/// the sun's longitude (which is within a few
+/// hundredths of a degree, or within an hour in
+/// time).
+///
+/// * `target_deg` - Ecliptic longitude (λ)
+/// * `near` - Date
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.91)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::sun::longitude_crossing;
+///
+/// // The summer solstice of 2022 was on June 21
+/// // at 09:14 (UT).
+/// let dt: NaiveDateTime = longitude_crossing(
+///     90.0,
+///     NaiveDate::from_ymd(2022, 6, 1),
+/// );
+/// let expected: NaiveDateTime =
+///     NaiveDate::from_ymd(2022, 6, 21).and_hms(9, 14, 0);
+/// assert!((dt - expected).num_minutes().abs() <= 30);
+/// ```
+pub fn longitude_crossing(
+    target_deg: f64,
+    near: NaiveDate,
+) -> NaiveDateTime {
+    // Mean daily motion of the sun (in degrees)
+    let rate: f64 = 360.0 / 365.242_191;
+
+    let mut dt: NaiveDateTime =
+        near.and_hms_opt(0, 0, 0).unwrap();
+
+    for _ in 0..10 {
+        let lng: f64 =
+            ecliptic_position_of_the_sun_from_generic_datetime(
+                dt,
+            )
+            .lng;
+
+        // Remaining angle in (-180, 180]
+        let mut diff: f64 = target_deg - lng;
+        diff -= 360.0 * (diff / 360.0).floor();
+        if diff > 180.0 {
+            diff -= 360.0;
+        }
+
+        let seconds: f64 = diff / rate * 86_400.0;
+        dt += Duration::nanoseconds(
+            (seconds * 1_000_000_000.0) as i64,
+        );
+
+        if seconds.abs() < 1.0 {
+            break;
+        }
+    }
+
+    dt
+}
+
+/// Given a specific datetime (in UT), returns right
+/// ascension (α) and declination (δ) of the sun.
+/// See `ecliptic_position_of_the_sun_from_generic_datetime`
+/// for how the time of the day is handled.
+///
+/// * `dt` - DateTime (UT)
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.91)
+pub fn equatorial_position_of_the_sun_from_generic_datetime<
+    T,
+>(
+    dt: T,
+) -> EquaCoord
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    equatorial_from_ecliptic_with_generic_date(
+        ecliptic_position_of_the_sun_from_generic_datetime(
+            dt,
+        ),
+        naive_date_from_generic_datetime(dt),
+    )
+}
+
+/// Same as
+/// `equatorial_position_of_the_sun_from_generic_datetime`,
+/// but for the local time with its zone, which is
+/// converted into UTC first (see `utc_from_fixed`).
+/// Passing the local time to the generic functions
+/// would silently use the local date (and time).
+///
+/// * `fixed` - Local DateTime
+///
+/// Example:
+/// ```rust
+/// use chrono::DateTime;
+/// use chrono::naive::NaiveDate;
+/// use chrono::offset::FixedOffset;
+/// use sowngwala::coords::EquaCoord;
+/// use sowngwala::sun::{
+///     equatorial_position_of_the_sun_from_fixed,
+///     equatorial_position_of_the_sun_from_generic_datetime,
+/// };
+/// use sowngwala::time::{
+///     build_fixed, decimal_hours_from_angle,
+/// };
+///
+/// // 1988-07-28 06:00 (+09:00) is 1988-07-27 21:00 (UT).
+/// let fixed: DateTime<FixedOffset> =
+///     build_fixed(1988, 7, 28, 6, 0, 0, 0, 9);
+///
+/// let coord_0: EquaCoord =
+///     equatorial_position_of_the_sun_from_fixed(fixed);
+/// let coord_1: EquaCoord =
+///     equatorial_position_of_the_sun_from_generic_datetime(
+///         NaiveDate::from_ymd(1988, 7, 27).and_hms(21, 0, 0),
+///     );
+/// assert_eq!(
+///     decimal_hours_from_angle(coord_0.asc),
+///     decimal_hours_from_angle(coord_1.asc)
+/// );
+/// ```
+pub fn equatorial_position_of_the_sun_from_fixed(
+    fixed: DateTime<FixedOffset>,
+) -> EquaCoord {
+    let utc: DateTime<Utc> = utc_from_fixed(fixed);
+    equatorial_position_of_the_sun_from_generic_datetime(
+        utc,
+    )
+}
+
+/// Same as
+/// `ecliptic_position_of_the_sun_from_generic_datetime`,
+/// but for the local time with its zone, which is
+/// converted into UTC first (see `utc_from_fixed`).
+///
+/// * `fixed` - Local DateTime
+pub fn ecliptic_position_of_the_sun_from_fixed(
+    fixed: DateTime<FixedOffset>,
+) -> EcliCoord {
+    let utc: DateTime<Utc> = utc_from_fixed(fixed);
+    ecliptic_position_of_the_sun_from_generic_datetime(
+        utc,
+    )
+}
+
+/// Given the local clock time (with its zone) and
+/// the observer's location, returns the altitude (a)
+/// of the sun. Refraction is not considered.
+///
+/// * `local` - Local datetime
+/// * `observer` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.35-37, p.91)
+///
+/// Example:
+/// ```rust
+/// use chrono::DateTime;
+/// use chrono::offset::FixedOffset;
+/// use sowngwala::coords::{Angle, Coord};
+/// use sowngwala::time::{
+///     build_fixed,
+///     decimal_hours_from_angle,
+/// };
+/// use sowngwala::sun::sun_altitude_at_local;
+///
+/// // Tokyo
+/// let zone: i32 = 9;
+/// let observer = Coord { lat: 35.68, lng: 139.77 };
+///
+/// // Local solar noon is about 11:42 at Tokyo.
+/// let noon: DateTime<FixedOffset> =
+///     build_fixed(2021, 6, 21, 11, 42, 0, 0, zone);
+/// let afternoon: DateTime<FixedOffset> =
+///     build_fixed(2021, 6, 21, 15, 0, 0, 0, zone);
+///
+/// let alt_noon: Angle =
+///     sun_altitude_at_local(noon, observer);
+/// let alt_afternoon: Angle =
+///     sun_altitude_at_local(afternoon, observer);
+///
+/// let alt_noon = decimal_hours_from_angle(alt_noon);
+/// let alt_afternoon =
+///     decimal_hours_from_angle(alt_afternoon);
+///
+/// // 90 - (35.68 - 23.44) = 77.76
+/// assert!((alt_noon - 77.76).abs() < 0.5);
+/// assert!(alt_noon > alt_afternoon);
+/// ```
+pub fn sun_altitude_at_local(
+    local: DateTime<FixedOffset>,
+    observer: Coord,
+) -> Angle {
+    horizontal_position_from_utc(
+        utc_from_fixed(local),
+        observer,
+    )
+    .alt
+}
+
+/// Given UT and the observer's location, returns the
+/// altitude (a) and azimuth (A) of the sun at the
+/// moment. The sun's position is taken at the time
+/// (see `equatorial_position_of_the_sun_from_generic_datetime`),
+/// and its hour-angle (see `hour_angle_from_utc`) is
+/// turned into horizontal coordinate (see
+/// `horizon_from_equatorial`). Refraction is not
+/// considered (see `horizontal_position_for_observer`).
+///
+/// * `utc` - UTC
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.35-37, p.91)
+///
+/// Example:
+/// ```rust
+/// use chrono::{DateTime, Timelike};
+/// use chrono::offset::Utc;
+/// use sowngwala::coords::{Coord, HorizCoord};
+/// use sowngwala::sun::horizontal_position_from_utc;
+/// use sowngwala::time::{build_utc, decimal_hours_from_angle};
+///
+/// // Tokyo, when the sun is the highest (at about
+/// // 02:42 UT), on the summer solstice
+/// let coord = Coord { lat: 35.68, lng: 139.77 };
+/// let utc: DateTime<Utc> = build_utc(2021, 6, 21, 2, 42, 0, 0);
+///
+/// let noon: HorizCoord = horizontal_position_from_utc(utc, coord);
+/// let alt: f64 = decimal_hours_from_angle(noon.alt);
+/// let azi: f64 = decimal_hours_from_angle(noon.azi);
+///
+/// // 90 - |35.68 - 23.44| = 77.76 (due south)
+/// assert!((alt - 77.76).abs() < 0.5);
+/// assert!((azi - 180.0).abs() < 2.0);
+///
+/// // Lower an hour before and after
+/// for &hour in [1, 3].iter() {
+///     let other: HorizCoord = horizontal_position_from_utc(
+///         utc.with_hour(hour).unwrap(),
+///         coord,
+///     );
+///     assert!(decimal_hours_from_angle(other.alt) < alt);
+/// }
+/// ```
+pub fn horizontal_position_from_utc(
+    utc: DateTime<Utc>,
+    coord: Coord,
+) -> HorizCoord {
+    let equa: EquaCoord =
+        equatorial_position_of_the_sun_from_generic_datetime(
+            utc,
+        );
+    let (lng, dir) = coord.lng_with_direction();
+    let ha: HourAngle =
+        hour_angle_from_utc(utc, equa.asc, lng, dir);
+
+    horizon_from_equatorial(
+        EquaCoord2 { ha, dec: equa.dec },
+        coord.lat,
+    )
+}
+
+/// Same as `horizontal_position_from_utc`, but for
+/// `Observer`, with the altitude raised by the
+/// refraction in the observer's atmosphere (see
+/// `Observer::apply_refraction`).
+///
+/// * `utc` - UTC
+/// * `observer` - Observer
+///
+/// Example:
+/// ```rust
+/// use sowngwala::coords::Observer;
+/// use sowngwala::sun::{
+///     horizontal_position_for_observer,
+///     horizontal_position_from_utc,
+/// };
+/// use sowngwala::time::{build_utc, decimal_hours_from_angle};
+///
+/// // Tokyo, in the late afternoon
+/// let observer = Observer::at(35.68, 139.77);
+/// let utc = build_utc(2021, 6, 21, 9, 0, 0, 0);
+///
+/// let apparent = horizontal_position_for_observer(utc, observer);
+/// let geometric =
+///     horizontal_position_from_utc(utc, observer.coord);
+/// assert!(
+///     decimal_hours_from_angle(apparent.alt)
+///         > decimal_hours_from_angle(geometric.alt)
+/// );
+/// ```
+pub fn horizontal_position_for_observer(
+    utc: DateTime<Utc>,
+    observer: Observer,
+) -> HorizCoord {
+    observer.apply_refraction(
+        horizontal_position_from_utc(
+            utc,
+            observer.coord,
+        ),
+    )
+}
+
+/// Given a date and the observer's location, returns
+/// the length of the day (from sunrise to sunset).
+/// The sun's declination (δ) is taken at around the
+/// local noon, and the sun is regarded as risen when
+/// its altitude is above -0.833° (for refraction and
+/// semidiameter). It returns 24 hours when the sun
+/// never sets, and zero when it never rises.
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.97)
+///
+/// Example:
+/// ```rust
+/// use chrono::Duration;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Coord;
+/// use sowngwala::sun::day_length_from_date;
+///
+/// let coord = Coord { lat: 52.0, lng: 0.0 };
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let length: Duration = day_length_from_date(date, coord);
+/// assert_eq!(length.num_hours(), 16);
+///
+/// // Midnight sun
+/// let coord = Coord { lat: 78.0, lng: 15.0 };
+/// let length: Duration = day_length_from_date(date, coord);
+/// assert_eq!(length, Duration::hours(24));
+/// ```
+pub fn day_length_from_date(
+    date: NaiveDate,
+    coord: Coord,
+) -> Duration {
+    // Roughly, the local noon in UT.
+    let noon: NaiveDateTime =
+        date.and_hms_opt(12, 0, 0).unwrap()
+            - Duration::seconds(
+                (coord.lng * 240.0) as i64,
+            );
+
+    let dec: f64 = decimal_hours_from_angle(
+        equatorial_position_of_the_sun_from_generic_datetime(
+            noon,
+        )
+        .dec,
+    )
+    .to_radians();
+    let lat: f64 = coord.lat.to_radians();
+    let alt: f64 =
+        SUN_ALTITUDE_AT_RISE_AND_SET.to_radians();
+
+    let cos_h: f64 = (alt.sin()
+        - (lat.sin() * dec.sin()))
+        / (lat.cos() * dec.cos());
+
+    if cos_h <= -1.0 {
+        return Duration::hours(24);
+    }
+    if cos_h >= 1.0 {
+        return Duration::zero();
+    }
+
+    // The sun's hour-angle (H) advances 15° in
+    // an hour of solar time (not sidereal time).
+    let hours: f64 =
+        2.0 * cos_h.acos().to_degrees() / 15.0;
+
+    Duration::nanoseconds(
+        (hours * 3_600_000_000_000.0) as i64,
+    )
+}
+
+/// Given a date and the observer's location, returns
+/// how much longer (or shorter when negative) the
+/// next day is. It is zero when the sun never rises
+/// or never sets on both days.
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - sowngwala::sun::day_length_from_date
+///
+/// Example:
+/// ```rust
+/// use chrono::Duration;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Coord;
+/// use sowngwala::sun::day_length_change;
+///
+/// let coord = Coord { lat: 52.0, lng: 0.0 };
+///
+/// // Around the equinox, it changes the most.
+/// let date = NaiveDate::from_ymd(2022, 3, 20);
+/// let change: Duration = day_length_change(date, coord);
+/// assert!(change > Duration::minutes(3));
+/// assert!(change < Duration::minutes(5));
+///
+/// // Around the solstice, it hardly changes.
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let change: Duration = day_length_change(date, coord);
+/// assert!(change.num_seconds().abs() < 15);
+///
+/// // No change in the midnight sun.
+/// let coord = Coord { lat: 78.0, lng: 15.0 };
+/// let change: Duration = day_length_change(date, coord);
+/// assert_eq!(change, Duration::zero());
+/// ```
+pub fn day_length_change(
+    date: NaiveDate,
+    coord: Coord,
+) -> Duration {
+    let next: NaiveDate = date.succ_opt().unwrap();
+    day_length_from_date(next, coord)
+        - day_length_from_date(date, coord)
+}
+
+/// Given a date and the observer's location, returns
+/// the times (in UT) of sunrise and sunset. The sun
+/// is regarded as risen (or set) when its altitude is
+/// -0.833° (for refraction and semidiameter). Both
+/// are `None` when the sun stays above (or below) the
+/// horizon all day, and the status tells which.
+///
+/// The sun's position is first taken at around 6h (or
+/// 18h) of the local mean time, and the hour-angle (H)
+/// for the altitude gives LST of the event, which is
+/// converted into UT through GST. Then, the sun's
+/// position is taken again at the time found, and the
+/// process is repeated for a few times.
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.97)
+///
+/// Example:
+/// ```rust
+/// use chrono::Timelike;
+/// use chrono::naive::{NaiveDate, NaiveTime};
+/// use sowngwala::coords::{
+///     Coord, RiseSet, RiseSetStatus,
+/// };
+/// use sowngwala::sun::rise_set_from_date;
+///
+/// // Greenwich on the summer solstice of 2022.
+/// // Almanacs give 03:43 and 20:21 (UT).
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let coord = Coord { lat: 51.4769, lng: -0.0005 };
+///
+/// let rise_set: RiseSet = rise_set_from_date(date, coord);
+/// let rise: NaiveTime = rise_set.rise.unwrap();
+/// let set: NaiveTime = rise_set.set.unwrap();
+///
+/// assert_eq!(rise.hour(), 3);
+/// assert!((42..=44).contains(&rise.minute()));
+/// assert_eq!(set.hour(), 20);
+/// assert!((20..=22).contains(&set.minute()));
+///
+/// // Midnight sun at Tromsø
+/// let coord = Coord { lat: 69.65, lng: 18.96 };
+/// let rise_set: RiseSet = rise_set_from_date(date, coord);
+/// assert!(rise_set.rise.is_none());
+/// assert!(rise_set.set.is_none());
+/// assert_eq!(rise_set.status, RiseSetStatus::Circumpolar);
+/// ```
+pub fn rise_set_from_date(
+    date: NaiveDate,
+    coord: Coord,
+) -> RiseSet {
+    rise_set_from_date_with_height(date, coord, 0.0)
+}
+
+/// Same as `rise_set_from_date`, but for the observer
+/// at the given height (on a mountaintop, or in the
+/// air). The sun rises earlier (and sets later) by
+/// the dip of the horizon (`dip_correction`).
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+/// * `height_m` - Observer's height (in meters)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Coord;
+/// use sowngwala::sun::{
+///     rise_set_from_date,
+///     rise_set_from_date_with_height,
+/// };
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let coord = Coord { lat: 51.4769, lng: -0.0005 };
+///
+/// let ground = rise_set_from_date(date, coord);
+/// let tower =
+///     rise_set_from_date_with_height(date, coord, 300.0);
+///
+/// assert!(tower.rise.unwrap() < ground.rise.unwrap());
+/// assert!(tower.set.unwrap() > ground.set.unwrap());
+/// ```
+pub fn rise_set_from_date_with_height(
+    date: NaiveDate,
+    coord: Coord,
+    height_m: f64,
+) -> RiseSet {
+    _rise_set_from_date_aux(
+        date,
+        coord,
+        SUN_ALTITUDE_AT_RISE_AND_SET
+            - dip_correction(height_m),
+    )
+}
+
+/// Same as `rise_set_from_date`, but for `Observer`,
+/// taking the observer's height and atmosphere into
+/// account (see `Observer::rise_set_correction`).
+///
+/// * `date` - Date
+/// * `observer` - Observer
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::{Coord, Observer};
+/// use sowngwala::sun::{
+///     rise_set_for_observer, rise_set_from_date,
+/// };
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let observer = Observer::at(51.4769, -0.0005);
+///
+/// let rise_set = rise_set_for_observer(date, observer);
+/// let rise_set_0 = rise_set_from_date(date, observer.coord);
+/// assert_eq!(rise_set.rise, rise_set_0.rise);
+/// assert_eq!(rise_set.set, rise_set_0.set);
+/// ```
+pub fn rise_set_for_observer(
+    date: NaiveDate,
+    observer: Observer,
+) -> RiseSet {
+    _rise_set_from_date_aux(
+        date,
+        observer.coord,
+        SUN_ALTITUDE_AT_RISE_AND_SET
+            - observer.rise_set_correction(),
+    )
+}
+
+// Rise and set times (in UT), each paired with the
+// azimuth of the sun at the moment
+#[derive(Debug, Copy, Clone)]
+pub struct SunRiseSet {
+    pub rise: Option<(NaiveTime, Angle)>,
+    pub set: Option<(NaiveTime, Angle)>,
+}
+
+/// Given a date and observer's location, returns the
+/// rise and set times (in UT) of the sun, each paired
+/// with the azimuth (A) of the sun at the moment (see
+/// `rise_set_azimuth_at_altitude`), measured from the
+/// north through the east. Both are for the sun at
+/// `SUN_ALTITUDE_AT_RISE_AND_SET` (-0.833°, with the
+/// refraction and the semidiameter). `None` when the
+/// sun does not rise (or set) on the day.
+///
+/// * `date` - Date
+/// * `coord` - Observer's latitude and longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.52-53, p.97)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Coord;
+/// use sowngwala::sun::{rise_set_detailed, SunRiseSet};
+/// use sowngwala::time::decimal_hours_from_angle;
+///
+/// // Greenwich on the spring equinox of 2022,
+/// // when the sun rises near due east, and sets
+/// // near due west.
+/// let date = NaiveDate::from_ymd(2022, 3, 20);
+/// let coord = Coord { lat: 51.4769, lng: -0.0005 };
+///
+/// let detailed: SunRiseSet = rise_set_detailed(date, coord);
+/// let (_, rise_az) = detailed.rise.unwrap();
+/// let (_, set_az) = detailed.set.unwrap();
+///
+/// assert_approx_eq!(
+///     decimal_hours_from_angle(rise_az),
+///     90.0,
+///     1e-2
+/// );
+/// assert_approx_eq!(
+///     decimal_hours_from_angle(set_az),
+///     270.0,
+///     1e-2
+/// );
+/// ```
+pub fn rise_set_detailed(
+    date: NaiveDate,
+    coord: Coord,
+) -> SunRiseSet {
+    let rise_set: RiseSet =
+        rise_set_from_date(date, coord);
+
+    let azimuth = |time: NaiveTime, rising: bool| {
+        let coord_0: EquaCoord =
+            equatorial_position_of_the_sun_from_generic_datetime(
+                date.and_time(time),
+            );
+        rise_set_azimuth_at_altitude(
+            coord_0.dec,
+            coord.lat,
+            SUN_ALTITUDE_AT_RISE_AND_SET,
+        )
+        .map(|(rise, set)| {
+            (time, if rising { rise } else { set })
+        })
+    };
+
+    SunRiseSet {
+        rise: rise_set
+            .rise
+            .and_then(|time| azimuth(time, true)),
+        set: rise_set
+            .set
+            .and_then(|time| azimuth(time, false)),
+    }
+}
+
+/// Same as `rise_set_for_observer`, but returns the
+/// rise and the set in the local time of the given
+/// zone (hours east of UTC, see `fixed_from_utc`),
+/// with `date` being the local date. An event of the
+/// local date may fall on the previous (or the next)
+/// day in UT (say, a sunrise at 5am in Tokyo is at
+/// 8pm the day before in UT), so the events of the
+/// UT dates before and after are also looked at, and
+/// those on the local date are taken. `None` when
+/// the sun does not rise (or set) on the local date.
+///
+/// * `date` - Date (in local time)
+/// * `observer` - Observer
+/// * `zone` - Time zone (hours east of UTC)
+///
+/// Example:
+/// ```rust
+/// use chrono::{Datelike, Timelike};
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Observer;
+/// use sowngwala::sun::rise_set_local;
+///
+/// // Tokyo on the summer solstice of 2022, when the
+/// // sun rises at 4:25 and sets at 19:00 (JST).
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+/// let observer = Observer::at(35.68, 139.77);
+///
+/// let (rise, set) = rise_set_local(date, observer, 9);
+/// let rise = rise.unwrap();
+/// let set = set.unwrap();
+///
+/// // Minutes of the day
+/// let minutes = |h: u32, m: u32| (h * 60 + m) as i32;
+///
+/// assert_eq!(rise.day(), 21);
+/// assert!(
+///     (minutes(rise.hour(), rise.minute()) - minutes(4, 25))
+///         .abs()
+///         <= 2
+/// );
+/// assert_eq!(set.day(), 21);
+/// assert!(
+///     (minutes(set.hour(), set.minute()) - minutes(19, 0))
+///         .abs()
+///         <= 2
+/// );
+/// ```
+pub fn rise_set_local(
+    date: NaiveDate,
+    observer: Observer,
+    zone: i32,
+) -> (
+    Option<DateTime<FixedOffset>>,
+    Option<DateTime<FixedOffset>>,
+) {
+    let mut rise: Option<DateTime<FixedOffset>> =
+        None;
+    let mut set: Option<DateTime<FixedOffset>> = None;
+
+    let on_the_date = |ut_date: NaiveDate,
+                       t: Option<NaiveTime>|
+     -> Option<
+        DateTime<FixedOffset>,
+    > {
+        t.map(|t| {
+            fixed_from_utc(
+                utc_from_naive(ut_date.and_time(t)),
+                zone,
+            )
+        })
+        .filter(|local| local.date_naive() == date)
+    };
+
+    for days in -1..=1 {
+        let ut_date: NaiveDate =
+            date + Duration::days(days);
+        let rise_set: RiseSet =
+            rise_set_for_observer(ut_date, observer);
+
+        if rise.is_none() {
+            rise =
+                on_the_date(ut_date, rise_set.rise);
+        }
+        if set.is_none() {
+            set = on_the_date(ut_date, rise_set.set);
+        }
+    }
+
+    (rise, set)
+}
+
+/// A named moment (in UTC), such as "Sunrise", for
+/// calendars (say, an iCalendar `VEVENT` with
+/// `SUMMARY` and `DTSTART`).
+///
+/// * `summary` - Name of the moment
+/// * `start` - When it happens (UTC)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub summary: String,
+    pub start: DateTime<Utc>,
+}
+
+/// Given a date and `Observer`, returns the sunrise
+/// and the sunset (see `rise_set_for_observer`) as
+/// `Event` named "Sunrise" and "Sunset", sorted by
+/// the time. Events which do not happen on the day
+/// (such as during the polar day or night) are
+/// omitted, so the list may have less than 2 events.
+///
+/// * `date` - Date (in UT)
+/// * `observer` - Observer
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Observer;
+/// use sowngwala::sun::{rise_set_as_events, Event};
+///
+/// let date = NaiveDate::from_ymd(2022, 6, 21);
+///
+/// // Greenwich
+/// let events: Vec<Event> =
+///     rise_set_as_events(date, Observer::at(51.4769, -0.0005));
+/// assert_eq!(events.len(), 2);
+/// assert_eq!(events[0].summary, "Sunrise");
+/// assert_eq!(events[1].summary, "Sunset");
+///
+/// // The midnight sun in Tromsø
+/// let events: Vec<Event> =
+///     rise_set_as_events(date, Observer::at(69.65, 18.96));
+/// assert!(events.is_empty());
+/// ```
+pub fn rise_set_as_events(
+    date: NaiveDate,
+    observer: Observer,
+) -> Vec<Event> {
+    let rise_set: RiseSet =
+        rise_set_for_observer(date, observer);
+
+    let mut events: Vec<Event> = [
+        ("Sunrise", rise_set.rise),
+        ("Sunset", rise_set.set),
+    ]
+    .iter()
+    .filter_map(|(summary, t)| {
+        t.map(|t| Event {
+            summary: summary.to_string(),
+            start: utc_from_naive(date.and_time(t)),
+        })
+    })
+    .collect();
+
+    events.sort_by_key(|event| event.start);
+    events
+}
+
+/// See `rise_set_from_date` for the specs. `alt` is
+/// the altitude (in degrees) of the rise and the set.
+fn _rise_set_from_date_aux(
+    date: NaiveDate,
+    coord: Coord,
+    alt: f64,
+) -> RiseSet {
+    match (
+        rise_or_set_from_date(date, coord, alt, true),
+        rise_or_set_from_date(
+            date, coord, alt, false,
+        ),
+    ) {
+        (Ok(rise), Ok(set)) => {
+            RiseSet::new(Some(rise), Some(set))
+        }
+        (Ok(rise), Err(_)) => {
+            RiseSet::new(Some(rise), None)
+        }
+        (Err(_), Ok(set)) => {
+            RiseSet::new(None, Some(set))
+        }
+        (Err(status), Err(_)) => RiseSet {
+            rise: None,
+            set: None,
+            status,
+        },
+    }
+}
+
+/// See `rise_set_from_date` for the specs. Returns
+/// the status instead of the time when the sun stays
+/// above (or below) the horizon.
+fn rise_or_set_from_date(
+    date: NaiveDate,
+    coord: Coord,
+    alt: f64,
+    rising: bool,
+) -> Result<NaiveTime, RiseSetStatus> {
+    let lat: f64 = coord.lat.to_radians();
+    let alt: f64 = alt.to_radians();
+
+    // UT in decimal hours (may be out of 0-24 at first)
+    let mut ut: f64 = if rising { 6.0 } else { 18.0 };
+    ut -= coord.lng / 15.0;
+
+    for _ in 0..4 {
+        let dt: NaiveDateTime =
+            date.and_hms_opt(0, 0, 0).unwrap()
+                + Duration::nanoseconds(
+                    (ut * 3_600_000_000_000.0) as i64,
+                );
+        let coord_0: EquaCoord =
+            equatorial_position_of_the_sun_from_generic_datetime(
+                dt,
+            );
+        let asc: f64 =
+            decimal_hours_from_angle(coord_0.asc);
+        let dec: f64 =
+            decimal_hours_from_angle(coord_0.dec)
+                .to_radians();
+
+        let cos_h: f64 = (alt.sin()
+            - (lat.sin() * dec.sin()))
+            / (lat.cos() * dec.cos());
+
+        if cos_h >= 1.0 {
+            return Err(RiseSetStatus::NeverRises);
+        }
+        if cos_h <= -1.0 {
+            return Err(RiseSetStatus::Circumpolar);
+        }
+
+        let h: f64 = cos_h.acos().to_degrees() / 15.0;
+        let mut lst: f64 =
+            if rising { asc - h } else { asc + h };
+        lst -= 24.0 * (lst / 24.0).floor();
+
+        let (lng, dir) = coord.lng_with_direction();
+        let gst: NaiveTime = gst_from_lst(
+            date.and_time(
+                naive_time_from_decimal_hours(lst),
+            ),
+            lng,
+            dir,
+        );
+        let utc: NaiveTime =
+            utc_from_gst(date.and_time(gst));
+
+        ut = decimal_hours_from_naive_time(utc);
+    }
+
+    Ok(naive_time_from_decimal_hours(ut))
+}
+
+/// Given the date in GST, returns the EOT.
+/// (Peter Duffett-Smith, pp.98-99)
+#[allow(clippy::many_single_char_names)]
+pub fn equation_of_time_from_gst(
+    gst: NaiveDateTime,
+) -> (Angle, f64) {
+    let date: NaiveDate = gst.date();
+    let coord: EquaCoord =
+        equatorial_position_of_the_sun_from_generic_date(date);
+    let asc_0: Angle = coord.asc.into();
+    let asc_1: NaiveTime = asc_0.into();
+    let naivetime = NaiveDateTime::new(date, asc_1);
+    let utc: NaiveTime = utc_from_gst(naivetime);
+    let decimal = decimal_hours_from_naive_time(utc);
+    let e = 12.0 - decimal;
+    let mut angle_0 = angle_from_decimal_hours(e);
+    let day_excess = angle_0.calibrate();
+    (angle_0, day_excess)
+}
+
+/// Given a date, returns the equation of time (EOT)
+/// in minutes at 0h UT. The sign follows "apparent
+/// minus mean" solar time, so that it is positive when
+/// the sundial is ahead of the clock (the sun transits
+/// before the mean noon, as in early November), and
+/// negative when behind (as in mid-February). Computed
+/// directly from the sun's mean longitude (L) and the
+/// right ascension (α) as L - α (wrapped to ±12h).
+///
+/// * `date` - Datelike
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.98-99)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::equation_of_time_minutes_from_date;
+///
+/// let eot = |y, m, d| {
+///     equation_of_time_minutes_from_date(
+///         NaiveDate::from_ymd(y, m, d),
+///     )
+/// };
+///
+/// // Minimum in mid-February (about -14.2 min)
+/// assert!((eot(2022, 2, 11) + 14.2).abs() < 0.3);
+/// // Maximum in mid-May (about +3.7 min)
+/// assert!((eot(2022, 5, 14) - 3.7).abs() < 0.3);
+/// // Minimum in late July (about -6.5 min)
+/// assert!((eot(2022, 7, 26) + 6.5).abs() < 0.3);
+/// // Maximum in early November (about +16.4 min)
+/// assert!((eot(2022, 11, 3) - 16.4).abs() < 0.3);
+/// ```
+pub fn equation_of_time_minutes_from_date<T>(
+    date: T,
+) -> f64
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    let day_number =
+        day_number_from_generic_date(date) as f64;
+    let days: f64 = days_since_1990(date.year())
+        as f64
+        + day_number;
+
+    let (mean_anom, _v): (f64, f64) =
+        anomalies_of_the_sun(days);
+
+    // Mean longitude (L) in hours
+    let mean_lng: f64 = (mean_anom
+        + ECLIPTIC_LONGITUDE_OF_PERIGEE)
+        / 15.0;
+
+    let coord: EquaCoord =
+        equatorial_position_of_the_sun_from_generic_date(
+            date,
+        );
+    let asc: f64 =
+        decimal_hours_from_angle(coord.asc);
+
+    let mut eot: f64 = mean_lng - asc;
+    eot -= 24.0 * ((eot + 12.0) / 24.0).floor();
+
+    eot * 60.0
+}
+
+#[allow(clippy::many_single_char_names)]
+pub fn equation_of_time_from_utc(
+    utc: DateTime<Utc>,
+) -> (Angle, f64) {
+    equation_of_time_from_gst(utc.naive_utc())
+}
+
+/// Orientation of the sun's rotation axis (in
+/// degrees) as seen from the earth.
+///
+/// * `p` - Position angle of the rotation axis (P),
+///   eastward from the north point of the disk
+/// * `b0` - Heliographic latitude of the disk center (B0)
+/// * `l0` - Carrington longitude of the disk center (L0)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolarOrientation {
+    pub p: f64,
+    pub b0: f64,
+    pub l0: f64,
+}
+
+/// Given a date, returns the orientation angles of
+/// the sun (P, B0, and L0) at 0h UT, using the sun's
+/// geometric longitude (see
+/// `ecliptic_position_of_the_sun_from_generic_date`)
+/// and the mean obliquity of the ecliptic. Mind that
+/// L0 decreases some 13.2° a day, so use
+/// `heliographic_from_disk_position` for a specific
+/// time of the day.
+///
+/// * `date` - Date (in UT)
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", pp.190-191)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::{
+///     solar_orientation_from_date, SolarOrientation,
+/// };
+///
+/// // Meeus, Example 29.a gives P = 26.27°,
+/// // B0 = 5.99°, and L0 = 238.63°.
+/// let orientation: SolarOrientation =
+///     solar_orientation_from_date(
+///         NaiveDate::from_ymd(1992, 10, 13),
+///     );
+/// assert!((orientation.p - 26.27).abs() < 0.1);
+/// assert!((orientation.b0 - 5.99).abs() < 0.1);
+/// assert!((orientation.l0 - 238.63).abs() < 0.1);
+/// ```
+pub fn solar_orientation_from_date<T>(
+    date: T,
+) -> SolarOrientation
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    _solar_orientation_aux(
+        julian_day_from_generic_date(date),
+        ecliptic_position_of_the_sun_from_generic_date(
+            date,
+        )
+        .lng,
+        mean_obliquity_of_the_epliptic(date),
+    )
+}
+
+// Given Julian Day, the sun's ecliptic longitude
+// (λ), and the obliquity of the ecliptic (ε) in
+// degrees, returns `SolarOrientation`.
+fn _solar_orientation_aux(
+    jd: f64,
+    lng: f64,
+    obliquity: f64,
+) -> SolarOrientation {
+    // Inclination of the solar equator (I)
+    let inc: f64 = 7.25_f64.to_radians();
+    // Longitude of the ascending node (K)
+    let node: f64 = 73.666_7
+        + 1.395_833_3 * (jd - 2_396_758.0) / 36_525.0;
+    // Rotation of the sun since the Carrington epoch
+    let theta: f64 =
+        (jd - 2_398_220.0) * 360.0 / 25.38;
+
+    let lng_rad: f64 = lng.to_radians();
+    let diff: f64 = (lng - node).to_radians();
+
+    let x: f64 = (-lng_rad.cos()
+        * obliquity.to_radians().tan())
+    .atan();
+    let y: f64 = (-diff.cos() * inc.tan()).atan();
+
+    let p: f64 = (x + y).to_degrees();
+    let b0: f64 = (diff.sin() * inc.sin()).asin();
+    let eta: f64 =
+        (-diff.sin() * inc.cos()).atan2(-diff.cos());
+    let l0: f64 =
+        (eta.to_degrees() - theta).rem_euclid(360.0);
+
+    SolarOrientation {
+        p,
+        b0: b0.to_degrees(),
+        l0,
+    }
+}
+
+/// Given a datetime (in UT) and a position on the
+/// solar disk as fractions of the radius, returns
+/// the heliographic latitude (B) and longitude (L)
+/// of the position (say, of a sunspot) in degrees.
+/// The disk is as seen in the sky with the celestial
+/// north up: `x_frac` is positive toward the west
+/// limb (to the right), and `y_frac` is positive
+/// toward the north limb (up). The position is
+/// projected onto the sphere orthographically, so it
+/// must be within the disk (x² + y² ≤ 1), or `NaN`
+/// is returned. L is the Carrington longitude which
+/// increases toward the west limb.
+///
+/// * `dt` - Datetime (in UT)
+/// * `x_frac` - Westward position (-1.0 to 1.0)
+/// * `y_frac` - Northward position (-1.0 to 1.0)
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", pp.190-191)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::sun::heliographic_from_disk_position;
+///
+/// let dt = NaiveDate::from_ymd(1992, 10, 13).and_hms(0, 0, 0);
+///
+/// // The disk center is at (B0, L0).
+/// let (lat, lng) = heliographic_from_disk_position(dt, 0.0, 0.0);
+/// assert!((lat - 5.99).abs() < 0.1);
+/// assert!((lng - 238.63).abs() < 0.1);
+///
+/// // Outside of the disk
+/// let (lat, _lng) = heliographic_from_disk_position(dt, 1.0, 1.0);
+/// assert!(lat.is_nan());
+/// ```
+pub fn heliographic_from_disk_position<T>(
+    dt: T,
+    x_frac: f64,
+    y_frac: f64,
+) -> (f64, f64)
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let jd: f64 =
+        julian_day_from_generic_datetime(dt);
+    let lng: f64 =
+        ecliptic_position_of_the_sun_from_generic_datetime(
+            dt,
+        )
+        .lng;
+    let obliquity: f64 =
+        mean_obliquity_of_the_epliptic(dt);
+
+    let orientation: SolarOrientation =
+        _solar_orientation_aux(jd, lng, obliquity);
+
+    let p: f64 = orientation.p.to_radians();
+    let b0: f64 = orientation.b0.to_radians();
+
+    // Toward the observer
+    let z: f64 =
+        (1.0 - x_frac * x_frac - y_frac * y_frac)
+            .sqrt();
+
+    // Rotated by P, so that y is along the projected
+    // rotation axis, and x toward the solar west.
+    let x: f64 = x_frac * p.cos() + y_frac * p.sin();
+    let y: f64 = -x_frac * p.sin() + y_frac * p.cos();
+
+    let lat: f64 =
+        (y * b0.cos() + z * b0.sin()).asin();
+    let lng: f64 = x
+        .atan2(-y * b0.sin() + z * b0.cos())
+        .to_degrees();
+
+    (
+        lat.to_degrees(),
+        (orientation.l0 + lng).rem_euclid(360.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::naive::NaiveDate;
+
+    // Plain Newton iteration run to the machine
+    // precision, to check `solve_kepler` against.
+    fn reference_kepler(
+        mean_anom: f64,
+        e: f64,
+    ) -> f64 {
+        let mut ecc: f64 = std::f64::consts::PI;
+        for _ in 0..100 {
+            ecc -= (ecc - e * ecc.sin() - mean_anom)
+                / (1.0 - e * ecc.cos());
+        }
+        ecc
+    }
+
+    #[test]
+    fn sunrise_for_observers_in_different_atmospheres(
+    ) {
+        let date = NaiveDate::from_ymd(2022, 3, 21);
+        let observer = Observer::at(35.68, 139.77);
+
+        let standard =
+            rise_set_for_observer(date, observer);
+        let default =
+            rise_set_from_date(date, observer.coord);
+        assert_eq!(standard.rise, default.rise);
+        assert_eq!(standard.set, default.set);
+
+        // Cold and dense air raises the sun more, so it
+        // rises earlier, and sets later.
+        let cold = rise_set_for_observer(
+            date,
+            Observer {
+                pressure_mbar: 1040.0,
+                temp_c: -20.0,
+                ..observer
+            },
+        );
+        assert!(cold.rise < standard.rise);
+        assert!(cold.set > standard.set);
+
+        // Thin air on a mountain does the opposite.
+        let thin = rise_set_for_observer(
+            date,
+            Observer {
+                pressure_mbar: 600.0,
+                ..observer
+            },
+        );
+        assert!(thin.rise > standard.rise);
+        assert!(thin.set < standard.set);
+
+        // And the height brings it back.
+        let summit = rise_set_for_observer(
+            date,
+            Observer {
+                height_m: 4_000.0,
+                pressure_mbar: 600.0,
+                ..observer
+            },
+        );
+        assert!(summit.rise < standard.rise);
+    }
+
+    #[test]
+    fn sunrise_is_earlier_for_higher_observers() {
+        let date = NaiveDate::from_ymd(2022, 3, 21);
+        let coord = Coord {
+            lat: 35.68,
+            lng: 139.77,
+        };
+
+        let mut last =
+            rise_set_from_date(date, coord);
+        assert_eq!(
+            rise_set_from_date_with_height(
+                date, coord, 0.0
+            )
+            .rise,
+            last.rise
+        );
+
+        for &height in
+            [10.0, 100.0, 1_000.0, 10_000.0].iter()
+        {
+            let rise_set =
+                rise_set_from_date_with_height(
+                    date, coord, height,
+                );
+            assert!(rise_set.rise < last.rise);
+            assert!(rise_set.set > last.set);
+            last = rise_set;
+        }
+    }
+
+    #[test]
+    fn rise_set_status_of_the_polar_day_and_night() {
+        let coord = Coord {
+            lat: 69.65,
+            lng: 18.96,
+        };
+
+        let summer = rise_set_from_date(
+            NaiveDate::from_ymd(2022, 6, 21),
+            coord,
+        );
+        assert_eq!(
+            summer.status,
+            RiseSetStatus::Circumpolar
+        );
+
+        let winter = rise_set_from_date(
+            NaiveDate::from_ymd(2022, 12, 21),
+            coord,
+        );
+        assert_eq!(
+            winter.status,
+            RiseSetStatus::NeverRises
+        );
+        assert!(winter.rise.is_none());
+        assert!(winter.set.is_none());
+
+        let spring = rise_set_from_date(
+            NaiveDate::from_ymd(2022, 3, 21),
+            coord,
+        );
+        assert_eq!(
+            spring.status,
+            RiseSetStatus::Normal
+        );
+        assert!(spring.rise.is_some());
+        assert!(spring.set.is_some());
+    }
+
+    #[test]
+    fn positions_over_range_match_the_scalar_function(
+    ) {
+        let start = NaiveDate::from_ymd(2021, 12, 15);
+        let end = NaiveDate::from_ymd(2022, 1, 15);
+        let positions =
+            positions_over_range(start, end, 1);
+        assert_eq!(positions.len(), 32);
+
+        for (date, coord) in positions {
+            let expected =
+                equatorial_position_of_the_sun_from_generic_date(
+                    date,
+                );
+            assert_eq!(
+                decimal_hours_from_angle(coord.asc),
+                decimal_hours_from_angle(
+                    expected.asc
+                )
+            );
+            assert_eq!(
+                decimal_hours_from_angle(coord.dec),
+                decimal_hours_from_angle(
+                    expected.dec
+                )
+            );
+        }
+
+        // Across the years, and with longer steps
+        let start = NaiveDate::from_ymd(1987, 3, 1);
+        let end = NaiveDate::from_ymd(1993, 3, 1);
+        for step in [5, 13, 30] {
+            for (date, coord) in
+                positions_over_range(start, end, step)
+            {
+                let expected =
+                    equatorial_position_of_the_sun_from_generic_date(
+                        date,
+                    );
+                assert_eq!(
+                    (
+                        coord.asc.hour(),
+                        coord.asc.minute(),
+                        coord.asc.second()
+                    ),
+                    (
+                        expected.asc.hour(),
+                        expected.asc.minute(),
+                        expected.asc.second()
+                    ),
+                    "{}",
+                    date
+                );
+                assert_eq!(
+                    (
+                        coord.dec.hour(),
+                        coord.dec.minute(),
+                        coord.dec.second()
+                    ),
+                    (
+                        expected.dec.hour(),
+                        expected.dec.minute(),
+                        expected.dec.second()
+                    ),
+                    "{}",
+                    date
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solve_kepler_with_arbitrary_eccentricity() {
+        let mean_anoms =
+            [0.0, 0.1, 1.0, 2.5, 3.1, 4.0, 6.0];
+
+        for &m in mean_anoms.iter() {
+            // A circular orbit needs no solving.
+            assert_eq!(
+                solve_kepler(m, 0.0).unwrap(),
+                m
+            );
+
+            for &e in [0.2, 0.9].iter() {
+                let ecc: f64 =
+                    solve_kepler(m, e).unwrap();
+                let ecc_0: f64 =
+                    reference_kepler(m, e);
+                assert!(
+                    (ecc - ecc_0).abs() < 1e-5,
+                    "M={} e={}: {} vs {}",
+                    m,
+                    e,
+                    ecc,
+                    ecc_0
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solve_kepler_gives_up_near_parabolic_orbit() {
+        // Close to E = 0, the derivative (1 - e cos E)
+        // vanishes for e near 1.0, and Newton's
+        // method only crawls toward the root.
+        let result = solve_kepler_with(
+            1e-9,
+            0.999_999_999,
+            Some(10),
+            Some(1e-15),
+        );
+
+        match result {
+            Err(KeplerError::DidNotConverge {
+                iterations,
+                residual,
+            }) => {
+                assert_eq!(iterations, 10);
+                assert!(residual.abs() > 1e-15);
+            }
+            _ => panic!(
+                "Expected DidNotConverge: {:?}",
+                result
+            ),
+        }
+    }
+
+    #[test]
+    fn see_if_you_can_find_monthly_zhi() {
+        // 立夏 (Li-xia) for 2022 starts on 5/5.
+        // So, it should result in `3` for 5/6.
+        let date: NaiveDate =
+            NaiveDate::from_ymd(2022, 5, 6);
+        assert_eq!(
+            zodiacal_branch_from_generic_date(date),
+            3
+        );
+    }
+
+    #[test]
+    fn zodiacal_branch_changes_at_the_solar_terms() {
+        // "節" solar terms for 2022 (in UT). Checks
+        // the day before and the day after.
+        let terms = [
+            ((1, 5), 11),  // 小寒 (xiaohan)
+            ((2, 3), 0),   // 立春 (lichun)
+            ((3, 5), 1),   // 啓蟄 (jingzhe)
+            ((4, 4), 2),   // 清明 (qingming)
+            ((5, 5), 3),   // 立夏 (lixia)
+            ((6, 5), 4),   // 芒種 (mangzhong)
+            ((7, 7), 5),   // 小暑 (xiaoshu)
+            ((8, 7), 6),   // 立秋 (liqiu)
+            ((9, 7), 7),   // 白露 (bailu)
+            ((10, 8), 8),  // 寒露 (hanlu)
+            ((11, 7), 9),  // 立冬 (lidong)
+            ((12, 7), 10), // 大雪 (daxue)
+        ];
+        for &((m, d), branch) in terms.iter() {
+            let date =
+                NaiveDate::from_ymd(2022, m, d);
+            let before = date - Duration::days(1);
+            let after = date + Duration::days(1);
+            assert_eq!(
+                zodiacal_branch_from_generic_date(
+                    before
+                ),
+                (branch + 11) % 12,
+                "{}",
+                before
+            );
+            assert_eq!(
+                zodiacal_branch_from_generic_date(
+                    after
+                ),
+                branch,
+                "{}",
+                after
+            );
+        }
+    }
+
+    #[test]
+    fn zodiac_sign_changes_at_the_boundaries() {
+        use ZodiacSign::*;
+
+        // Ingresses for 2022 (in UT)
+        let ingresses = [
+            ((1, 20), Capricorn, Aquarius),
+            ((2, 18), Aquarius, Pisces),
+            ((3, 20), Pisces, Aries),
+            ((4, 20), Aries, Taurus),
+            ((5, 21), Taurus, Gemini),
+            ((6, 21), Gemini, Cancer),
+            ((7, 22), Cancer, Leo),
+            ((8, 23), Leo, Virgo),
+            ((9, 23), Virgo, Libra),
+            ((10, 23), Libra, Scorpio),
+            ((11, 22), Scorpio, Sagittarius),
+            ((12, 21), Sagittarius, Capricorn),
+        ];
+        for &((m, d), from, to) in ingresses.iter() {
+            let date =
+                NaiveDate::from_ymd(2022, m, d);
+            assert_eq!(
+                zodiac_sign_from_generic_date(
+                    date - Duration::days(1)
+                ),
+                from
+            );
+            assert_eq!(
+                zodiac_sign_from_generic_date(
+                    date + Duration::days(1)
+                ),
+                to
+            );
+        }
+
+        assert_eq!(
+            ZodiacSign::from_ecliptic_longitude(
+                359.99
+            ),
+            Pisces
+        );
+        assert_eq!(
+            ZodiacSign::from_ecliptic_longitude(
+                360.0
+            ),
+            Aries
+        );
+    }
+
+    #[test]
+    fn rise_set_detailed_azimuth_is_where_the_sun_is()
+    {
+        // Tokyo on the summer solstice, when the sun
+        // rises far north of due east.
+        let date = NaiveDate::from_ymd(2022, 6, 21);
+        let coord = Coord {
+            lat: 35.68,
+            lng: 139.65,
+        };
+        let detailed = rise_set_detailed(date, coord);
+
+        for &(time, az) in [
+            detailed.rise.unwrap(),
+            detailed.set.unwrap(),
+        ]
+        .iter()
+        {
+            let utc: DateTime<Utc> =
+                utc_from_naive(date.and_time(time));
+            let horiz: HorizCoord =
+                horizontal_position_from_utc(
+                    utc, coord,
+                );
+
+            assert!(
+                (decimal_hours_from_angle(horiz.alt)
+                    - SUN_ALTITUDE_AT_RISE_AND_SET)
+                    .abs()
+                    < 0.05
+            );
+            assert!(
+                (decimal_hours_from_angle(horiz.azi)
+                    - decimal_hours_from_angle(az))
+                .abs()
+                    < 0.05,
+                "{:?}",
+                time
+            );
+        }
+    }
+
+    #[test]
+    fn longitude_crossing_finds_the_spring_equinox() {
+        // The spring equinox of 2022 was on March 20
+        // at 15:33 (UT).
+        let expected: NaiveDateTime =
+            NaiveDate::from_ymd(2022, 3, 20)
+                .and_hms(15, 33, 0);
+
+        // From either side of 0°
+        for &(month, day) in [(3, 1), (3, 25)].iter()
+        {
+            let dt: NaiveDateTime =
+                longitude_crossing(
+                    0.0,
+                    NaiveDate::from_ymd(
+                        2022, month, day,
+                    ),
+                );
+            assert!(
+                (dt - expected).num_minutes().abs()
+                    <= 30
+            );
+        }
+
+        // From the winter, the nearest is the next one.
+        let dt: NaiveDateTime = longitude_crossing(
+            0.0,
+            NaiveDate::from_ymd(2021, 12, 31),
+        );
+        assert!(
+            (dt - expected).num_minutes().abs() <= 30
+        );
+
+        // The other equinox and solstices of 2022,
+        // and the spring equinoxes of 2000 and 1990.
+        // The sun's longitude is off by up to a few
+        // hundredths of a degree (the winter solstice
+        // of 2022 comes 46 minutes early), but it is
+        // the target at the crossing.
+        for &(target, y, m, d, h, mi) in [
+            (90.0, 2022, 6, 21, 9, 14),
+            (180.0, 2022, 9, 23, 1, 4),
+            (270.0, 2022, 12, 21, 21, 48),
+            (0.0, 2000, 3, 20, 7, 35),
+            (0.0, 1990, 3, 20, 21, 19),
+        ]
+        .iter()
+        {
+            let crossing: NaiveDateTime =
+                longitude_crossing(
+                    target,
+                    NaiveDate::from_ymd(y, m, 1),
+                );
+            let expected: NaiveDateTime =
+                NaiveDate::from_ymd(y, m, d)
+                    .and_hms(h, mi, 0);
+            assert!(
+                (crossing - expected)
+                    .num_minutes()
+                    .abs()
+                    <= 60,
+                "{}",
+                crossing
+            );
+
+            let lng: f64 =
+                ecliptic_position_of_the_sun_from_generic_datetime(
+                    crossing,
+                )
+                .lng;
+            let mut diff: f64 = lng - target;
+            diff -= 360.0 * (diff / 360.0).round();
+            assert!(diff.abs() < 1e-5, "{}", lng);
+        }
+
+        // 360° is the same as 0°.
+        let dt_360: NaiveDateTime =
+            longitude_crossing(
+                360.0,
+                NaiveDate::from_ymd(2022, 3, 1),
+            );
+        assert!(
+            (dt_360 - dt).num_seconds().abs() <= 1
+        );
+    }
+
+    #[test]
+    fn fixed_offset_uses_the_utc_day() {
+        use crate::time::build_fixed;
+
+        // 2022-01-01 05:00 (+09:00) is still
+        // 2021-12-31 20:00 in UTC.
+        let fixed: DateTime<FixedOffset> =
+            build_fixed(2022, 1, 1, 5, 0, 0, 0, 9);
+
+        let lng: f64 =
+            ecliptic_position_of_the_sun_from_fixed(
+                fixed,
+            )
+            .lng;
+        let lng_utc: f64 =
+            ecliptic_position_of_the_sun_from_generic_datetime(
+                NaiveDate::from_ymd(2021, 12, 31)
+                    .and_hms(20, 0, 0),
+            )
+            .lng;
+        let lng_local: f64 =
+            ecliptic_position_of_the_sun_from_generic_datetime(
+                fixed.naive_local(),
+            )
+            .lng;
+
+        assert_eq!(lng, lng_utc);
+        // 9 hours apart (about 0.38°)
+        assert!(
+            (lng_local - lng - 0.38).abs() < 0.01
+        );
+
+        let coord: EquaCoord =
+            equatorial_position_of_the_sun_from_fixed(
+                fixed,
+            );
+        let coord_utc: EquaCoord =
+            equatorial_position_of_the_sun_from_generic_datetime(
+                NaiveDate::from_ymd(2021, 12, 31)
+                    .and_hms(20, 0, 0),
+            );
+        assert_eq!(
+            decimal_hours_from_angle(coord.dec),
+            decimal_hours_from_angle(coord_utc.dec)
+        );
+    }
+
+    #[test]
+    fn try_functions_return_errors_instead_of_panicking(
+    ) {
+        for &v in [f64::NAN, f64::INFINITY].iter() {
+            assert!(matches!(
+                try_find_kepler(v),
+                Err(SowngwalaError::NotFinite(_))
+            ));
+            assert!(find_kepler(v).is_nan());
+        }
+
+        let start = NaiveDate::from_ymd(2021, 1, 1);
+        let end = NaiveDate::from_ymd(2021, 1, 31);
+        assert_eq!(
+            try_positions_over_range(start, end, 0)
+                .err(),
+            Some(SowngwalaError::ZeroStep)
+        );
+        assert!(positions_over_range(start, end, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn heliographic_at_disk_center_is_b0_and_l0() {
+        for &(y, m, d) in [
+            (1992, 10, 13),
+            (2022, 3, 21),
+            (2022, 9, 1),
+        ]
+        .iter()
+        {
+            let dt = NaiveDate::from_ymd(y, m, d)
+                .and_hms(6, 0, 0);
+            let SolarOrientation { b0, l0, .. } =
+                _solar_orientation_aux(
+                julian_day_from_generic_datetime(dt),
+                ecliptic_position_of_the_sun_from_generic_datetime(
+                    dt,
+                )
+                .lng,
+                mean_obliquity_of_the_epliptic(dt),
+            );
+
+            let (lat, lng) =
+                heliographic_from_disk_position(
+                    dt, 0.0, 0.0,
+                );
+            assert!((lat - b0).abs() < 1e-9);
+            assert!((lng - l0).abs() < 1e-9);
+
+            // Westward on the disk is the larger
+            // Carrington longitude.
+            let (_lat, west) =
+                heliographic_from_disk_position(
+                    dt, 0.5, 0.0,
+                );
+            let diff: f64 =
+                (west - l0).rem_euclid(360.0);
+            assert!(diff > 25.0 && diff < 35.0);
+        }
+    }
+
+    #[test]
+    fn solar_orientation_matches_the_disk_center_at_0h(
+    ) {
+        let date = NaiveDate::from_ymd(2022, 6, 21);
+        let orientation: SolarOrientation =
+            solar_orientation_from_date(date);
+        let (lat, lng) =
+            heliographic_from_disk_position(
+                date.and_hms(0, 0, 0),
+                0.0,
+                0.0,
+            );
+        assert!((orientation.b0 - lat).abs() < 1e-9);
+        assert!((orientation.l0 - lng).abs() < 1e-9);
+
+        // The axis tilts the most toward us in early
+        // September (B0 ≈ +7.25°), and P stays within
+        // ±26.3°.
+        let sep = solar_orientation_from_date(
+            NaiveDate::from_ymd(2022, 9, 8),
+        );
+        assert!((sep.b0 - 7.25).abs() < 0.1);
+        assert!(orientation.p.abs() < 26.4);
+    }
+
+    #[test]
+    fn local_sunrise_on_the_previous_utc_day() {
+        let date = NaiveDate::from_ymd(2022, 6, 21);
+        let observer = Observer::at(35.68, 139.77);
+
+        let (rise, set) =
+            rise_set_local(date, observer, 9);
+        let rise = rise.unwrap();
+        let set = set.unwrap();
+
+        // The sunrise is on June 20th in UT.
+        let rise_utc = utc_from_fixed(rise);
+        assert_eq!(rise_utc.date_naive().day(), 20);
+        assert_eq!(
+            Some(rise_utc.time()),
+            rise_set_for_observer(
+                NaiveDate::from_ymd(2022, 6, 20),
+                observer
+            )
+            .rise
+        );
+
+        // Both on June 21st in JST
+        assert_eq!(rise.date_naive(), date);
+        assert_eq!(set.date_naive(), date);
+        assert!(rise < set);
+
+        // Far west of UTC, the sunset is on the next
+        // day in UT.
+        let (_rise, set) = rise_set_local(
+            date,
+            Observer::at(21.31, -157.86),
+            -10,
+        );
+        let set = set.unwrap();
+        assert_eq!(set.date_naive(), date);
+        assert_eq!(
+            utc_from_fixed(set).date_naive().day(),
+            22
+        );
+    }
+
+    #[test]
+    fn rise_set_events_on_a_normal_and_a_polar_day() {
+        let date = NaiveDate::from_ymd(2022, 6, 21);
+
+        // In Tokyo, the sunrise is later than the
+        // sunset in UT, but the events are in order.
+        let observer = Observer::at(35.68, 139.77);
+        let events =
+            rise_set_as_events(date, observer);
+        assert_eq!(events.len(), 2);
+        assert!(events[0].start < events[1].start);
+        assert_eq!(events[0].summary, "Sunset");
+        assert_eq!(events[1].summary, "Sunrise");
+
+        let rise_set =
+            rise_set_for_observer(date, observer);
+        assert_eq!(
+            Some(events[1].start.time()),
+            rise_set.rise
+        );
+
+        // No sunset during the midnight sun
+        let events = rise_set_as_events(
+            date,
+            Observer::at(69.65, 18.96),
+        );
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| e.summary == "Sunset")
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn kepler_gives_up_on_nan_without_recursing() {
+        // A huge cap must not blow the stack.
+        match solve_kepler_with(
+            f64::NAN,
+            0.5,
+            Some(10_000_000),
+            None,
+        ) {
+            Err(KeplerError::DidNotConverge {
+                residual,
+                ..
+            }) => assert!(residual.is_nan()),
+            other => panic!("{:?}", other),
+        }
+        assert!(find_kepler(f64::NAN).is_nan());
+
+        // Converges as usual with a huge cap.
+        assert!(solve_kepler_with(
+            3.5,
+            0.2,
+            Some(u32::MAX),
+            Some(1e-12)
+        )
+        .is_ok());
     }
 }
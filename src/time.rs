@@ -1,9 +1,11 @@
 use chrono::naive::{
     NaiveDate, NaiveDateTime, NaiveTime,
 };
-use chrono::offset::{FixedOffset, TimeZone, Utc};
+use chrono::offset::{
+    FixedOffset, LocalResult, TimeZone, Utc,
+};
 use chrono::{
-    DateTime, Datelike, Duration, Timelike,
+    DateTime, Datelike, Duration, Timelike, Weekday,
 };
 use core::ops::Add;
 
@@ -11,7 +13,13 @@ use crate::constants::{
     J2000, NUM_OF_DAYS_IN_A_YEAR,
 };
 use crate::coords::{Angle, Direction};
-use crate::sun::equation_of_time_from_utc;
+use crate::delta_t::delta_t_from_generic_date;
+use crate::error::SowngwalaError;
+use crate::sun::{
+    equation_of_time_from_utc,
+    equation_of_time_minutes_from_date,
+};
+use crate::units::DecimalDegrees;
 use crate::utils::overflow;
 
 /// A handy tool to build `DateTime<FixedOffset>`.
@@ -114,9 +122,6 @@ pub fn naive_date_from_generic_datetime<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     NaiveDate::from_ymd(
         dt.year(),
@@ -131,7 +136,6 @@ pub fn naive_time_from_generic_datetime<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::fmt::Display,
 {
     NaiveTime::from_hms_nano(
         dt.hour(),
@@ -156,9 +160,6 @@ pub fn decimal_year_from_generic_date<T>(
 ) -> f64
 where
     T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     (date.year() as f64)
         + (date.month() as f64 - 0.5) / 12.0
@@ -229,9 +230,6 @@ pub fn decimal_hours_from_naive_time(
 pub fn decimal_hours_from_generic_time<T>(t: T) -> f64
 where
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     let hour = t.hour() as f64;
     let min = t.minute() as f64;
@@ -252,7 +250,51 @@ where
     }
 }
 
-pub fn decimal_hours_from_angle(angle: Angle) -> f64 {
+/// Converts `Angle` into Decimal Hours (or degrees).
+/// The sign is taken from `Angle::sign`, and the
+/// magnitudes of the components are summed up.
+///
+/// Contract:
+/// - The sign of the whole angle is that of its first
+///   nonzero component (hour, then minute, then
+///   second). This is where `angle_from_decimal_hours`
+///   (and `Angle::with_sign`) puts the sign, so the
+///   two are inverses of each other.
+/// - The signs of the components after the first
+///   nonzero one are ignored. `-8° 2' 42"` is
+///   `-8.045`, whether built as `Angle::new(-8, 2, 42.0)`
+///   or `Angle::new(-8, -2, -42.0)`, and never
+///   `-8 + 2/60 + 42/3600`.
+/// - A zero angle (including `-0.0` seconds) is `0.0`.
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::Angle;
+/// use sowngwala::time::{
+///     angle_from_decimal_hours,
+///     decimal_hours_from_angle,
+/// };
+///
+/// // -0°0'30"
+/// let angle = Angle::with_sign(-1, 0, 0, 30.0);
+/// let dec: f64 = decimal_hours_from_angle(angle);
+/// assert_approx_eq!(dec, -30.0 / 3600.0, 1e-12);
+///
+/// let angle: Angle = angle_from_decimal_hours(dec);
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), 0);
+/// assert_approx_eq!(angle.second(), -30.0, 1e-9);
+///
+/// // Declination of -8° 2' 42"
+/// let dec: f64 =
+///     decimal_hours_from_angle(Angle::new(-8, 2, 42.0));
+/// assert_approx_eq!(dec, -8.045, 1e-12);
+/// ```
+pub fn decimal_hours_from_angle<T: Into<Angle>>(
+    angle: T,
+) -> f64 {
+    let angle: Angle = angle.into();
     let hour = angle.hour().abs() as f64;
     let min = angle.minute().abs() as f64;
 
@@ -261,10 +303,7 @@ pub fn decimal_hours_from_angle(angle: Angle) -> f64 {
     let dec: f64 =
         hour + ((min + (sec / 60.0)) / 60.0);
 
-    if angle.hour() < 0
-        || angle.minute() < 0
-        || angle.second() < 0.0
-    {
+    if angle.sign() < 0 {
         -dec
     } else {
         dec
@@ -278,14 +317,12 @@ pub fn decimal_days_from_generic_datetime<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
+    let day = dt.day() as f64;
     let t: NaiveTime =
         naive_time_from_generic_datetime(dt);
     let dec: f64 = decimal_hours_from_generic_time(t);
-    (dt.day() as f64) + (dec / 24.0)
+    day + (dec / 24.0)
 }
 
 // Carry-over utils (1)
@@ -301,10 +338,27 @@ pub fn hms_from_decimal_hours(
 }
 
 // Carry-over utils (2)
+/// Splits seconds into whole seconds and nanoseconds,
+/// rounding to the nearest nanosecond (truncating
+/// turns 5.23 into 5.229999999). Stays below 60
+/// seconds, since it cannot carry into minutes.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::nano_from_second;
+///
+/// assert_eq!(nano_from_second(5.23), (5, 230_000_000));
+/// assert_eq!(
+///     nano_from_second(59.999_999_999_9),
+///     (59, 999_999_999)
+/// );
+/// ```
 pub fn nano_from_second(sec_0: f64) -> (u32, u32) {
-    let sec = sec_0.floor() as u32;
-    let base_0: f64 = sec_0.fract() * 1_000_000_000.0;
-    let nano = base_0.floor() as u32;
+    let nanos: u64 = ((sec_0 * 1_000_000_000.0)
+        .round() as u64)
+        .min(59_999_999_999);
+    let sec = (nanos / 1_000_000_000) as u32;
+    let nano = (nanos % 1_000_000_000) as u32;
     (sec, nano)
 }
 
@@ -335,26 +389,131 @@ pub fn naive_time_from_decimal_hours(
     angle.to_naive_time()
 }
 
-#[allow(clippy::many_single_char_names)]
-pub fn angle_from_decimal_hours(dec: f64) -> Angle {
-    let sign: i16 = if dec < 0.0 { -1 } else { 1 };
+/// Converts Decimal Hours into `NaiveTime` rounding to
+/// the nearest nanosecond, and returns the residual
+/// (in decimal hours) which `NaiveTime` cannot hold.
+/// `naive_time_from_decimal_hours` goes through `Angle`
+/// and drops the nanosecond tail, which accumulates
+/// when converted back and forth (say, between UT and
+/// GST). Passing the residual to
+/// `decimal_hours_preserving` recovers the decimal.
+///
+/// * `dec` - Decimal Hours (0.0 to 24.0)
+///
+/// Example:
+/// ```rust
+/// use chrono::Timelike;
+/// use chrono::naive::NaiveTime;
+/// use sowngwala::time::{
+///     decimal_hours_preserving,
+///     naive_time_with_residual,
+/// };
+///
+/// // 18h 31m 27s plus 0.7 nanoseconds
+/// let dec: f64 = 18.524_166_666_666_667
+///     + 0.7e-9 / 3600.0;
+/// let (t, residual): (NaiveTime, f64) =
+///     naive_time_with_residual(dec);
+///
+/// assert_eq!(t.second(), 27);
+/// assert_eq!(t.nanosecond(), 1);
+/// assert!(residual < 0.0);
+/// assert_eq!(decimal_hours_preserving(t, residual), dec);
+/// ```
+pub fn naive_time_with_residual(
+    dec: f64,
+) -> (NaiveTime, f64) {
+    let nanos_per_hour: f64 = 3_600_000_000_000.0;
+    let nanos_per_day: i64 = 86_400_000_000_000;
 
-    let (h, m, s): (i32, i32, f64) =
-        hms_from_decimal_hours(dec.abs());
+    let nanos: i64 = ((dec * nanos_per_hour).round()
+        as i64)
+        .rem_euclid(nanos_per_day);
 
-    let mut hour: i32 = h;
-    let mut min: i32 = m;
-    let mut sec: f64 = s;
+    let secs: u32 = (nanos / 1_000_000_000) as u32;
+    let t = NaiveTime::from_hms_nano_opt(
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60,
+        (nanos % 1_000_000_000) as u32,
+    )
+    .unwrap();
 
-    if hour != 0 {
-        hour *= sign as i32;
-    } else if min != 0 {
-        min *= sign as i32;
-    } else {
-        sec *= sign as f64;
+    let mut residual: f64 =
+        dec - decimal_hours_from_naive_time(t);
+
+    // When rounded up to the next midnight
+    if residual > 12.0 {
+        residual -= 24.0;
     }
 
-    Angle::new(hour, min, sec)
+    (t, residual)
+}
+
+/// Given `NaiveTime` and the residual returned from
+/// `naive_time_with_residual`, returns Decimal Hours.
+pub fn decimal_hours_preserving(
+    t: NaiveTime,
+    residual: f64,
+) -> f64 {
+    decimal_hours_from_naive_time(t) + residual
+}
+
+/// Converts Decimal Hours (or degrees) into `Angle`.
+///
+/// The sign of the whole quantity is stored once
+/// (`Angle::sign`), and the hour, the minute,
+/// and the second are kept as the magnitudes (see
+/// `Angle::with_sign`). The accessors show the sign
+/// on the first nonzero component. Say, `-8.045`
+/// reads `-8h 2m 42s`, and `-0.1` reads `0h -6m 0s`.
+/// `decimal_hours_from_angle` reads the sign back
+/// (`Angle::sign`), and the two functions round trip
+/// for any value.
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::coords::Angle;
+/// use sowngwala::time::{
+///     angle_from_decimal_hours,
+///     decimal_hours_from_angle,
+/// };
+///
+/// let angle: Angle = angle_from_decimal_hours(-0.1);
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), -6);
+/// assert!(angle.second() >= 0.0);
+/// assert_eq!(angle.sign(), -1);
+/// assert_approx_eq!(
+///     decimal_hours_from_angle(angle),
+///     -0.1,
+///     1e-12
+/// );
+///
+/// let angle: Angle = angle_from_decimal_hours(-0.003);
+/// assert_eq!(angle.hour(), 0);
+/// assert_eq!(angle.minute(), 0);
+/// assert_approx_eq!(angle.second(), -10.8, 1e-12);
+/// assert_approx_eq!(
+///     decimal_hours_from_angle(angle),
+///     -0.003,
+///     1e-12
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn angle_from_decimal_hours(dec: f64) -> Angle {
+    let (hour, min, sec): (i32, i32, f64) =
+        hms_from_decimal_hours(dec.abs());
+
+    let sign: i8 = if dec < 0.0 { -1 } else { 1 };
+
+    Angle::with_sign(
+        sign,
+        hour as u32,
+        min as u32,
+        sec,
+    )
 }
 
 /// Example:
@@ -378,6 +537,92 @@ pub fn naive_time_from_decimal_days(
     (day, naive)
 }
 
+/// The first day on which the Gregorian calendar
+/// was in use. Countries adopted the Gregorian
+/// calendar on different dates, and dates before
+/// the reform are regarded as those of the Julian
+/// calendar.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::CalendarReform;
+///
+/// let reform = CalendarReform::gregorian_1582();
+/// assert_eq!(reform.year, 1582);
+/// assert_eq!(reform.month, 10);
+/// assert_eq!(reform.day, 15);
+///
+/// // Julian Day for 0h of the first Gregorian day.
+/// assert_eq!(reform.julian_day(), 2_299_160.5);
+/// assert_eq!(
+///     CalendarReform::british().julian_day(),
+///     2_361_221.5
+/// );
+/// assert_eq!(
+///     CalendarReform::russian().julian_day(),
+///     2_421_638.5
+/// );
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct CalendarReform {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarReform {
+    pub fn new(
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Self {
+        CalendarReform { year, month, day }
+    }
+
+    /// October 15, 1582 (Catholic countries).
+    /// This is the default for the crate.
+    pub fn gregorian_1582() -> Self {
+        CalendarReform::new(1582, 10, 15)
+    }
+
+    /// September 14, 1752 (Britain and its colonies)
+    pub fn british() -> Self {
+        CalendarReform::new(1752, 9, 14)
+    }
+
+    /// February 14, 1918 (Russia)
+    pub fn russian() -> Self {
+        CalendarReform::new(1918, 2, 14)
+    }
+
+    /// Returns Julian Day for 0h of the first
+    /// Gregorian day.
+    pub fn julian_day(&self) -> f64 {
+        julian_day_with_reform(
+            self.year,
+            self.month,
+            self.day as f64,
+            *self,
+        )
+    }
+
+    fn is_before(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> bool {
+        (year, month, day)
+            < (self.year, self.month, self.day)
+    }
+}
+
+impl Default for CalendarReform {
+    fn default() -> Self {
+        CalendarReform::gregorian_1582()
+    }
+}
+
 /// Checks whether the given Date is julian day.
 ///
 /// Example:
@@ -394,37 +639,70 @@ pub fn naive_time_from_decimal_days(
 pub fn is_julian_date<T>(date: T) -> bool
 where
     T: Datelike,
-    T: std::fmt::Display,
 {
-    if date.year() > 1582 {
-        return false;
-    }
-    if date.year() < 1582 {
-        return true;
-    }
-    if date.month() > 10 {
-        return false;
-    }
-    if date.month() < 10 {
-        return true;
-    }
-    if date.day() > 14 {
-        return false;
-    }
-    true
+    is_julian_date_with_reform(
+        date,
+        CalendarReform::gregorian_1582(),
+    )
+}
+
+/// Checks whether the given Date is julian day
+/// for the given calendar reform.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::{
+///     CalendarReform,
+///     is_julian_date_with_reform,
+/// };
+///
+/// let reform = CalendarReform::british();
+///
+/// let date = NaiveDate::from_ymd(1752, 9, 2);
+/// assert_eq!(is_julian_date_with_reform(date, reform), true);
+///
+/// let date = NaiveDate::from_ymd(1752, 9, 14);
+/// assert_eq!(is_julian_date_with_reform(date, reform), false);
+/// ```
+pub fn is_julian_date_with_reform<T>(
+    date: T,
+    reform: CalendarReform,
+) -> bool
+where
+    T: Datelike,
+{
+    reform.is_before(
+        date.year(),
+        date.month(),
+        date.day(),
+    )
 }
 
-/// Check if the given year is a leap year.
+/// Check if the given year is a leap year in the
+/// (proleptic) Gregorian calendar. Year 0 is 1 BC,
+/// and negative years follow the same rules since
+/// the remainders for them are 0 whenever divisible.
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::is_leap_year;
+///
+/// assert_eq!(is_leap_year(1988), true);
+/// assert_eq!(is_leap_year(1900), false);
+/// assert_eq!(is_leap_year(2000), true);
+/// assert_eq!(is_leap_year(2100), false);
+///
+/// // Proleptic years
+/// assert_eq!(is_leap_year(0), true);
+/// assert_eq!(is_leap_year(-4), true);
+/// assert_eq!(is_leap_year(-1), false);
+/// assert_eq!(is_leap_year(-100), false);
+/// assert_eq!(is_leap_year(-400), true);
+/// ```
 pub fn is_leap_year(year: i32) -> bool {
-    if year % 4 == 0 {
-        if year % 100 == 0 {
-            year % 400 == 0
-        } else {
-            true
-        }
-    } else {
-        false
-    }
+    (year % 4 == 0 && year % 100 != 0)
+        || year % 400 == 0
 }
 
 /// Finds the day number from date.
@@ -447,7 +725,6 @@ pub fn is_leap_year(year: i32) -> bool {
 pub fn day_number_from_generic_date<T>(date: T) -> u32
 where
     T: Datelike,
-    T: std::fmt::Display,
 {
     let tmp: f64 = if is_leap_year(date.year()) {
         62.0
@@ -465,6 +742,45 @@ where
     (num as u32) + date.day()
 }
 
+/// Given a datetime, returns how far into the year it
+/// is, from 0.0 at the start of Jan 1st, to (almost)
+/// 1.0 at the end of Dec 31st. Unlike
+/// `decimal_year_from_generic_date` (which only takes
+/// the month), it goes by the day number and the time
+/// of the day, over 365 days (or 366 days for leap
+/// years).
+///
+/// * `dt` - DateTime
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::fraction_of_year;
+///
+/// let dt = NaiveDate::from_ymd(2021, 7, 2).and_hms(12, 0, 0);
+/// assert_approx_eq!(fraction_of_year(dt), 0.5, 1e-12);
+/// ```
+pub fn fraction_of_year<T>(dt: T) -> f64
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    let days: f64 = if is_leap_year(dt.year()) {
+        366.0
+    } else {
+        365.0
+    };
+    let hours: f64 = decimal_hours_from_generic_time(
+        naive_time_from_generic_datetime(dt),
+    );
+
+    ((day_number_from_generic_date(dt) - 1) as f64
+        + hours / 24.0)
+        / days
+}
+
 /// Note:
 /// Regardless of the month, the diff is of "Jan 0th".
 /// Say, for "July 27th, 1988", it will be the diff
@@ -485,13 +801,38 @@ where
 /// (365 * -2 years) - 1
 ///
 /// where "-1" is for 1988, a leap year.
-#[allow(clippy::many_single_char_names)]
+///
+/// See `days_since_epoch` for epochs other than 1990.
 pub fn days_since_1990(year: i32) -> i32 {
+    days_since_epoch(year, 1990)
+}
+
+/// Same as `days_since_1990`, but from Jan 0th of the
+/// given epoch year, for the orbital elements at
+/// other epochs (say, 2000).
+///
+/// * `year` - Year
+/// * `epoch_year` - Year of the epoch
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::{days_since_1990, days_since_epoch};
+///
+/// assert_eq!(days_since_epoch(1988, 1990), days_since_1990(1988));
+///
+/// // 1990 to 1999 has 2 leap years (1992 and 1996).
+/// assert_eq!(days_since_epoch(1990, 2000), -3652);
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn days_since_epoch(
+    year: i32,
+    epoch_year: i32,
+) -> i32 {
     let mut year_0: i32 = year;
     let mut days: i32 = 0;
 
-    if year - 1990 < 0 {
-        while year_0 < 1990 {
+    if year - epoch_year < 0 {
+        while year_0 < epoch_year {
             let leap = is_leap_year(year_0);
             days -= 365;
             if leap {
@@ -500,8 +841,10 @@ pub fn days_since_1990(year: i32) -> i32 {
             year_0 += 1;
         }
     } else {
-        while year_0 > 1990 {
-            let leap = is_leap_year(year_0);
+        while year_0 > epoch_year {
+            // Days of the years from the epoch to the
+            // year before.
+            let leap = is_leap_year(year_0 - 1);
             days += 365;
             if leap {
                 days += 1;
@@ -513,6 +856,37 @@ pub fn days_since_1990(year: i32) -> i32 {
     days
 }
 
+/// Given two dates, returns the signed number of days
+/// from `a` to `b` (negative when `b` is earlier).
+/// It goes by the Julian days (see
+/// `julian_day_from_generic_date`), so that leap years
+/// and the calendar reform in 1582 (where October 4th
+/// is followed by October 15th) are taken care of.
+///
+/// * `a` - Date (from)
+/// * `b` - Date (to)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::day_difference;
+///
+/// let a = NaiveDate::from_ymd(1988, 7, 27);
+/// let b = NaiveDate::from_ymd(1990, 1, 1);
+///
+/// assert_eq!(day_difference(a, b), 523);
+/// assert_eq!(day_difference(b, a), -523);
+/// ```
+pub fn day_difference<T, U>(a: T, b: U) -> i64
+where
+    T: Datelike,
+    U: Datelike,
+{
+    (julian_day_from_generic_date(b)
+        - julian_day_from_generic_date(a))
+    .round() as i64
+}
+
 /// Converts a generic datetime into Julian Day.
 ///
 /// Example:
@@ -541,24 +915,81 @@ pub fn julian_day_from_generic_datetime<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
-    julian_day(
-        dt.year(),
-        dt.month(),
-        decimal_days_from_generic_datetime(dt),
+    julian_day_from_naive(
+        _naive_from_generic_datetime(dt),
     )
 }
 
+/// Converts `NaiveDateTime` into Julian Day. Julian
+/// Day of the date (at 0h) is added the fraction of
+/// the day, which is taken directly from the seconds
+/// (and nanoseconds) since the midnight. It is the
+/// same as giving `julian_day` the day with the
+/// fraction.
+///
+/// References:
+/// - (Peter Duffett-Smith, pp.6-7)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::{julian_day, julian_day_from_naive};
+///
+/// let dt = NaiveDate::from_ymd(1985, 2, 17).and_hms(6, 0, 0);
+/// assert_eq!(julian_day_from_naive(dt), 2_446_113.75);
+/// assert_eq!(
+///     julian_day_from_naive(dt),
+///     julian_day(1985, 2, 17.25)
+/// );
+///
+/// // Marty McFly goes back in time.
+/// let dt = NaiveDate::from_ymd(1985, 10, 26).and_hms(1, 35, 0);
+/// assert_approx_eq!(
+///     julian_day_from_naive(dt), // 2446364.565972222
+///     2_446_364.566,
+///     1e-9
+/// );
+/// ```
+pub fn julian_day_from_naive(
+    dt: NaiveDateTime,
+) -> f64 {
+    let secs: f64 = dt.num_seconds_from_midnight()
+        as f64
+        + dt.nanosecond() as f64 / 1_000_000_000.0;
+
+    julian_day_from_generic_date(dt.date())
+        + secs / 86_400.0
+}
+
+/// Converts a generic date into Julian Day (for 0h
+/// of the date).
+///
+/// Example:
+/// ```rust
+/// use chrono::NaiveDate;
+/// use sowngwala::time::julian_day_from_generic_date;
+///
+/// // The last day of the Julian calendar, and the
+/// // first day of the Gregorian calendar.
+/// assert_eq!(
+///     julian_day_from_generic_date(
+///         NaiveDate::from_ymd(1582, 10, 4)
+///     ),
+///     2_299_159.5
+/// );
+/// assert_eq!(
+///     julian_day_from_generic_date(
+///         NaiveDate::from_ymd(1582, 10, 15)
+///     ),
+///     2_299_160.5
+/// );
+/// ```
 #[allow(clippy::many_single_char_names)]
 pub fn julian_day_from_generic_date<T>(date: T) -> f64
 where
     T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     julian_day(
         date.year(),
@@ -603,65 +1034,269 @@ pub fn julian_day(
     month: u32,
     day: f64,
 ) -> f64 {
-    let (y, m) = if month == 1 || month == 2 {
-        ((year - 1) as f64, (month + 12) as f64)
-    } else {
-        (year as f64, month as f64)
-    };
-
-    let b: f64 = if is_julian_date(
-        NaiveDate::from_ymd(year, month, day as u32),
-    ) {
-        0.0
-    } else {
-        let a = (y / 100.0).floor();
-        2.0 - a + (a / 4.0).floor()
-    };
-
-    let c: f64 = if y < 0.0 {
-        ((NUM_OF_DAYS_IN_A_YEAR * y) - 0.75).floor()
-    } else {
-        (NUM_OF_DAYS_IN_A_YEAR * y).floor()
-    };
-
-    let d: f64 = (30.6001 * (m + 1.0)).floor();
-
-    b + c + d + day + 1_720_994.5
+    julian_day_with_reform(
+        year,
+        month,
+        day,
+        CalendarReform::gregorian_1582(),
+    )
 }
 
-/// Converts Julian Day into `NaiveDateTime`. Duffett-
-/// Smith suggests a float value (ex. 17.5) for `day`
-/// for the returned result, but we want the excess
-/// (which is 0.5) being separate. That is why, instead
-/// of returning `NaiveDate`, returning `NaiveDateTime`,
+/// Converts a generic datetime into Julian Day
+/// for the given calendar reform. Dates before
+/// the reform are read as those of the Julian
+/// calendar. See `julian_day` for the rest.
 ///
 /// References:
-/// - (Peter Duffett-Smith, p.8)
-/// - sowngwala::time::date_from_julian_day
+/// - (Peter Duffett-Smith, pp.6-7)
 ///
 /// Example:
 /// ```rust
-/// use chrono::{Datelike, Timelike};
-/// use chrono::naive::NaiveDateTime;
-/// use sowngwala::time::naive_from_julian_day;
+/// use sowngwala::time::{
+///   CalendarReform,
+///   julian_day,
+///   julian_day_with_reform,
+/// };
 ///
-/// let naive: NaiveDateTime =
-///     naive_from_julian_day(2_446_113.75);
+/// let reform = CalendarReform::british();
 ///
-/// assert_eq!(naive.year(), 1985);
-/// assert_eq!(naive.month(), 2);
-/// assert_eq!(naive.day(), 17);
-/// assert_eq!(naive.hour(), 6);
-/// ```
-#[allow(clippy::many_single_char_names)]
-pub fn naive_from_julian_day(
-    mut jd: f64,
+/// // In Britain, September 2, 1752 (Julian)
+/// // was followed by September 14, 1752 (Gregorian).
+/// assert_eq!(
+///     julian_day_with_reform(1752, 9, 2.0, reform),
+///     2_361_220.5
+/// );
+/// assert_eq!(
+///     julian_day_with_reform(1752, 9, 14.0, reform),
+///     2_361_221.5
+/// );
+///
+/// // It is Gregorian for the default reform (1582).
+/// assert_eq!(
+///     julian_day(1752, 9, 2.0),
+///     2_361_209.5
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn julian_day_with_reform(
+    year: i32,
+    month: u32,
+    day: f64,
+    reform: CalendarReform,
+) -> f64 {
+    // The calendar is decided by the date itself,
+    // not by the fraction of the day (1582-10-04.9
+    // is still that of the Julian calendar).
+    let gregorian: bool = !reform.is_before(
+        year,
+        month,
+        day.floor() as u32,
+    );
+    _julian_day_aux(year, month, day, gregorian)
+}
+
+/// Calendar to read a date in, when converting it
+/// into Julian Day (see `julian_day_with_calendar`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Calendar {
+    /// The Julian calendar (even after 1582).
+    Julian,
+    /// The Gregorian calendar (proleptic before 1582).
+    Gregorian,
+    /// The Julian calendar before October 15, 1582,
+    /// and the Gregorian calendar since then (which
+    /// is what `julian_day` does).
+    Auto,
+}
+
+/// Converts a date into Julian Day reading the date
+/// in the given calendar. Use `Calendar::Gregorian`
+/// for the proleptic Gregorian calendar.
+///
+/// * `year` - Year
+/// * `month` - Month
+/// * `day` - Day (with the fraction of the day)
+/// * `calendar` - Calendar
+///
+/// References:
+/// - (Peter Duffett-Smith, pp.6-7)
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::{
+///     Calendar,
+///     julian_day,
+///     julian_day_with_calendar,
+/// };
+///
+/// // Until the reform, `Auto` is the Julian calendar.
+/// assert_eq!(
+///     julian_day_with_calendar(1582, 10, 4.0, Calendar::Auto),
+///     julian_day(1582, 10, 4.0),
+/// );
+/// assert_eq!(
+///     julian_day_with_calendar(1582, 10, 4.0, Calendar::Julian),
+///     2_299_159.5
+/// );
+/// // Ten days apart in the proleptic Gregorian calendar
+/// assert_eq!(
+///     julian_day_with_calendar(1582, 10, 4.0, Calendar::Gregorian),
+///     2_299_149.5
+/// );
+/// ```
+pub fn julian_day_with_calendar(
+    year: i32,
+    month: u32,
+    day: f64,
+    calendar: Calendar,
+) -> f64 {
+    match calendar {
+        Calendar::Julian => {
+            _julian_day_aux(year, month, day, false)
+        }
+        Calendar::Gregorian => {
+            _julian_day_aux(year, month, day, true)
+        }
+        Calendar::Auto => {
+            julian_day(year, month, day)
+        }
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn _julian_day_aux(
+    year: i32,
+    month: u32,
+    day: f64,
+    gregorian: bool,
+) -> f64 {
+    let (y, m) = if month == 1 || month == 2 {
+        ((year - 1) as f64, (month + 12) as f64)
+    } else {
+        (year as f64, month as f64)
+    };
+
+    let b: f64 = if !gregorian {
+        0.0
+    } else {
+        let a = (y / 100.0).floor();
+        2.0 - a + (a / 4.0).floor()
+    };
+
+    let c: f64 = if y < 0.0 {
+        ((NUM_OF_DAYS_IN_A_YEAR * y) - 0.75).floor()
+    } else {
+        (NUM_OF_DAYS_IN_A_YEAR * y).floor()
+    };
+
+    let d: f64 = (30.6001 * (m + 1.0)).floor();
+
+    b + c + d + day + 1_720_994.5
+}
+
+/// Converts Julian Day into `NaiveDateTime`. Duffett-
+/// Smith gives the day as a float (ex. 17.5), but we
+/// want the fraction (0.5) kept apart as the time of
+/// the day. That is why it returns `NaiveDateTime`
+/// instead of `NaiveDate`. The fraction of the day is
+/// rounded to the nearest nanosecond. Note that `f64`
+/// holds Julian Days of this era only to some 40
+/// microseconds.
+///
+/// References:
+/// - (Peter Duffett-Smith, p.8)
+/// - sowngwala::time::date_from_julian_day
+///
+/// Example:
+/// ```rust
+/// use chrono::{Datelike, Timelike};
+/// use chrono::naive::NaiveDateTime;
+/// use sowngwala::time::naive_from_julian_day;
+///
+/// let naive: NaiveDateTime =
+///     naive_from_julian_day(2_446_113.75);
+///
+/// assert_eq!(naive.year(), 1985);
+/// assert_eq!(naive.month(), 2);
+/// assert_eq!(naive.day(), 17);
+/// assert_eq!(naive.hour(), 6);
+/// ```
+///
+/// # Panics
+///
+/// Panics for the leap days of the Julian calendar
+/// that are not those of the Gregorian calendar (such
+/// as 1500-02-29), which `NaiveDate` does not have.
+/// See `naive_from_julian_day_with_reform` for
+/// `TimeError` instead.
+#[allow(clippy::many_single_char_names)]
+pub fn naive_from_julian_day(
+    jd: f64,
 ) -> NaiveDateTime {
+    naive_from_julian_day_with_reform(
+        jd,
+        CalendarReform::gregorian_1582(),
+    )
+    .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Converts Julian Day into `NaiveDateTime` for the
+/// given calendar reform. Julian Days before the
+/// reform are converted into dates of the Julian
+/// calendar. See `naive_from_julian_day` for the rest.
+///
+/// Returns `TimeError::UnrepresentableDate` for the
+/// leap days of the Julian calendar that are not
+/// those of the Gregorian calendar (such as
+/// 1700-02-29 under the British reform), since
+/// `NaiveDate` follows the Gregorian calendar.
+///
+/// References:
+/// - (Peter Duffett-Smith, p.8)
+///
+/// Example:
+/// ```rust
+/// use chrono::Datelike;
+/// use chrono::naive::NaiveDateTime;
+/// use sowngwala::time::{
+///     CalendarReform,
+///     TimeError,
+///     julian_day_with_reform,
+///     naive_from_julian_day_with_reform,
+/// };
+///
+/// let reform = CalendarReform::british();
+///
+/// let naive: NaiveDateTime =
+///     naive_from_julian_day_with_reform(2_361_220.5, reform)
+///         .unwrap();
+/// assert_eq!(naive.year(), 1752);
+/// assert_eq!(naive.month(), 9);
+/// assert_eq!(naive.day(), 2);
+///
+/// let naive: NaiveDateTime =
+///     naive_from_julian_day_with_reform(2_361_221.5, reform)
+///         .unwrap();
+/// assert_eq!(naive.year(), 1752);
+/// assert_eq!(naive.month(), 9);
+/// assert_eq!(naive.day(), 14);
+///
+/// let jd = julian_day_with_reform(1700, 2, 29.0, reform);
+/// assert_eq!(
+///     naive_from_julian_day_with_reform(jd, reform),
+///     Err(TimeError::UnrepresentableDate(1700, 2, 29))
+/// );
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn naive_from_julian_day_with_reform(
+    mut jd: f64,
+    reform: CalendarReform,
+) -> Result<NaiveDateTime, TimeError> {
     jd += 0.5;
 
     let i = jd.floor();
-    let f = jd.abs().fract();
-    let b = if i > 2_299_160.0 {
+    let f = jd - i;
+    let b = if i >= reform.julian_day() + 0.5 {
         let a =
             ((i - 1_867_216.25) / 36_524.25).floor();
         i + 1.0 + a - (a / 4.0).floor()
@@ -674,12 +1309,11 @@ pub fn naive_from_julian_day(
     let e = (d * NUM_OF_DAYS_IN_A_YEAR).floor();
     let g = ((c - e) / 30.6001).floor();
 
-    let decimal_days =
-        c - e + f - (g * 30.6001).floor();
-
     // This is where it differs from Duffett-Smith.
-    let (day, naive_time) =
-        naive_time_from_decimal_days(decimal_days);
+    // The fraction of the day (f) is added later
+    // in nanoseconds, so that it does not go through
+    // decimal hours (which loses the precision).
+    let day = (c - e - (g * 30.6001).floor()) as u32;
 
     let month =
         if g < 13.5 { g - 1.0 } else { g - 13.0 };
@@ -688,13 +1322,26 @@ pub fn naive_from_julian_day(
     } else {
         d - 4715.0
     };
-    let naive_date = NaiveDate::from_ymd(
-        year as i32,
-        month as u32,
-        day,
-    );
+    let midnight: NaiveDateTime =
+        NaiveDate::from_ymd_opt(
+            year as i32,
+            month as u32,
+            day,
+        )
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or(
+            TimeError::UnrepresentableDate(
+                year as i32,
+                month as u32,
+                day,
+            ),
+        )?;
 
-    NaiveDateTime::new(naive_date, naive_time)
+    // Rounding up to the next midnight moves the date.
+    let nanos: i64 =
+        (f * 86_400_000_000_000.0).round() as i64;
+
+    Ok(midnight + Duration::nanoseconds(nanos))
 }
 
 pub fn j2000_from_julian_day(jd: f64) -> f64 {
@@ -705,9 +1352,6 @@ pub fn j2000_from_generic_datetime<T>(dt: T) -> f64
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     j2000_from_julian_day(
         julian_day_from_generic_datetime(dt),
@@ -726,114 +1370,389 @@ pub fn modified_julian_day_from_generic_datetime<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     modified_julian_day_from_julian_day(
         julian_day_from_generic_datetime(dt),
     )
 }
 
-/// Finds day of the week out of a generic datetime.
+/// Given Julian epoch (such as 2000.0 for J2000.0),
+/// returns Julian Day. A Julian year is 365.25 days.
 ///
-/// References:
-/// - (Peter Duffett-Smith, p.9)
-/// - sowngwala::time::day_of_the_week
+/// JD = 2451545.0 + (epoch - 2000) × 365.25
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", p.133)
 ///
 /// Example:
 /// ```rust
-/// use chrono::naive::{NaiveDate, NaiveDateTime};
-/// use sowngwala::time::day_of_the_week;
+/// use sowngwala::time::julian_epoch_to_jd;
 ///
-/// let naive: NaiveDate =
-///     NaiveDate::from_ymd(1985, 2, 17);
+/// assert_eq!(julian_epoch_to_jd(2000.0), 2_451_545.0);
+/// assert_eq!(julian_epoch_to_jd(2050.0), 2_469_807.5);
+/// ```
+pub fn julian_epoch_to_jd(epoch: f64) -> f64 {
+    J2000 + (epoch - 2000.0) * 365.25
+}
+
+/// Given Julian Day, returns Julian epoch.
 ///
-/// assert_eq!(day_of_the_week(naive), 1);
+/// Example:
+/// ```rust
+/// use sowngwala::time::jd_to_julian_epoch;
+///
+/// assert_eq!(jd_to_julian_epoch(2_451_545.0), 2000.0);
 /// ```
-pub fn day_of_the_week<T>(dt: T) -> u32
+pub fn jd_to_julian_epoch(jd: f64) -> f64 {
+    2000.0 + (jd - J2000) / 365.25
+}
+
+/// Given Julian Day, returns Julian centuries (of
+/// 36525 days) since J2000.0 (T), which most of the
+/// polynomials for the sidereal time, the obliquity,
+/// and the nutation are written in.
+///
+/// T = (JD - 2451545.0) / 36525
+///
+/// Example:
+/// ```rust
+/// use sowngwala::time::julian_centuries_since_j2000;
+///
+/// assert_eq!(julian_centuries_since_j2000(2_451_545.0), 0.0);
+/// assert_eq!(julian_centuries_since_j2000(2_488_070.0), 1.0);
+/// ```
+pub fn julian_centuries_since_j2000(jd: f64) -> f64 {
+    (jd - J2000) / 36_525.0
+}
+
+/// Same as `julian_centuries_since_j2000`, but for
+/// a generic datetime.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::julian_centuries_from_generic_datetime;
+///
+/// let dt = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
+/// assert_eq!(julian_centuries_from_generic_datetime(dt), 0.0);
+/// ```
+pub fn julian_centuries_from_generic_datetime<T>(
+    dt: T,
+) -> f64
 where
     T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Display,
+    T: Timelike,
 {
-    // let jd = julian_day(dt);
-    // let a = (jd + 1.5) / 7.0;
-    // (a.abs().fract() * 7.0).round() as i32
+    julian_centuries_since_j2000(
+        julian_day_from_generic_datetime(dt),
+    )
+}
 
-    dt.weekday().number_from_sunday()
+/// Given Besselian epoch (such as 1950.0 for
+/// B1950.0), returns Julian Day. A Besselian year is
+/// the tropical year (365.242198781 days) starting
+/// when the mean sun is at 280°.
+///
+/// JD = 2415020.31352 + (epoch - 1900) × 365.242198781
+///
+/// Reference:
+/// - (Jean Meeus, "Astronomical Algorithms", p.133)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::time::besselian_epoch_to_jd;
+///
+/// assert_approx_eq!(
+///     besselian_epoch_to_jd(1950.0),
+///     2_433_282.4235,
+///     1e-10
+/// );
+/// assert_approx_eq!(
+///     besselian_epoch_to_jd(1900.0),
+///     2_415_020.3135,
+///     1e-10
+/// );
+/// ```
+pub fn besselian_epoch_to_jd(epoch: f64) -> f64 {
+    2_415_020.313_52
+        + (epoch - 1900.0) * 365.242_198_781
 }
 
-/// References:
-/// - sowngwala::time::add_date
+/// Given Julian Day, returns Besselian epoch.
 ///
 /// Example:
 /// ```rust
-/// use chrono::{DateTime, Datelike, Timelike};
-/// use chrono::naive::{NaiveDate, NaiveDateTime};
-/// use chrono::offset::{FixedOffset, Utc};
+/// use approx_eq::assert_approx_eq;
 /// use sowngwala::time::{
-///     build_fixed,
-///     build_utc,
-///     add_date
+///     besselian_epoch_to_jd, jd_to_besselian_epoch,
 /// };
 ///
-/// let days: i64 = 1;
-/// let zone: i32 = 4;
-///
-/// let naive: NaiveDateTime =
-///     NaiveDate::from_ymd(2021, 1, 1)
-///         .and_hms(22, 37, 0);
-/// let naive: NaiveDateTime = add_date(naive, days);
-///
-/// assert_eq!(naive.day(), 2);
-/// assert_eq!(naive.hour(), 22);
-/// assert_eq!(naive.minute(), 37);
-/// assert_eq!(naive.second(), 0);
+/// assert_approx_eq!(
+///     jd_to_besselian_epoch(2_433_282.4235),
+///     1950.0,
+///     1e-9
+/// );
+/// // J2000.0 is B2000.0012775
+/// assert_approx_eq!(
+///     jd_to_besselian_epoch(2_451_545.0),
+///     2000.001_277_5,
+///     1e-9
+/// );
+/// assert_approx_eq!(
+///     jd_to_besselian_epoch(besselian_epoch_to_jd(1875.0)),
+///     1875.0,
+///     1e-12
+/// );
+/// ```
+pub fn jd_to_besselian_epoch(jd: f64) -> f64 {
+    1900.0 + (jd - 2_415_020.313_52) / 365.242_198_781
+}
+
+/// Given UT, returns TT (Terrestrial Time) by adding
+/// ΔT (see `delta_t_from_generic_date`) as `Duration`
+/// in nanoseconds. The date rolls over when ΔT takes
+/// it past midnight.
 ///
-/// let fixed: DateTime<FixedOffset> =
-///     build_fixed(2021, 1, 1, 22, 37, 0, 0, zone);
-/// let fixed: DateTime<FixedOffset> =
-///     add_date(fixed, days);
+/// * `dt` - DateTime (in UT)
 ///
-/// assert_eq!(fixed.day(), 2);
-/// assert_eq!(fixed.hour(), 22);
-/// assert_eq!(fixed.minute(), 37);
-/// assert_eq!(fixed.second(), 0);
+/// Reference:
+/// - (Peter Duffett-Smith, pp.22-23)
 ///
-/// let utc: DateTime<Utc> =
-///     build_utc(2021, 1, 1, 22, 37, 0, 0);
-/// let utc: DateTime<Utc> = add_date(utc, 1);
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::time::tt_from_ut;
 ///
-/// assert_eq!(utc.day(), 2);
-/// assert_eq!(utc.hour(), 22);
-/// assert_eq!(utc.minute(), 37);
-/// assert_eq!(utc.second(), 0);
+/// // ΔT in 2000 is about 64 seconds.
+/// let ut: NaiveDateTime =
+///     NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
+/// let tt: NaiveDateTime = tt_from_ut(ut);
+/// assert_eq!((tt - ut).num_seconds(), 63);
 /// ```
-pub fn add_date<T>(dt: T, days: i64) -> T
+pub fn tt_from_ut<T>(dt: T) -> NaiveDateTime
 where
-    T: Add<Duration> + Add<Duration, Output = T>,
+    T: Datelike,
+    T: Timelike,
 {
-    dt + Duration::days(days)
+    let ut: NaiveDateTime =
+        _naive_from_generic_datetime(dt);
+    ut + _delta_t_duration(ut.date())
 }
 
-/// Example
-/// ```rust
-/// use sowngwala::time::calibrate_hmsn;
-///
-/// let ((hour, min, sec), day_excess) = calibrate_hmsn(0, 0, 63.0);
-/// assert_eq!(sec, 3.0);
-/// assert_eq!(min, 1);
+/// Given TT (Terrestrial Time), returns UT by
+/// subtracting ΔT. The inverse of `tt_from_ut`.
 ///
-/// let ((hour, min, sec), day_excess) = calibrate_hmsn(0, 63, 0.0);
-/// assert_eq!(min, 3);
-/// assert_eq!(hour, 1);
+/// * `dt` - DateTime (in TT)
 ///
-/// let ((hour, min, sec), day_excess) = calibrate_hmsn(24, 0, 0.0);
-/// assert_eq!(hour, 0);
-/// assert_eq!(day_excess, 1.0);
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::time::{tt_from_ut, ut_from_tt};
 ///
-/// let ((hour, min, sec), day_excess) = calibrate_hmsn(23, 59, 60.0);
+/// let ut: NaiveDateTime =
+///     NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
+/// assert_eq!(ut_from_tt(tt_from_ut(ut)), ut);
+/// ```
+pub fn ut_from_tt<T>(dt: T) -> NaiveDateTime
+where
+    T: Datelike,
+    T: Timelike,
+{
+    let tt: NaiveDateTime =
+        _naive_from_generic_datetime(dt);
+    let ut: NaiveDateTime =
+        tt - _delta_t_duration(tt.date());
+
+    // ΔT of the date in UT (which could be the day
+    // before) for `tt_from_ut` to be inverted.
+    tt - _delta_t_duration(ut.date())
+}
+
+fn _naive_from_generic_datetime<T>(
+    dt: T,
+) -> NaiveDateTime
+where
+    T: Datelike,
+    T: Timelike,
+{
+    NaiveDate::from_ymd_opt(
+        dt.year(),
+        dt.month(),
+        dt.day(),
+    )
+    .and_then(|date| {
+        date.and_hms_nano_opt(
+            dt.hour(),
+            dt.minute(),
+            dt.second(),
+            dt.nanosecond(),
+        )
+    })
+    .unwrap()
+}
+
+fn _delta_t_duration(date: NaiveDate) -> Duration {
+    let delta_t: f64 =
+        delta_t_from_generic_date(date);
+    Duration::nanoseconds(
+        (delta_t * 1_000_000_000.0).round() as i64,
+    )
+}
+
+/// Finds day of the week out of a generic datetime.
+/// Returns a number (1 for Sunday, and 7 for
+/// Saturday). See `weekday_from_generic_date` for
+/// the typed value.
+///
+/// References:
+/// - (Peter Duffett-Smith, p.9)
+/// - sowngwala::time::day_of_the_week
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::time::day_of_the_week;
+///
+/// let naive: NaiveDate =
+///     NaiveDate::from_ymd(1985, 2, 17);
+///
+/// assert_eq!(day_of_the_week(naive), 1);
+/// ```
+pub fn day_of_the_week<T>(dt: T) -> u32
+where
+    T: Datelike,
+{
+    weekday_from_generic_date(dt).number_from_sunday()
+}
+
+/// Finds day of the week out of a generic date.
+///
+/// * `date` - Datelike
+///
+/// Example:
+/// ```rust
+/// use chrono::Weekday;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::weekday_from_generic_date;
+///
+/// let date = NaiveDate::from_ymd(1985, 2, 17);
+/// assert_eq!(weekday_from_generic_date(date), Weekday::Sun);
+/// ```
+pub fn weekday_from_generic_date<T>(
+    date: T,
+) -> Weekday
+where
+    T: Datelike,
+{
+    date.weekday()
+}
+
+/// Finds day of the week out of Julian Day. Works for
+/// any Julian Day (including those of the Julian
+/// calendar).
+///
+/// * `jd` - Julian Day
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.9)
+///
+/// Example:
+/// ```rust
+/// use chrono::Weekday;
+/// use sowngwala::time::weekday_from_julian_day;
+///
+/// // 1985-02-17 0h
+/// assert_eq!(
+///     weekday_from_julian_day(2_446_113.5),
+///     Weekday::Sun
+/// );
+/// ```
+pub fn weekday_from_julian_day(jd: f64) -> Weekday {
+    // The day starts at 0h (JD + 0.5), and JD 0 is a
+    // Monday (1 day after Sunday).
+    let days: f64 = (jd + 1.5).floor();
+    match days.rem_euclid(7.0) as u32 {
+        0 => Weekday::Sun,
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        _ => Weekday::Sat,
+    }
+}
+
+/// References:
+/// - sowngwala::time::add_date
+///
+/// Example:
+/// ```rust
+/// use chrono::{DateTime, Datelike, Timelike};
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use chrono::offset::{FixedOffset, Utc};
+/// use sowngwala::time::{
+///     build_fixed,
+///     build_utc,
+///     add_date
+/// };
+///
+/// let days: i64 = 1;
+/// let zone: i32 = 4;
+///
+/// let naive: NaiveDateTime =
+///     NaiveDate::from_ymd(2021, 1, 1)
+///         .and_hms(22, 37, 0);
+/// let naive: NaiveDateTime = add_date(naive, days);
+///
+/// assert_eq!(naive.day(), 2);
+/// assert_eq!(naive.hour(), 22);
+/// assert_eq!(naive.minute(), 37);
+/// assert_eq!(naive.second(), 0);
+///
+/// let fixed: DateTime<FixedOffset> =
+///     build_fixed(2021, 1, 1, 22, 37, 0, 0, zone);
+/// let fixed: DateTime<FixedOffset> =
+///     add_date(fixed, days);
+///
+/// assert_eq!(fixed.day(), 2);
+/// assert_eq!(fixed.hour(), 22);
+/// assert_eq!(fixed.minute(), 37);
+/// assert_eq!(fixed.second(), 0);
+///
+/// let utc: DateTime<Utc> =
+///     build_utc(2021, 1, 1, 22, 37, 0, 0);
+/// let utc: DateTime<Utc> = add_date(utc, 1);
+///
+/// assert_eq!(utc.day(), 2);
+/// assert_eq!(utc.hour(), 22);
+/// assert_eq!(utc.minute(), 37);
+/// assert_eq!(utc.second(), 0);
+/// ```
+pub fn add_date<T>(dt: T, days: i64) -> T
+where
+    T: Add<Duration> + Add<Duration, Output = T>,
+{
+    dt + Duration::days(days)
+}
+
+/// Example
+/// ```rust
+/// use sowngwala::time::calibrate_hmsn;
+///
+/// let ((hour, min, sec), day_excess) = calibrate_hmsn(0, 0, 63.0);
+/// assert_eq!(sec, 3.0);
+/// assert_eq!(min, 1);
+///
+/// let ((hour, min, sec), day_excess) = calibrate_hmsn(0, 63, 0.0);
+/// assert_eq!(min, 3);
+/// assert_eq!(hour, 1);
+///
+/// let ((hour, min, sec), day_excess) = calibrate_hmsn(24, 0, 0.0);
+/// assert_eq!(hour, 0);
+/// assert_eq!(day_excess, 1.0);
+///
+/// let ((hour, min, sec), day_excess) = calibrate_hmsn(23, 59, 60.0);
 /// assert_eq!(sec, 0.0);
 /// assert_eq!(min, 0);
 /// assert_eq!(hour, 0);
@@ -853,6 +1772,50 @@ pub fn calibrate_hmsn(
     hour: i32,
     min: i32,
     sec: f64,
+) -> ((i32, i32, f64), f64) {
+    _calibrate_aux(hour, min, sec, 24.0)
+}
+
+/// Same as `calibrate_hmsn`, but for angles in
+/// degrees, arcminutes, and arcseconds (such as
+/// longitudes), which carry over at 360° instead of
+/// 24 hours. Returns the calibrated angle in
+/// [0°, 360°), and how many full circles were carried
+/// over (the degree excess).
+///
+/// Example
+/// ```rust
+/// use sowngwala::time::calibrate_dms;
+///
+/// let ((deg, min, sec), excess) = calibrate_dms(359, 59, 61.0);
+/// assert_eq!((deg, min, sec), (0, 0, 1.0));
+/// assert_eq!(excess, 1.0);
+///
+/// // Unlike hours, 24° stays as it is.
+/// let ((deg, min, sec), excess) = calibrate_dms(23, 59, 60.0);
+/// assert_eq!((deg, min, sec), (24, 0, 0.0));
+/// assert_eq!(excess, 0.0);
+///
+/// let ((deg, min, sec), excess) = calibrate_dms(0, 0, -1.0);
+/// assert_eq!((deg, min, sec), (359, 59, 59.0));
+/// assert_eq!(excess, -1.0);
+/// ```
+pub fn calibrate_dms(
+    deg: i32,
+    min: i32,
+    sec: f64,
+) -> ((i32, i32, f64), f64) {
+    _calibrate_aux(deg, min, sec, 360.0)
+}
+
+/// See `calibrate_hmsn` for the specs. `full` is
+/// where the first component carries over (24.0 for
+/// hours, and 360.0 for degrees).
+fn _calibrate_aux(
+    hour: i32,
+    min: i32,
+    sec: f64,
+    full: f64,
 ) -> ((i32, i32, f64), f64) {
     let mut hour = hour as f64;
     let mut min = min as f64;
@@ -887,7 +1850,7 @@ pub fn calibrate_hmsn(
     hour += hour_excess;
 
     let (hour_2, day_excess_0): (f64, f64) =
-        overflow(hour, 24.0);
+        overflow(hour, full);
 
     hour = hour_2;
 
@@ -919,17 +1882,145 @@ pub fn calibrate_hmsn(
     }
 
     if hour < 0.0 {
-        hour += 24.0;
+        hour += full;
         day_excess -= 1.0;
     }
 
     ((hour as i32, min as i32, sec), day_excess)
 }
 
+/// Errors for converting local times.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeError {
+    /// The zone (in hours) is out of range.
+    InvalidZone(i32),
+    /// The local time does not exist (such as in the
+    /// gap when the daylight saving time begins).
+    NonexistentLocalTime(NaiveDateTime),
+    /// The local time happens twice (such as when the
+    /// daylight saving time ends).
+    AmbiguousLocalTime(NaiveDateTime),
+    /// The date (year, month, and day) of the Julian
+    /// calendar has no `NaiveDate` (such as 1700-02-29,
+    /// which is not a leap day of the Gregorian
+    /// calendar).
+    UnrepresentableDate(i32, u32, u32),
+}
+
+impl std::fmt::Display for TimeError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match self {
+            TimeError::InvalidZone(zone) => {
+                write!(f, "Invalid zone: {}", zone)
+            }
+            TimeError::NonexistentLocalTime(
+                naive,
+            ) => {
+                write!(
+                    f,
+                    "Local time does not exist: {}",
+                    naive
+                )
+            }
+            TimeError::AmbiguousLocalTime(naive) => {
+                write!(
+                    f,
+                    "Local time is ambiguous: {}",
+                    naive
+                )
+            }
+            TimeError::UnrepresentableDate(
+                year,
+                month,
+                day,
+            ) => {
+                write!(
+                    f,
+                    "Date is not representable: {}-{:02}-{:02}",
+                    year, month, day
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeError {}
+
+/// Attaches the time zone to `NaiveDateTime` (the
+/// local time). Unlike chrono's `from_local_datetime`,
+/// returns `TimeError` when the local time does not
+/// exist or is ambiguous in the time zone.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use chrono::offset::FixedOffset;
+/// use sowngwala::time::try_local_from_naive;
+///
+/// let naive: NaiveDateTime =
+///     NaiveDate::from_ymd(2021, 1, 1).and_hms(22, 37, 0);
+/// let fixed =
+///     try_local_from_naive(naive, &FixedOffset::east(4 * 3600))
+///         .unwrap();
+///
+/// assert_eq!(fixed.naive_local(), naive);
+/// ```
+pub fn try_local_from_naive<Tz: TimeZone>(
+    naive: NaiveDateTime,
+    tz: &Tz,
+) -> Result<DateTime<Tz>, TimeError> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Ok(dt),
+        LocalResult::None => Err(
+            TimeError::NonexistentLocalTime(naive),
+        ),
+        LocalResult::Ambiguous(_, _) => {
+            Err(TimeError::AmbiguousLocalTime(naive))
+        }
+    }
+}
+
+/// Converts `NaiveDateTime` into
+/// `DateTime<FixedOffset>`, or returns `TimeError`
+/// when `zone` is out of range (-23 to 23). See
+/// `fixed_from_naive`.
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::{NaiveDate, NaiveDateTime};
+/// use sowngwala::time::{try_fixed_from_naive, TimeError};
+///
+/// let naive: NaiveDateTime =
+///     NaiveDate::from_ymd(2021, 1, 1).and_hms(22, 37, 0);
+///
+/// assert!(try_fixed_from_naive(naive, 4).is_ok());
+/// assert_eq!(
+///     try_fixed_from_naive(naive, 25),
+///     Err(TimeError::InvalidZone(25))
+/// );
+/// ```
+pub fn try_fixed_from_naive(
+    naive: NaiveDateTime,
+    zone: i32,
+) -> Result<DateTime<FixedOffset>, TimeError> {
+    let offset = zone
+        .checked_mul(3600)
+        .and_then(FixedOffset::east_opt)
+        .ok_or(TimeError::InvalidZone(zone))?;
+
+    try_local_from_naive(naive, &offset)
+}
+
 /// Converts `NaiveDateTime` into
 /// `DateTime<FixedOffset>`. Resulted `hour` should be
 /// the same regardless of `zone` given. In another
 /// word, it just attaches `zone` to the given.
+/// When `zone` is out of range (-23 to 23), it is
+/// clamped into the range (instead of panicking), for
+/// which `try_fixed_from_naive` returns an error.
 ///
 /// Example:
 /// ```rust
@@ -953,9 +2044,12 @@ pub fn fixed_from_naive(
     naive: NaiveDateTime,
     zone: i32,
 ) -> DateTime<FixedOffset> {
-    FixedOffset::east(zone * 3600)
-        .from_local_datetime(&naive)
-        .unwrap()
+    let zone: i32 = zone.clamp(-23, 23);
+    let offset: FixedOffset =
+        FixedOffset::east_opt(zone * 3600).unwrap();
+
+    // No gaps nor overlaps for fixed offsets
+    offset.from_utc_datetime(&(naive - offset))
 }
 
 /// Converts `DateTime<Utc>` into `DateTime<FixedOffset>`.
@@ -1055,6 +2149,38 @@ pub fn utc_from_fixed(
     Utc.from_utc_datetime(&fixed.naive_utc())
 }
 
+/// Converts `DateTime` of any time zone (`Local`,
+/// `FixedOffset`, or `Utc` itself) into
+/// `DateTime<Utc>`. Same as `utc_from_fixed`, but
+/// for whatever `DateTime` one has (say, from
+/// `Local::now()`).
+///
+/// Example
+/// ```rust
+/// use chrono::{DateTime, Timelike};
+/// use chrono::offset::{FixedOffset, Local, Utc};
+/// use sowngwala::time::{
+///     build_fixed,
+///     utc_from_any,
+///     utc_from_fixed,
+/// };
+///
+/// let fixed: DateTime<FixedOffset> =
+///     build_fixed(2021, 1, 1, 2, 37, 0, 0, 4);
+/// let utc: DateTime<Utc> = utc_from_any(fixed);
+///
+/// assert_eq!(utc, utc_from_fixed(fixed));
+/// assert_eq!(utc.hour(), 22);
+///
+/// let now: DateTime<Local> = Local::now();
+/// assert_eq!(utc_from_any(now), now.with_timezone(&Utc));
+/// ```
+pub fn utc_from_any<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+) -> DateTime<Utc> {
+    dt.with_timezone(&Utc)
+}
+
 /// Converts `DateTime<FixedOffset>` into
 /// `NaiveDateTime`. Resulted `hour` should be the same
 /// regardless of `zone` given. In another word, it
@@ -1149,8 +2275,8 @@ pub fn eot_decimal_from_utc(
 /// assert_eq!(utc.day(), 31);
 /// assert_eq!(utc.hour(), 23);
 /// assert_eq!(utc.minute(), 59);
-/// assert_eq!(utc.second(), 34); // 34.227691152289594
-/// assert_eq!(utc.nanosecond(), 227_691_152);
+/// assert_eq!(utc.second(), 34); // 34.22770756
+/// assert_eq!(utc.nanosecond(), 227_707_561);
 /// ```
 #[allow(clippy::many_single_char_names)]
 pub fn eot_fortified_utc_from_fixed(
@@ -1185,37 +2311,253 @@ pub fn eot_fortified_utc_from_fixed(
     )
 }
 
-/// Given UT, and retursn GST.
+/// Given UT and longitude (along with its direction),
+/// returns the local mean time (LMT), which is UT
+/// shifted by the longitude (an hour for every 15
+/// degrees, ahead for East). Returns
+/// `SowngwalaError::InvalidDirection` when `dir` is
+/// North or South.
 ///
-/// References:
-/// - (Peter Duffett-Smith, p.17)
-/// - sowngwala::time::gst_from_ut
+/// * `utc` - UTC
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
 ///
 /// Example:
 /// ```rust
 /// use chrono::{DateTime, Timelike};
 /// use chrono::naive::NaiveTime;
 /// use chrono::offset::Utc;
+/// use sowngwala::coords::Direction;
+/// use sowngwala::time::{build_utc, local_mean_time_from_utc};
+///
+/// let utc: DateTime<Utc> = build_utc(1980, 7, 27, 12, 0, 0, 0);
+///
+/// // 64° West is 4h 16m behind.
+/// let lmt: NaiveTime =
+///     local_mean_time_from_utc(utc, 64.0, Direction::West)
+///         .unwrap();
+/// assert_eq!(lmt.hour(), 7);
+/// assert_eq!(lmt.minute(), 44);
+/// assert_eq!(lmt.second(), 0);
+///
+/// assert!(
+///     local_mean_time_from_utc(utc, 64.0, Direction::North)
+///         .is_err()
+/// );
+/// ```
+pub fn local_mean_time_from_utc(
+    utc: DateTime<Utc>,
+    lng: f64,
+    dir: Direction,
+) -> Result<NaiveTime, SowngwalaError> {
+    Ok(naive_time_with_residual(
+        _local_mean_decimal_from_utc(utc, lng, dir)?,
+    )
+    .0)
+}
+
+/// Given UT and longitude (along with its direction),
+/// returns the local apparent (solar) time (LAT),
+/// which is what a sundial shows. It is the local
+/// mean time plus the equation of time (see
+/// `equation_of_time_minutes_from_date`, which is
+/// interpolated between 0h of the day and the next).
+/// Returns `SowngwalaError::InvalidDirection` when
+/// `dir` is North or South.
+///
+/// * `utc` - UTC
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.98-99)
+///
+/// Example:
+/// ```rust
+/// use chrono::{DateTime, Timelike};
+/// use chrono::naive::NaiveTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::coords::Direction;
 /// use sowngwala::time::{
 ///     build_utc,
-///     gst_from_utc,
+///     decimal_hours_from_naive_time,
+///     local_apparent_time_from_utc,
+///     local_mean_time_from_utc,
 /// };
 ///
-/// let nanosecond: u32 = 670_000_000;
-/// let utc: DateTime<Utc> =
-///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
-/// let gst: NaiveTime = gst_from_utc(utc);
+/// let utc: DateTime<Utc> = build_utc(1980, 7, 27, 12, 0, 0, 0);
 ///
-/// assert_eq!(gst.hour(), 4);
-/// assert_eq!(gst.minute(), 40);
-/// assert_eq!(gst.second(), 5); // 5.229576759185761
-/// assert_eq!(gst.nanosecond(), 229_576_759);
+/// let lmt: NaiveTime =
+///     local_mean_time_from_utc(utc, 64.0, Direction::West)
+///         .unwrap();
+/// let lat: NaiveTime =
+///     local_apparent_time_from_utc(utc, 64.0, Direction::West)
+///         .unwrap();
+///
+/// // The sundial is behind by the EOT (about 6m 29s).
+/// let eot: f64 = (decimal_hours_from_naive_time(lat)
+///     - decimal_hours_from_naive_time(lmt))
+///     * 60.0;
+/// assert!((eot + 6.48).abs() < 0.05);
+/// assert_eq!(lat.hour(), 7);
+/// assert_eq!(lat.minute(), 37);
 /// ```
-pub fn gst_from_utc(utc: DateTime<Utc>) -> NaiveTime {
-    let jd = julian_day_from_generic_date(utc);
+pub fn local_apparent_time_from_utc(
+    utc: DateTime<Utc>,
+    lng: f64,
+    dir: Direction,
+) -> Result<NaiveTime, SowngwalaError> {
+    let lmt: f64 =
+        _local_mean_decimal_from_utc(utc, lng, dir)?;
+    let date: NaiveDate = utc.date_naive();
+    let eot_0: f64 =
+        equation_of_time_minutes_from_date(date);
+    let eot_1: f64 =
+        equation_of_time_minutes_from_date(
+            date + Duration::days(1),
+        );
+    let frac: f64 =
+        decimal_hours_from_generic_time(utc.time())
+            / 24.0;
+    let eot: f64 =
+        (eot_0 + (eot_1 - eot_0) * frac) / 60.0;
+
+    let lat: f64 = (lmt + eot).rem_euclid(24.0);
+    Ok(naive_time_with_residual(lat).0)
+}
+
+fn _local_mean_decimal_from_utc(
+    utc: DateTime<Utc>,
+    lng: f64,
+    dir: Direction,
+) -> Result<f64, SowngwalaError> {
+    let ut: f64 =
+        decimal_hours_from_generic_time(utc.time());
+    let diff: f64 = lng / 15.0;
+
+    let lmt: f64 = match dir {
+        Direction::West => ut - diff,
+        Direction::East => ut + diff,
+        Direction::North | Direction::South => {
+            return Err(
+                SowngwalaError::InvalidDirection,
+            )
+        }
+    };
+
+    Ok(lmt.rem_euclid(24.0))
+}
+
+/// Given UT, and retursn GST. `DateTime` of any
+/// time zone is accepted, and is converted into UTC
+/// first (see `utc_from_any`).
+///
+/// References:
+/// - (Peter Duffett-Smith, p.17)
+/// - sowngwala::time::gst_from_ut
+///
+/// Example:
+/// ```rust
+/// use chrono::{DateTime, Timelike};
+/// use chrono::naive::NaiveTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::time::{
+///     build_utc,
+///     gst_from_utc,
+/// };
+///
+/// let nanosecond: u32 = 670_000_000;
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, nanosecond);
+/// let gst: NaiveTime = gst_from_utc(utc);
+///
+/// assert_eq!(gst.hour(), 4);
+/// assert_eq!(gst.minute(), 40);
+/// assert_eq!(gst.second(), 5); // 5.229576759185761
+/// assert_eq!(gst.nanosecond(), 229_576_759);
+/// ```
+pub fn gst_from_utc<Tz: TimeZone>(
+    utc: DateTime<Tz>,
+) -> NaiveTime {
+    gst_from_utc_with_residual(utc_from_any(utc), 0.0)
+        .0
+}
+
+/// Given UT and the residual (in decimal hours) which
+/// `NaiveTime` cannot hold, returns GST and its
+/// residual. See `naive_time_with_residual`.
+///
+/// Example:
+/// ```rust
+/// use chrono::DateTime;
+/// use chrono::naive::NaiveTime;
+/// use chrono::offset::Utc;
+/// use sowngwala::time::{
+///     build_utc,
+///     gst_from_utc_with_residual,
+///     utc_from_gst_with_residual,
+/// };
+///
+/// let utc: DateTime<Utc> =
+///     build_utc(1980, 4, 22, 14, 36, 51, 670_000_001);
+///
+/// let (gst, residual): (NaiveTime, f64) =
+///     gst_from_utc_with_residual(utc, 0.0);
+/// let (t, residual): (NaiveTime, f64) =
+///     utc_from_gst_with_residual(
+///         utc.date_naive().and_time(gst),
+///         residual,
+///     );
+///
+/// assert_eq!(t, utc.time());
+/// assert!(residual.abs() < 1e-15);
+/// ```
+pub fn gst_from_utc_with_residual(
+    utc: DateTime<Utc>,
+    residual: f64,
+) -> (NaiveTime, f64) {
+    naive_time_with_residual(
+        _gst_decimal_from_utc_aux(utc, residual),
+    )
+}
+
+/// Same as `gst_from_utc`, but returns GST in
+/// decimal hours (0.0 to 24.0), as it is before
+/// turned into `NaiveTime`.
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use sowngwala::time::{build_utc, gst_decimal_from_utc};
+///
+/// let utc = build_utc(1980, 4, 22, 14, 36, 51, 670_000_000);
+///
+/// // 4h 40m 5.23s
+/// assert_approx_eq!(
+///     gst_decimal_from_utc(utc),
+///     4.668_119,
+///     1e-6
+/// );
+/// ```
+pub fn gst_decimal_from_utc<Tz: TimeZone>(
+    utc: DateTime<Tz>,
+) -> f64 {
+    _gst_decimal_from_utc_aux(utc_from_any(utc), 0.0)
+}
+
+// See `gst_from_utc_with_residual` for the specs.
+fn _gst_decimal_from_utc_aux(
+    utc: DateTime<Utc>,
+    residual: f64,
+) -> f64 {
+    // T0 is always based on JD at 0h UT of the date
+    // (JD ending with .5), and the time of the day is
+    // folded in below, scaled to sidereal hours.
+    let jd = julian_day_from_generic_date(
+        utc.date_naive(),
+    );
 
-    let s = jd - 2_451_545.0;
-    let t = s / 36_525.0;
+    let t = julian_centuries_since_j2000(jd);
     let t0 = 6.697_374_558
         + (2_400.051_336 * t)
         + (0.000_025_862 * t * t);
@@ -1225,15 +2567,91 @@ pub fn gst_from_utc(utc: DateTime<Utc>) -> NaiveTime {
     let naive_time =
         naive_time_from_generic_datetime(utc);
 
-    let mut decimal =
-        decimal_hours_from_generic_time(naive_time);
+    let mut decimal = decimal_hours_preserving(
+        naive_time, residual,
+    );
     decimal *= 1.002_737_909;
     decimal += t0;
 
     let (decimal, _factor): (f64, f64) =
         overflow(decimal, 24.0);
 
-    naive_time_from_decimal_hours(decimal)
+    // Into [0, 24) (before 0h of the day in GST)
+    if decimal < 0.0 {
+        decimal + 24.0
+    } else {
+        decimal
+    }
+}
+
+/// GST at 0h UT (T0) of a date, computed once so
+/// that sampling LST over the date (tracking an
+/// object over a night, or iterating for rise and
+/// set) needs only a multiply and an add per sample
+/// instead of the Julian day and the polynomial.
+///
+/// Reference:
+/// - (Peter Duffett-Smith, p.17)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::SiderealBaseline;
+///
+/// let baseline = SiderealBaseline::for_date(
+///     NaiveDate::from_ymd(1980, 4, 22),
+/// );
+///
+/// // 14h 36m 51.67s UT at 64°W
+/// let ut: f64 = 14.0 + (36.0 + 51.67 / 60.0) / 60.0;
+/// let lst: f64 = baseline.lst_at(ut, -64.0);
+///
+/// // 0h 24m 5.23s
+/// assert!((lst - 0.401_453).abs() < 1e-5);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct SiderealBaseline {
+    gmst0: f64,
+    date: NaiveDate,
+}
+
+impl SiderealBaseline {
+    pub fn for_date(date: NaiveDate) -> Self {
+        let jd = julian_day_from_generic_date(date);
+
+        let t = julian_centuries_since_j2000(jd);
+        let t0 = 6.697_374_558
+            + (2_400.051_336 * t)
+            + (0.000_025_862 * t * t);
+
+        let (gmst0, _factor) = overflow(t0, 24.0);
+
+        SiderealBaseline { gmst0, date }
+    }
+
+    /// GST at 0h UT in decimal hours.
+    pub fn gmst0(&self) -> f64 {
+        self.gmst0
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Given UT in decimal hours and the longitude
+    /// in degrees (positive for East), returns LST
+    /// in decimal hours.
+    pub fn lst_at(
+        &self,
+        ut_hours: f64,
+        lng_signed: f64,
+    ) -> f64 {
+        let mut lst: f64 = self.gmst0
+            + (ut_hours * 1.002_737_909)
+            + (lng_signed / 15.0);
+        lst -= 24.0 * (lst / 24.0).floor();
+        lst
+    }
 }
 
 /// Given GST, returns UTC.
@@ -1256,10 +2674,10 @@ pub fn gst_from_utc(utc: DateTime<Utc>) -> NaiveTime {
 /// let utc = utc_from_gst(gst);
 /// assert_eq!(utc.hour(), 14);
 /// assert_eq!(utc.minute(), 36);
-/// assert_eq!(utc.second(), 51); // 51.67040214530175
+/// assert_eq!(utc.second(), 51); // 51.670422085
 /// assert_eq!(
 ///     utc.nanosecond(),
-///     670_402_145
+///     670_422_085
 /// );
 /// ```
 #[allow(clippy::many_single_char_names)]
@@ -1268,35 +2686,99 @@ where
     T: Datelike,
     T: Timelike,
     T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
+{
+    utc_from_gst_with_residual(gst, 0.0).0
+}
+
+/// Given GST and the residual (in decimal hours) which
+/// `NaiveTime` cannot hold, returns UT and its
+/// residual. See `gst_from_utc_with_residual`.
+#[allow(clippy::many_single_char_names)]
+pub fn utc_from_gst_with_residual<T>(
+    gst: T,
+    residual: f64,
+) -> (NaiveTime, f64)
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    naive_time_with_residual(
+        _utc_decimal_from_gst_aux(gst, residual),
+    )
+}
+
+/// Same as `utc_from_gst`, but returns UT in decimal
+/// hours (0.0 to 24.0), as it is before turned into
+/// `NaiveTime`.
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::utc_decimal_from_gst;
+///
+/// let gst = NaiveDate::from_ymd(1980, 4, 22)
+///     .and_hms_nano(4, 40, 5, 230_000_000);
+///
+/// // 14h 36m 51.67s
+/// assert_approx_eq!(
+///     utc_decimal_from_gst(gst),
+///     14.614_353,
+///     1e-6
+/// );
+/// ```
+pub fn utc_decimal_from_gst<T>(gst: T) -> f64
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
+{
+    _utc_decimal_from_gst_aux(gst, 0.0)
+}
+
+// See `utc_from_gst_with_residual` for the specs.
+fn _utc_decimal_from_gst_aux<T>(
+    gst: T,
+    residual: f64,
+) -> f64
+where
+    T: Datelike,
+    T: Timelike,
+    T: std::marker::Copy,
 {
     // Luckily, we only need date, not datetime.
     let jd = julian_day_from_generic_date(gst);
 
-    let s = jd - 2_451_545.0;
-    let t = s / 36_525.0;
+    let t = julian_centuries_since_j2000(jd);
     let t0 = 6.697_374_558
         + (2_400.051_336 * t)
         + (0.000_025_862 * t * t);
     let (t0, _factor): (f64, f64) =
         overflow(t0, 24.0);
 
-    let decimal = decimal_hours_from_generic_time(
+    let decimal = decimal_hours_preserving(
         NaiveTime::from_hms_nano(
             gst.hour(),
             gst.minute(),
             gst.second(),
             gst.nanosecond(),
         ),
+        residual,
     );
 
-    let (decimal, _factor2): (f64, f64) =
+    let (mut decimal, _factor2): (f64, f64) =
         overflow(decimal - t0, 24.0);
 
-    naive_time_from_decimal_hours(
-        decimal * 0.997_269_566_3,
-    )
+    // GST earlier than T0 belongs to the same day.
+    if decimal < 0.0 {
+        decimal += 24.0;
+    }
+
+    // Dividing by the same ratio as `gst_from_utc`
+    // (the book multiplies by 0.9972695663, which
+    // drifts by some 30 μs in a day).
+    decimal / 1.002_737_909
 }
 
 /// Given GST and longitude, returns LST.
@@ -1334,30 +2816,159 @@ pub fn lst_from_gst<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
+{
+    naive_time_from_decimal_hours(
+        lst_decimal_from_gst(gst, lng, dir),
+    )
+}
+
+/// Same as `lst_from_gst`, but returns LST in decimal
+/// hours (0.0 to 24.0), as it is before turned into
+/// `NaiveTime`.
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::coords::Direction;
+/// use sowngwala::time::lst_decimal_from_gst;
+///
+/// let gst = NaiveDate::from_ymd(1980, 4, 22)
+///     .and_hms_nano(4, 40, 5, 230_000_000);
+///
+/// // 0h 24m 5.23s
+/// assert_approx_eq!(
+///     lst_decimal_from_gst(gst, 64.0, Direction::West),
+///     0.401_453,
+///     1e-6
+/// );
+/// ```
+pub fn lst_decimal_from_gst<T>(
+    gst: T,
+    lng: f64,
+    dir: Direction,
+) -> f64
+where
+    T: Datelike,
+    T: Timelike,
 {
     let decimal = decimal_hours_from_generic_time(
         naive_time_from_generic_datetime(gst),
     );
     let diff = lng / 15.0;
 
-    let mut lst = match dir {
+    let lst = match dir {
         Direction::West => decimal - diff,
         Direction::East => decimal + diff,
         _ => decimal,
     };
 
-    if lst > 24.0 {
-        lst -= 24.0;
-    };
+    // Into [0, 24) however far it goes
+    let (lst, _factor) = overflow(lst, 24.0);
 
     if lst < 0.0 {
-        lst += 24.0;
-    };
+        lst + 24.0
+    } else {
+        lst
+    }
+}
+
+/// Given UT and longitude, returns LST. GST is
+/// found for UT, and placed on the same date as UT
+/// (so that it holds across midnight) before turning
+/// it into LST. `DateTime` of any time zone is
+/// accepted, and is converted into UTC first.
+///
+/// * `utc` - UTC (or `DateTime` of any time zone)
+/// * `lng` - Longitude
+/// * `dir` - Direction for Longitude
+///
+/// Reference:
+/// - (Peter Duffett-Smith, pp.17-20)
+///
+/// Example:
+/// ```rust
+/// use chrono::Timelike;
+/// use chrono::naive::NaiveTime;
+/// use sowngwala::coords::Direction;
+/// use sowngwala::time::{build_utc, lst_from_utc};
+///
+/// // The LST used by `hour_angle_from_utc` (64° W)
+/// let utc = build_utc(1980, 4, 22, 14, 36, 51, 670_000_000);
+/// let lst: NaiveTime =
+///     lst_from_utc(utc, 64.0, Direction::West);
+///
+/// assert_eq!(lst.hour(), 0);
+/// assert_eq!(lst.minute(), 24);
+/// assert_eq!(lst.second(), 5); // 5.229576759
+/// ```
+pub fn lst_from_utc<Tz: TimeZone>(
+    utc: DateTime<Tz>,
+    lng: f64,
+    dir: Direction,
+) -> NaiveTime {
+    let utc: DateTime<Utc> = utc_from_any(utc);
+    let gst: NaiveTime = gst_from_utc(utc);
+    lst_from_gst(
+        utc.date_naive().and_time(gst),
+        lng,
+        dir,
+    )
+}
 
-    naive_time_from_decimal_hours(lst)
+/// Same as `lst_from_gst`, but takes the longitude
+/// as `DecimalDegrees` (positive for East), so that
+/// passing hours for degrees does not compile.
+///
+/// * `gst` - GST
+/// * `lng` - Longitude (positive for East)
+///
+/// Example:
+/// ```rust
+/// use std::convert::TryFrom;
+/// use chrono::Timelike;
+/// use chrono::naive::{NaiveDateTime, NaiveDate};
+/// use sowngwala::time::lst_from_gst_typed;
+/// use sowngwala::units::DecimalDegrees;
+///
+/// let lng = DecimalDegrees::try_from(-64.0).unwrap();
+/// let gst: NaiveDateTime =
+///     NaiveDate::from_ymd(1980, 4, 22)
+///         .and_hms_nano(4, 40, 5, 230_000_000);
+///
+/// let lst = lst_from_gst_typed(gst, lng);
+///
+/// assert_eq!(lst.hour(), 0);
+/// assert_eq!(lst.minute(), 24);
+/// assert_eq!(lst.second(), 5);
+/// ```
+///
+/// Longitude in hours does not compile:
+/// ```compile_fail
+/// use std::convert::TryFrom;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::time::lst_from_gst_typed;
+/// use sowngwala::units::DecimalHours;
+///
+/// let lng = DecimalHours::try_from(-64.0 / 15.0).unwrap();
+/// let gst = NaiveDate::from_ymd(1980, 4, 22).and_hms(4, 40, 5);
+///
+/// let lst = lst_from_gst_typed(gst, lng);
+/// ```
+pub fn lst_from_gst_typed<T>(
+    gst: T,
+    lng: DecimalDegrees,
+) -> NaiveTime
+where
+    T: Datelike,
+    T: Timelike,
+{
+    let lng: f64 = lng.into();
+    if lng < 0.0 {
+        lst_from_gst(gst, -lng, Direction::West)
+    } else {
+        lst_from_gst(gst, lng, Direction::East)
+    }
 }
 
 /// Given LST and longitude, returns GST.
@@ -1395,28 +3006,22 @@ pub fn gst_from_lst<T>(
 where
     T: Datelike,
     T: Timelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
     let decimal = decimal_hours_from_generic_time(
         naive_time_from_generic_datetime(lst),
     );
     let diff = lng / 15.0;
 
-    let mut gst = match dir {
+    let gst = match dir {
         Direction::West => decimal + diff,
         Direction::East => decimal - diff,
         _ => decimal,
     };
 
-    if gst > 24.0 {
-        gst -= 24.0;
-    };
-
-    if gst < 0.0 {
-        gst += 24.0;
-    };
+    // Into [0, 24) however far it goes
+    let (gst, _factor) = overflow(gst, 24.0);
+    let gst =
+        if gst < 0.0 { gst + 24.0 } else { gst };
 
     naive_time_from_decimal_hours(gst)
 }
@@ -1424,29 +3029,1443 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+
     use approx_eq::assert_approx_eq;
     use chrono::naive::{NaiveDate, NaiveDateTime};
+    use chrono::Duration;
     // use crate::time::julian_day_from_generic_datetime;
 
     #[test]
-    fn julian_day_for_marty_mcfly_goes_back() {
-        // On Saturday, October 26, 1985, 1:35 AM,
-        // Marty McFly goes back in time,
-        // and arrives at future Peabody Farm
-        // on Saturday, November 5, 1955, 6:15 am.
-        let datetime: NaiveDateTime =
-            NaiveDate::from_ymd(1985, 10, 26)
-                .and_hms(1, 35, 0);
+    fn decimal_hours_from_angle_takes_the_first_sign()
+    {
+        let expected: f64 = -8.045;
 
-        let jd: f64 =
-            julian_day_from_generic_datetime(
-                datetime,
+        for angle in [
+            Angle::new(-8, 2, 42.0),
+            Angle::new(-8, -2, -42.0),
+            Angle::new(-8, -2, 42.0),
+            Angle::with_sign(-1, 8, 2, 42.0),
+        ]
+        .iter()
+        {
+            let dec: f64 =
+                decimal_hours_from_angle(*angle);
+            assert!((dec - expected).abs() < 1e-12);
+        }
+
+        // Not -8 + 2/60 + 42/3600 (= -7.955)
+        assert!(
+            (decimal_hours_from_angle(Angle::new(
+                -8, 2, 42.0
+            )) + 7.955)
+                .abs()
+                > 0.01
+        );
+
+        // Sign on the minutes, or the seconds
+        assert!(
+            (decimal_hours_from_angle(Angle::new(
+                0, -2, 42.0
+            )) + 0.045)
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            (decimal_hours_from_angle(Angle::new(
+                0, 0, -36.0
+            )) + 0.01)
+                .abs()
+                < 1e-12
+        );
+
+        assert_eq!(
+            decimal_hours_from_angle(Angle::new(
+                0, 0, -0.0
+            )),
+            0.0
+        );
+
+        // Round trip with `angle_from_decimal_hours`
+        for &dec in
+            [-8.045, -0.045, -0.01, 8.045].iter()
+        {
+            let angle = angle_from_decimal_hours(dec);
+            assert!(
+                (decimal_hours_from_angle(angle)
+                    - dec)
+                    .abs()
+                    < 1e-12
             );
+        }
+    }
 
-        assert_approx_eq!(
-            jd, // 2446364.565972222
-            2446364.56597,
+    // US Eastern time on March 14, 2021, when the
+    // clocks jump from 2:00 to 3:00 (and -5h to -4h).
+    #[derive(Debug, Copy, Clone)]
+    struct SpringForward;
+
+    impl SpringForward {
+        fn gap() -> (NaiveDateTime, NaiveDateTime) {
+            let date =
+                NaiveDate::from_ymd(2021, 3, 14);
+            (
+                date.and_hms(2, 0, 0),
+                date.and_hms(3, 0, 0),
+            )
+        }
+    }
+
+    impl TimeZone for SpringForward {
+        type Offset = FixedOffset;
+
+        fn from_offset(
+            _offset: &FixedOffset,
+        ) -> Self {
+            SpringForward
+        }
+
+        fn offset_from_local_date(
+            &self,
+            local: &NaiveDate,
+        ) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(
+                &local.and_hms(0, 0, 0),
+            )
+        }
+
+        fn offset_from_local_datetime(
+            &self,
+            local: &NaiveDateTime,
+        ) -> LocalResult<FixedOffset> {
+            let (start, end) = SpringForward::gap();
+            if *local < start {
+                LocalResult::Single(
+                    FixedOffset::west(5 * 3600),
+                )
+            } else if *local < end {
+                LocalResult::None
+            } else {
+                LocalResult::Single(
+                    FixedOffset::west(4 * 3600),
+                )
+            }
+        }
+
+        fn offset_from_utc_date(
+            &self,
+            utc: &NaiveDate,
+        ) -> FixedOffset {
+            self.offset_from_utc_datetime(
+                &utc.and_hms(0, 0, 0),
+            )
+        }
+
+        fn offset_from_utc_datetime(
+            &self,
+            utc: &NaiveDateTime,
+        ) -> FixedOffset {
+            let (start, _end) = SpringForward::gap();
+            if *utc < start + Duration::hours(5) {
+                FixedOffset::west(5 * 3600)
+            } else {
+                FixedOffset::west(4 * 3600)
+            }
+        }
+    }
+
+    // Implements only `Datelike` and `Timelike` (no
+    // `Copy`, `Debug`, nor `Display`).
+    struct Bare(NaiveDateTime);
+
+    macro_rules! bare_with {
+        ($($name:ident: $t:ty),*) => {
+            $(
+                fn $name(&self, v: $t) -> Option<Self> {
+                    self.0.$name(v).map(Bare)
+                }
+            )*
+        };
+    }
+
+    impl Datelike for Bare {
+        fn year(&self) -> i32 {
+            self.0.year()
+        }
+        fn month(&self) -> u32 {
+            self.0.month()
+        }
+        fn month0(&self) -> u32 {
+            self.0.month0()
+        }
+        fn day(&self) -> u32 {
+            self.0.day()
+        }
+        fn day0(&self) -> u32 {
+            self.0.day0()
+        }
+        fn ordinal(&self) -> u32 {
+            self.0.ordinal()
+        }
+        fn ordinal0(&self) -> u32 {
+            self.0.ordinal0()
+        }
+        fn weekday(&self) -> chrono::Weekday {
+            self.0.weekday()
+        }
+        fn iso_week(&self) -> chrono::IsoWeek {
+            self.0.iso_week()
+        }
+        bare_with!(
+            with_year: i32,
+            with_month: u32,
+            with_month0: u32,
+            with_day: u32,
+            with_day0: u32,
+            with_ordinal: u32,
+            with_ordinal0: u32
+        );
+    }
+
+    impl Timelike for Bare {
+        fn hour(&self) -> u32 {
+            self.0.hour()
+        }
+        fn minute(&self) -> u32 {
+            self.0.minute()
+        }
+        fn second(&self) -> u32 {
+            self.0.second()
+        }
+        fn nanosecond(&self) -> u32 {
+            self.0.nanosecond()
+        }
+        bare_with!(
+            with_hour: u32,
+            with_minute: u32,
+            with_second: u32,
+            with_nanosecond: u32
+        );
+    }
+
+    #[test]
+    fn generic_functions_take_bare_datelike_and_timelike(
+    ) {
+        let dt = NaiveDate::from_ymd(1985, 2, 17)
+            .and_hms(6, 0, 0);
+        let bare = || Bare(dt);
+
+        assert_eq!(
+            julian_day_from_generic_datetime(bare()),
+            julian_day_from_generic_datetime(dt)
+        );
+        assert_eq!(
+            j2000_from_generic_datetime(bare()),
+            j2000_from_generic_datetime(dt)
+        );
+        assert_eq!(
+            modified_julian_day_from_generic_datetime(
+                bare()
+            ),
+            modified_julian_day_from_generic_datetime(
+                dt
+            )
+        );
+        assert_eq!(
+            decimal_days_from_generic_datetime(bare()),
+            17.25
+        );
+        assert_eq!(
+            julian_day_from_generic_date(bare()),
+            julian_day_from_generic_date(dt)
+        );
+        assert_eq!(
+            day_number_from_generic_date(bare()),
+            day_number_from_generic_date(dt)
+        );
+        assert_eq!(
+            decimal_year_from_generic_date(bare()),
+            decimal_year_from_generic_date(dt)
+        );
+        assert_eq!(
+            decimal_hours_from_generic_time(bare()),
+            6.0
+        );
+        assert_eq!(
+            naive_date_from_generic_datetime(bare()),
+            dt.date()
+        );
+        assert_eq!(
+            naive_time_from_generic_datetime(bare()),
+            dt.time()
+        );
+        assert!(!is_julian_date(bare()));
+        assert_eq!(
+            lst_from_gst(
+                bare(),
+                64.0,
+                Direction::West
+            ),
+            lst_from_gst(dt, 64.0, Direction::West)
+        );
+        assert_eq!(
+            crate::utils::mean_obliquity_of_the_epliptic(
+                bare()
+            ),
+            crate::utils::mean_obliquity_of_the_epliptic(dt)
+        );
+        assert_eq!(
+            crate::delta_t::delta_t_from_generic_date(
+                bare()
+            ),
+            crate::delta_t::delta_t_from_generic_date(
+                dt
+            )
+        );
+    }
+
+    #[test]
+    fn weekday_of_1985_02_17_is_sunday() {
+        let date = NaiveDate::from_ymd(1985, 2, 17);
+        assert_eq!(
+            weekday_from_generic_date(date),
+            Weekday::Sun
+        );
+        assert_eq!(day_of_the_week(date), 1);
+
+        // Through the day, and across the ages
+        for &(jd, y, m, d) in [
+            (2_446_113.5, 1985, 2, 17),
+            (2_446_113.99, 1985, 2, 17),
+            (2_451_544.5, 2000, 1, 1),
+            (2_299_160.5, 1582, 10, 15),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                weekday_from_julian_day(jd),
+                NaiveDate::from_ymd(y, m, d)
+                    .weekday()
+            );
+        }
+        // Thursday, 1582-10-04 (Julian calendar)
+        assert_eq!(
+            weekday_from_julian_day(2_299_159.5),
+            Weekday::Thu
+        );
+    }
+
+    #[test]
+    fn naive_from_julian_day_keeps_nanoseconds() {
+        let naive =
+            naive_from_julian_day(2_446_113.75);
+        assert_eq!(
+            naive,
+            NaiveDate::from_ymd(1985, 2, 17)
+                .and_hms_nano(6, 0, 0, 0)
+        );
+
+        // Fractions exact in binary come back to the
+        // nanosecond (1/1024 day is 84.375 seconds).
+        let naive = naive_from_julian_day(
+            2_446_113.5 + (1.0 / 1024.0),
+        );
+        assert_eq!(
+            naive,
+            NaiveDate::from_ymd(1985, 2, 17)
+                .and_hms_nano(0, 1, 24, 375_000_000)
+        );
+    }
+
+    #[test]
+    fn julian_day_and_naive_round_trip() {
+        for &(y, m, d, h, min, sec, nano) in [
+            (1985, 2, 17, 6, 0, 0, 0),
+            (2000, 1, 1, 12, 0, 0, 0),
+            (2021, 12, 31, 23, 59, 59, 999_000_000),
+            (1980, 4, 22, 14, 36, 51, 670_000_000),
+            (1600, 3, 1, 0, 0, 0, 1),
+        ]
+        .iter()
+        {
+            let dt = NaiveDate::from_ymd(y, m, d)
+                .and_hms_nano(h, min, sec, nano);
+            let jd =
+                julian_day_from_generic_datetime(dt);
+            let dt_1 = naive_from_julian_day(jd);
+
+            // Within what `f64` holds for the Julian Day.
+            let diff = (dt_1 - dt)
+                .num_nanoseconds()
+                .unwrap();
+            assert!(
+                diff.abs() < 50_000,
+                "{} {}",
+                dt,
+                dt_1
+            );
+        }
+    }
+
+    #[test]
+    fn julian_and_proleptic_gregorian_differ_before_1582(
+    ) {
+        // 10 days apart from March 1, 1500 (the
+        // Julian 1500 is a leap year) to the reform.
+        for &(y, m, d) in [
+            (1500, 3, 1.0),
+            (1582, 1, 1.0),
+            (1582, 10, 4.5),
+        ]
+        .iter()
+        {
+            let julian = julian_day_with_calendar(
+                y,
+                m,
+                d,
+                Calendar::Julian,
+            );
+            let gregorian = julian_day_with_calendar(
+                y,
+                m,
+                d,
+                Calendar::Gregorian,
+            );
+            assert_eq!(julian - gregorian, 10.0);
+            assert_eq!(
+                julian_day_with_calendar(
+                    y,
+                    m,
+                    d,
+                    Calendar::Auto
+                ),
+                julian
+            );
+        }
+
+        // Julian 1582-10-04 was followed by Gregorian
+        // 1582-10-15.
+        assert_eq!(
+            julian_day_with_calendar(
+                1582,
+                10,
+                4.0,
+                Calendar::Julian
+            ) + 1.0,
+            julian_day_with_calendar(
+                1582,
+                10,
+                15.0,
+                Calendar::Gregorian
+            )
+        );
+
+        // They agree with `Auto` after the reform.
+        assert_eq!(
+            julian_day_with_calendar(
+                2000,
+                1,
+                1.5,
+                Calendar::Gregorian
+            ),
+            julian_day_with_calendar(
+                2000,
+                1,
+                1.5,
+                Calendar::Auto
+            )
+        );
+    }
+
+    #[test]
+    fn julian_day_at_the_calendar_reform() {
+        // Fractions of the last Julian day stay in
+        // the Julian calendar, and run continuously
+        // into the first Gregorian day.
+        assert_eq!(
+            julian_day(1582, 10, 4.0),
+            2_299_159.5
+        );
+        assert_approx_eq!(
+            julian_day(1582, 10, 4.9),
+            2_299_160.4,
+            1e-12
+        );
+        assert_approx_eq!(
+            julian_day(1582, 10, 4.999),
+            2_299_160.499,
+            1e-12
+        );
+        assert_eq!(
+            julian_day(1582, 10, 15.0),
+            2_299_160.5
+        );
+        assert_eq!(
+            julian_day(1582, 10, 15.5),
+            2_299_161.0
+        );
+
+        // The end of the month
+        assert_approx_eq!(
+            julian_day(1582, 9, 30.9),
+            2_299_156.4,
+            1e-12
+        );
+        assert_eq!(
+            julian_day(1582, 10, 1.0),
+            2_299_156.5
+        );
+        assert_eq!(
+            julian_day(1582, 10, 31.0)
+                - julian_day(1582, 10, 30.0),
+            1.0
+        );
+
+        assert_eq!(
+            julian_day_from_generic_date(
+                NaiveDate::from_ymd(1582, 10, 15)
+            ),
+            2_299_160.5
+        );
+        assert_eq!(
+            julian_day_from_generic_datetime(
+                NaiveDate::from_ymd(1582, 10, 15)
+                    .and_hms(12, 0, 0)
+            ),
+            2_299_161.0
+        );
+    }
+
+    #[test]
+    fn julian_day_matches_the_reference_values() {
+        // Duffett-Smith, p.7
+        assert_eq!(
+            julian_day(1985, 2, 17.25),
+            2_446_113.75
+        );
+        // Meeus, Example 7.a and 7.b
+        assert_approx_eq!(
+            julian_day(1957, 10, 4.81),
+            2_436_116.31,
+            1e-12
+        );
+        assert_eq!(
+            julian_day(333, 1, 27.5),
+            1_842_713.0
+        );
+        // J2000.0
+        assert_eq!(
+            julian_day(2000, 1, 1.5),
+            2_451_545.0
+        );
+        // Meeus, p.62
+        assert_eq!(
+            julian_day(1600, 1, 1.0),
+            2_305_447.5
+        );
+        assert_eq!(
+            julian_day(837, 4, 10.3),
+            2_026_871.8
+        );
+    }
+
+    #[test]
+    fn tt_rolls_over_to_the_next_day() {
+        // ΔT in 1900 is about -2.7 seconds, and in
+        // 1600 is about 120 seconds.
+        let ut = NaiveDate::from_ymd(1600, 12, 31)
+            .and_hms(23, 59, 30);
+        let tt = tt_from_ut(ut);
+        assert_eq!(
+            tt.date(),
+            NaiveDate::from_ymd(1601, 1, 1)
+        );
+        assert_eq!(tt.hour(), 0);
+        assert_eq!(tt.minute(), 1);
+        assert_eq!(ut_from_tt(tt), ut);
+
+        // Negative ΔT goes back to the day before.
+        let ut = NaiveDate::from_ymd(1900, 1, 1)
+            .and_hms_nano(0, 0, 1, 500_000_000);
+        let tt = tt_from_ut(ut);
+        assert_eq!(
+            tt.date(),
+            NaiveDate::from_ymd(1899, 12, 31)
+        );
+        assert_eq!(ut_from_tt(tt), ut);
+    }
+
+    #[test]
+    fn local_time_in_dst_gap_is_an_error() {
+        let date = NaiveDate::from_ymd(2021, 3, 14);
+
+        let naive: NaiveDateTime =
+            date.and_hms(2, 30, 0);
+        assert_eq!(
+            try_local_from_naive(
+                naive,
+                &SpringForward
+            ),
+            Err(TimeError::NonexistentLocalTime(
+                naive
+            ))
+        );
+
+        let naive: NaiveDateTime =
+            date.and_hms(3, 30, 0);
+        let local = try_local_from_naive(
+            naive,
+            &SpringForward,
+        )
+        .unwrap();
+        assert_eq!(
+            local.naive_utc(),
+            date.and_hms(7, 30, 0)
+        );
+    }
+
+    #[test]
+    fn lst_and_gst_stay_within_a_day_for_large_longitudes(
+    ) {
+        let date = NaiveDate::from_ymd(1980, 4, 22);
+
+        for &(h, m) in
+            [(0, 0), (0, 30), (12, 0), (23, 59)]
+                .iter()
+        {
+            let t: NaiveDateTime =
+                date.and_hms(h, m, 0);
+            let decimal: f64 =
+                h as f64 + (m as f64 / 60.0);
+
+            for &lng in [359.0, 359.9, 719.0].iter() {
+                for &east in [true, false].iter() {
+                    let dir = || {
+                        if east {
+                            Direction::East
+                        } else {
+                            Direction::West
+                        }
+                    };
+                    let sign: f64 =
+                        if east { 1.0 } else { -1.0 };
+
+                    let mut expected: f64 =
+                        decimal + sign * lng / 15.0;
+                    expected -= 24.0
+                        * (expected / 24.0).floor();
+
+                    let lst: f64 =
+                        decimal_hours_from_naive_time(
+                            lst_from_gst(
+                                t,
+                                lng,
+                                dir(),
+                            ),
+                        );
+                    assert!(
+                        (0.0..24.0).contains(&lst)
+                    );
+                    assert!(
+                        (lst - expected).abs() < 1e-6
+                    );
+
+                    let gst: f64 = decimal_hours_from_naive_time(
+                        gst_from_lst(
+                            date.and_time(
+                                naive_time_from_decimal_hours(lst),
+                            ),
+                            lng,
+                            dir(),
+                        ),
+                    );
+                    let mut diff: f64 =
+                        (gst - decimal).abs();
+                    if diff > 12.0 {
+                        diff = 24.0 - diff;
+                    }
+                    assert!(
+                        (0.0..24.0).contains(&gst)
+                    );
+                    assert!(
+                        diff < 1e-6,
+                        "{} {} {}",
+                        lng,
+                        gst,
+                        decimal
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sidereal_baseline_matches_gst_and_lst() {
+        let date = NaiveDate::from_ymd(1980, 4, 22);
+        let baseline =
+            SiderealBaseline::for_date(date);
+
+        for &(h, m, lng) in [
+            (0, 0, 0.0),
+            (3, 15, -64.0),
+            (9, 42, 139.7),
+            (14, 36, -64.0),
+            (23, 59, 179.5),
+        ]
+        .iter()
+        {
+            let utc: DateTime<Utc> =
+                build_utc(1980, 4, 22, h, m, 0, 0);
+            let gst: NaiveTime = gst_from_utc(utc);
+            let (lng_abs, dir) = if lng < 0.0 {
+                (-lng, Direction::West)
+            } else {
+                (lng, Direction::East)
+            };
+            let lst_0: f64 =
+                decimal_hours_from_naive_time(
+                    lst_from_gst(
+                        date.and_time(gst),
+                        lng_abs,
+                        dir,
+                    ),
+                );
+
+            let ut: f64 =
+                h as f64 + (m as f64 / 60.0);
+            let lst: f64 = baseline.lst_at(ut, lng);
+
+            let mut diff: f64 = (lst - lst_0).abs();
+            if diff > 12.0 {
+                diff = 24.0 - diff;
+            }
+            assert!(
+                diff < 1e-9,
+                "{}:{} {} vs {}",
+                h,
+                m,
+                lst,
+                lst_0
+            );
+        }
+    }
+
+    #[test]
+    fn julian_day_for_marty_mcfly_goes_back() {
+        // On Saturday, October 26, 1985, 1:35 AM,
+        // Marty McFly goes back in time,
+        // and arrives at future Peabody Farm
+        // on Saturday, November 5, 1955, 6:15 am.
+        let datetime: NaiveDateTime =
+            NaiveDate::from_ymd(1985, 10, 26)
+                .and_hms(1, 35, 0);
+
+        let jd: f64 =
+            julian_day_from_generic_datetime(
+                datetime,
+            );
+
+        assert_approx_eq!(
+            jd, // 2446364.565972222
+            2446364.56597,
             1e-11
         );
     }
+
+    #[test]
+    fn calibrate_dms_carries_over_at_360_degrees() {
+        assert_eq!(
+            calibrate_dms(359, 59, 61.0),
+            ((0, 0, 1.0), 1.0)
+        );
+        assert_eq!(
+            calibrate_dms(359, 59, 59.0),
+            ((359, 59, 59.0), 0.0)
+        );
+        assert_eq!(
+            calibrate_dms(360, 0, 0.0),
+            ((0, 0, 0.0), 1.0)
+        );
+        assert_eq!(
+            calibrate_dms(720, 0, 0.0),
+            ((0, 0, 0.0), 2.0)
+        );
+        assert_eq!(
+            calibrate_dms(45, 59, 60.0),
+            ((46, 0, 0.0), 0.0)
+        );
+        assert_eq!(
+            calibrate_dms(0, 0, -1.0),
+            ((359, 59, 59.0), -1.0)
+        );
+        assert_eq!(
+            calibrate_dms(-1, 0, 0.0),
+            ((359, 0, 0.0), -1.0)
+        );
+
+        // Hours carry over at 24 for the same input.
+        assert_eq!(
+            calibrate_hmsn(359, 59, 61.0),
+            ((0, 0, 1.0), 15.0)
+        );
+    }
+
+    #[test]
+    fn julian_day_from_naive_matches_the_day_fraction(
+    ) {
+        let cases: [(i32, u32, u32, u32, u32, u32);
+            5] = [
+            (1985, 10, 26, 1, 35, 0),
+            (1985, 2, 17, 6, 0, 0),
+            (2000, 1, 1, 12, 0, 0),
+            (1582, 10, 4, 23, 59, 59),
+            (-4712, 1, 1, 12, 0, 0),
+        ];
+
+        for &(y, mo, d, h, mi, s) in cases.iter() {
+            let dt = NaiveDate::from_ymd(y, mo, d)
+                .and_hms(h, mi, s);
+            let day: f64 = d as f64
+                + (h * 3600 + mi * 60 + s) as f64
+                    / 86_400.0;
+
+            assert_approx_eq!(
+                julian_day_from_naive(dt),
+                julian_day(y, mo, day),
+                1e-12
+            );
+            assert_eq!(
+                julian_day_from_naive(dt),
+                julian_day_from_generic_datetime(dt)
+            );
+        }
+
+        assert_eq!(
+            julian_day_from_naive(
+                NaiveDate::from_ymd(-4712, 1, 1)
+                    .and_hms(12, 0, 0)
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn sidereal_round_trip_to_the_nanosecond() {
+        let date = NaiveDate::from_ymd(1980, 4, 22);
+
+        // GST at 0h UT (T0)
+        let t0: f64 = decimal_hours_from_naive_time(
+            gst_from_utc(DateTime::<Utc>::from_utc(
+                date.and_hms(0, 0, 0),
+                Utc,
+            )),
+        );
+
+        let mut old_loss: i64 = 0;
+
+        // Up to 23h 50m (UT of the last 4 minutes share
+        // GST with the first 4 minutes of the day).
+        for k in 0..1430_i64 {
+            let naive: NaiveDateTime = date
+                .and_hms(0, 0, 0)
+                + Duration::nanoseconds(
+                    k * 60_000_000_007 + 123,
+                );
+            let utc =
+                DateTime::<Utc>::from_utc(naive, Utc);
+            let gst: NaiveTime = gst_from_utc(utc);
+
+            // The book's ratio, and the nanoseconds
+            // dropped through `Angle`.
+            let (dec, _): (f64, f64) = overflow(
+                decimal_hours_from_naive_time(gst)
+                    - t0
+                    + 24.0,
+                24.0,
+            );
+            let old: NaiveTime =
+                naive_time_from_decimal_hours(
+                    dec * 0.997_269_566_3,
+                );
+            old_loss = old_loss.max(
+                (old - naive.time())
+                    .num_nanoseconds()
+                    .unwrap()
+                    .abs(),
+            );
+
+            let diff: i64 =
+                (utc_from_gst(date.and_time(gst))
+                    - naive.time())
+                .num_nanoseconds()
+                .unwrap();
+            assert!(
+                diff.abs() <= 1,
+                "{}: {}",
+                naive,
+                diff
+            );
+
+            let (gst, residual) =
+                gst_from_utc_with_residual(utc, 0.0);
+            let (t, _) = utc_from_gst_with_residual(
+                date.and_time(gst),
+                residual,
+            );
+            assert_eq!(t, naive.time());
+        }
+
+        // Some 30 microseconds by the end of the day
+        assert!(old_loss > 20_000, "{}", old_loss);
+    }
+
+    #[test]
+    fn utc_from_gst_earlier_than_gst_at_0h() {
+        // GST at 0h UT on 2022-06-21 is about 17h 56m,
+        // and GST of 14h 20m comes at about 20h 21m UT.
+        let gst: NaiveDateTime =
+            NaiveDate::from_ymd(2022, 6, 21)
+                .and_hms(14, 20, 52);
+        let utc: NaiveTime = utc_from_gst(gst);
+        let gst_1: NaiveTime =
+            gst_from_utc(DateTime::<Utc>::from_utc(
+                NaiveDate::from_ymd(2022, 6, 21)
+                    .and_time(utc),
+                Utc,
+            ));
+
+        assert_eq!(utc.hour(), 20);
+        assert_approx_eq!(
+            decimal_hours_from_naive_time(gst_1),
+            decimal_hours_from_naive_time(gst.time()),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn days_since_1990_around_leap_years() {
+        assert_eq!(days_since_1990(1990), 0);
+        assert_eq!(days_since_1990(1991), 365);
+        assert_eq!(days_since_1990(1992), 730);
+        assert_eq!(days_since_1990(1993), 1096);
+        assert_eq!(days_since_1990(1989), -365);
+        assert_eq!(days_since_1990(1988), -731);
+
+        // The same as the difference in Julian Day
+        // for Jan 0th.
+        for year in
+            [1900, 1970, 2000, 2020, 2021, 2024]
+        {
+            let jd = julian_day(year, 1, 0.0)
+                - julian_day(1990, 1, 0.0);
+            assert_eq!(
+                days_since_1990(year) as f64,
+                jd,
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn september_1752_under_british_reform() {
+        let reform = CalendarReform::british();
+
+        // 1752-09-02 (Julian) was followed by
+        // 1752-09-14 (Gregorian) in Britain.
+        let jd_0 = julian_day_with_reform(
+            1752, 9, 2.0, reform,
+        );
+        let jd_1 = julian_day_with_reform(
+            1752, 9, 14.0, reform,
+        );
+        assert_eq!(jd_1 - jd_0, 1.0);
+
+        for jd in [jd_0, jd_1] {
+            let naive: NaiveDateTime =
+                naive_from_julian_day_with_reform(
+                    jd, reform,
+                )
+                .unwrap();
+            assert_eq!(
+                julian_day_with_reform(
+                    naive.year(),
+                    naive.month(),
+                    naive.day() as f64,
+                    reform,
+                ),
+                jd
+            );
+        }
+    }
+
+    #[test]
+    fn julian_only_leap_days_are_errors() {
+        let cases = [
+            (CalendarReform::british(), 1700),
+            (CalendarReform::gregorian_1582(), 1500),
+        ];
+
+        for (reform, year) in cases {
+            let jd = julian_day_with_reform(
+                year, 2, 29.0, reform,
+            );
+            assert_eq!(
+                naive_from_julian_day_with_reform(
+                    jd, reform
+                ),
+                Err(TimeError::UnrepresentableDate(
+                    year, 2, 29
+                ))
+            );
+
+            // The days around it are still there.
+            for (jd, month, day) in
+                [(jd - 1.0, 2, 28), (jd + 1.0, 3, 1)]
+            {
+                let naive: NaiveDateTime =
+                    naive_from_julian_day_with_reform(
+                        jd, reform,
+                    )
+                    .unwrap();
+                assert_eq!(
+                    (
+                        naive.year(),
+                        naive.month(),
+                        naive.day()
+                    ),
+                    (year, month, day)
+                );
+            }
+        }
+
+        // 1600 is a leap year either way.
+        let reform = CalendarReform::british();
+        let jd = julian_day_with_reform(
+            1600, 2, 29.0, reform,
+        );
+        let naive: NaiveDateTime =
+            naive_from_julian_day_with_reform(
+                jd, reform,
+            )
+            .unwrap();
+        assert_eq!(
+            (naive.month(), naive.day()),
+            (2, 29)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "1500-02-29")]
+    fn naive_from_julian_day_panics_on_julian_only_leap_day(
+    ) {
+        naive_from_julian_day(julian_day(
+            1500, 2, 29.0,
+        ));
+    }
+
+    #[test]
+    fn negative_decimal_hours_round_trip_through_angle(
+    ) {
+        for i in 1..=20_000 {
+            for scale in [1e-5, 1e-3, 1e-1] {
+                let dec: f64 = -(i as f64) * scale;
+                let angle: Angle =
+                    angle_from_decimal_hours(dec);
+
+                // The sign is stored once, with
+                // the magnitudes.
+                assert_eq!(
+                    angle.sign(),
+                    -1,
+                    "{:?}",
+                    angle
+                );
+                assert!(
+                    angle.hour >= 0
+                        && angle.minute >= 0
+                        && angle.second >= 0.0,
+                    "{:?}",
+                    angle
+                );
+
+                // Only the first nonzero
+                // component shows the sign.
+                let negatives = [
+                    angle.hour() < 0,
+                    angle.minute() < 0,
+                    angle.second() < 0.0,
+                ];
+                assert_eq!(
+                    negatives
+                        .iter()
+                        .filter(|n| **n)
+                        .count(),
+                    1,
+                    "{:?}",
+                    angle
+                );
+
+                assert_approx_eq!(
+                    decimal_hours_from_angle(angle),
+                    dec,
+                    1e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fraction_of_year_from_jan_1st_to_dec_31st() {
+        // The start of Jan 1st
+        let dt: NaiveDateTime =
+            NaiveDate::from_ymd(2021, 1, 1)
+                .and_hms(0, 0, 0);
+        assert_eq!(fraction_of_year(dt), 0.0);
+
+        // July 2nd of a non-leap year is the middle.
+        let dt: NaiveDateTime =
+            NaiveDate::from_ymd(2021, 7, 2)
+                .and_hms(0, 0, 0);
+        assert_approx_eq!(
+            fraction_of_year(dt),
+            182.0 / 365.0,
+            1e-12
+        );
+        assert!(
+            (fraction_of_year(dt) - 0.5).abs()
+                < 0.002
+        );
+
+        // The end of Dec 31st (in leap and non-leap years)
+        for &year in [2020, 2021].iter() {
+            let dt: NaiveDateTime =
+                NaiveDate::from_ymd(year, 12, 31)
+                    .and_hms(23, 59, 59);
+            let fraction: f64 = fraction_of_year(dt);
+            assert!(fraction < 1.0);
+            assert!(fraction > 0.99998);
+        }
+    }
+
+    #[test]
+    fn sidereal_time_from_any_time_zone() {
+        // 1980-04-22 14:36:51.67 (UT) seen from +09:00,
+        // as `Local::now()` would give in Tokyo
+        let utc: DateTime<Utc> = build_utc(
+            1980,
+            4,
+            22,
+            14,
+            36,
+            51,
+            670_000_000,
+        );
+        let local: DateTime<FixedOffset> =
+            FixedOffset::east(9 * 3600)
+                .from_local_datetime(
+                    &NaiveDate::from_ymd(1980, 4, 22)
+                        .and_hms_nano(
+                            23,
+                            36,
+                            51,
+                            670_000_000,
+                        ),
+                )
+                .unwrap();
+
+        assert_eq!(utc_from_any(local), utc);
+        assert_eq!(
+            gst_from_utc(local),
+            gst_from_utc(utc)
+        );
+        assert_eq!(
+            lst_from_utc(
+                local,
+                64.0,
+                Direction::West
+            ),
+            lst_from_utc(utc, 64.0, Direction::West)
+        );
+    }
+
+    #[test]
+    fn fixed_from_naive_clamps_the_zone() {
+        use crate::error::SowngwalaError;
+
+        let naive: NaiveDateTime =
+            NaiveDate::from_ymd(2021, 1, 1)
+                .and_hms(22, 37, 0);
+
+        let err: SowngwalaError =
+            try_fixed_from_naive(naive, 25)
+                .unwrap_err()
+                .into();
+        assert_eq!(
+            err,
+            SowngwalaError::Time(
+                TimeError::InvalidZone(25)
+            )
+        );
+
+        // Clamped to 23 (instead of panicking)
+        let fixed = fixed_from_naive(naive, 25);
+        assert_eq!(
+            fixed,
+            fixed_from_naive(naive, 23)
+        );
+        assert_eq!(fixed.naive_local(), naive);
+        assert_eq!(
+            fixed_from_naive(naive, -30)
+                .offset()
+                .local_minus_utc(),
+            -23 * 3600
+        );
+    }
+
+    #[test]
+    fn decimal_sidereal_times_match_naive_times() {
+        let close =
+            |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        for hour in 0..24 {
+            let utc: DateTime<Utc> = build_utc(
+                1980,
+                4,
+                22,
+                hour,
+                36,
+                51,
+                670_000_000,
+            );
+            assert!(close(
+                gst_decimal_from_utc(utc),
+                decimal_hours_from_naive_time(
+                    gst_from_utc(utc)
+                )
+            ));
+
+            let gst: NaiveDateTime =
+                NaiveDate::from_ymd(1980, 4, 22)
+                    .and_hms_nano(
+                        hour,
+                        40,
+                        5,
+                        230_000_000,
+                    );
+            assert!(close(
+                utc_decimal_from_gst(gst),
+                decimal_hours_from_naive_time(
+                    utc_from_gst(gst)
+                )
+            ));
+
+            // (`Direction` is not `Clone`)
+            let dir = |west: bool| {
+                if west {
+                    Direction::West
+                } else {
+                    Direction::East
+                }
+            };
+            for &west in [true, false].iter() {
+                assert!(close(
+                    lst_decimal_from_gst(
+                        gst,
+                        64.0,
+                        dir(west)
+                    ),
+                    decimal_hours_from_naive_time(
+                        lst_from_gst(
+                            gst,
+                            64.0,
+                            dir(west)
+                        )
+                    )
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn day_difference_over_leap_days_and_the_reform()
+    {
+        // Over a leap day
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(2020, 2, 28),
+                NaiveDate::from_ymd(2020, 3, 1),
+            ),
+            2
+        );
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(2021, 2, 28),
+                NaiveDate::from_ymd(2021, 3, 1),
+            ),
+            1
+        );
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(2000, 1, 1),
+                NaiveDate::from_ymd(2001, 1, 1),
+            ),
+            366
+        );
+
+        // Over the calendar reform in 1582, October 4th
+        // (Julian) was followed by October 15th
+        // (Gregorian).
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(1582, 10, 4),
+                NaiveDate::from_ymd(1582, 10, 15),
+            ),
+            1
+        );
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(1582, 10, 15),
+                NaiveDate::from_ymd(1582, 10, 4),
+            ),
+            -1
+        );
+
+        // Agrees with the day numbers since 1990
+        let date = NaiveDate::from_ymd(1988, 7, 27);
+        assert_eq!(
+            day_difference(
+                NaiveDate::from_ymd(1990, 1, 1),
+                date
+            ) + 1,
+            (days_since_1990(1988)
+                + day_number_from_generic_date(date)
+                    as i32) as i64
+        );
+    }
+
+    #[test]
+    fn days_since_epoch_1990_is_the_legacy() {
+        for year in 1800..2200 {
+            assert_eq!(
+                days_since_epoch(year, 1990),
+                days_since_1990(year)
+            );
+        }
+
+        // The same span either way
+        assert_eq!(
+            days_since_epoch(2000, 1990),
+            3652
+        );
+        assert_eq!(
+            days_since_epoch(1990, 2000),
+            -3652
+        );
+        assert_eq!(days_since_epoch(2000, 2000), 0);
+    }
+
+    #[test]
+    fn is_leap_year_edge_cases() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+
+        // Year 0 (1 BC) and negative years
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-1));
+        assert!(!is_leap_year(-3));
+        assert!(!is_leap_year(-100));
+        assert!(!is_leap_year(-1900));
+        assert!(is_leap_year(-400));
+    }
+
+    #[test]
+    fn is_leap_year_agrees_with_chrono() {
+        for year in -4000..4000 {
+            assert_eq!(
+                is_leap_year(year),
+                NaiveDate::from_ymd_opt(year, 2, 29)
+                    .is_some(),
+                "year {}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn gst_from_utc_uses_jd_at_0h_ut() {
+        let utc =
+            build_utc(1980, 4, 22, 14, 36, 51, 0);
+        let jd = julian_day_from_generic_date(utc);
+
+        assert_eq!(jd.fract(), 0.5);
+
+        // 4h 40m 5.23s
+        let gst = gst_from_utc(build_utc(
+            1980,
+            4,
+            22,
+            14,
+            36,
+            51,
+            670_000_000,
+        ));
+        assert_eq!(gst.hour(), 4);
+        assert_eq!(gst.minute(), 40);
+        assert_eq!(gst.second(), 5);
+    }
+
+    #[test]
+    fn gst_from_utc_is_continuous_across_midnight() {
+        let before: f64 = gst_decimal_from_utc(
+            build_utc(1980, 4, 22, 23, 59, 59, 0),
+        );
+        let after: f64 = gst_decimal_from_utc(
+            build_utc(1980, 4, 23, 0, 0, 1, 0),
+        );
+
+        // 2 seconds in UT are 2 * 1.002_737_909
+        // seconds in GST.
+        let mut diff: f64 = after - before;
+        diff -= 24.0 * (diff / 24.0).round();
+
+        assert_approx_eq!(
+            diff * 3_600.0,
+            2.0 * 1.002_737_909,
+            1e-3
+        );
+    }
+
+    #[test]
+    fn julian_centuries_at_and_after_j2000() {
+        assert_eq!(
+            julian_centuries_since_j2000(J2000),
+            0.0
+        );
+        assert_eq!(
+            julian_centuries_since_j2000(
+                J2000 + 36_525.0
+            ),
+            1.0
+        );
+        assert_eq!(
+            julian_centuries_since_j2000(
+                J2000 - 36_525.0
+            ),
+            -1.0
+        );
+
+        // 2100-01-01 12:00 is a century after J2000.0
+        // (2000 being a leap year, and 2100 not).
+        assert_eq!(
+            julian_centuries_from_generic_datetime(
+                NaiveDate::from_ymd(2100, 1, 1)
+                    .and_hms(12, 0, 0)
+            ),
+            1.0
+        );
+    }
 }
@@ -0,0 +1,157 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+/// Errors for building the units out of `f64`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UnitError {
+    /// The value is `NaN` or infinite.
+    NotFinite(f64),
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            UnitError::NotFinite(v) => {
+                write!(f, "Not a finite value: {}", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UnitError {}
+
+/// Decimal hours (24 hours to a circle), such as
+/// right ascension, hour-angle, and sidereal time.
+/// Converts from and into `DecimalDegrees` (15
+/// degrees to an hour).
+///
+/// Example:
+/// ```rust
+/// use std::convert::TryFrom;
+/// use sowngwala::units::{DecimalDegrees, DecimalHours};
+///
+/// let hours = DecimalHours::try_from(4.0).unwrap();
+/// let degrees: DecimalDegrees = hours.into();
+/// assert_eq!(f64::from(degrees), 60.0);
+///
+/// assert!(DecimalHours::try_from(f64::NAN).is_err());
+/// ```
+///
+/// Hours are not degrees (the 15× mix-up does not
+/// compile):
+/// ```compile_fail
+/// use std::convert::TryFrom;
+/// use sowngwala::units::{DecimalDegrees, DecimalHours};
+///
+/// let hours = DecimalHours::try_from(4.0).unwrap();
+/// let degrees: DecimalDegrees = hours;
+/// ```
+#[derive(
+    Debug, Copy, Clone, PartialEq, PartialOrd,
+)]
+pub struct DecimalHours(f64);
+
+/// Decimal degrees (360 degrees to a circle), such
+/// as longitude, declination, and altitude. Converts
+/// from and into `DecimalHours` (15 degrees to an
+/// hour).
+///
+/// Example:
+/// ```rust
+/// use std::convert::TryFrom;
+/// use sowngwala::units::{DecimalDegrees, DecimalHours};
+///
+/// let degrees = DecimalDegrees::try_from(64.0).unwrap();
+/// let hours: DecimalHours = degrees.into();
+/// assert!((f64::from(hours) - 64.0 / 15.0).abs() < 1e-12);
+/// ```
+#[derive(
+    Debug, Copy, Clone, PartialEq, PartialOrd,
+)]
+pub struct DecimalDegrees(f64);
+
+impl TryFrom<f64> for DecimalHours {
+    type Error = UnitError;
+
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        if v.is_finite() {
+            Ok(DecimalHours(v))
+        } else {
+            Err(UnitError::NotFinite(v))
+        }
+    }
+}
+
+impl TryFrom<f64> for DecimalDegrees {
+    type Error = UnitError;
+
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        if v.is_finite() {
+            Ok(DecimalDegrees(v))
+        } else {
+            Err(UnitError::NotFinite(v))
+        }
+    }
+}
+
+impl From<DecimalHours> for f64 {
+    fn from(hours: DecimalHours) -> Self {
+        hours.0
+    }
+}
+
+impl From<DecimalDegrees> for f64 {
+    fn from(degrees: DecimalDegrees) -> Self {
+        degrees.0
+    }
+}
+
+impl From<DecimalDegrees> for DecimalHours {
+    fn from(degrees: DecimalDegrees) -> Self {
+        DecimalHours(degrees.0 / 15.0)
+    }
+}
+
+impl From<DecimalHours> for DecimalDegrees {
+    fn from(hours: DecimalHours) -> Self {
+        DecimalDegrees(hours.0 * 15.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hours_and_degrees_round_trip() {
+        for &v in
+            [-180.0, -64.0, 0.0, 15.0, 359.5].iter()
+        {
+            let degrees =
+                DecimalDegrees::try_from(v).unwrap();
+            let hours: DecimalHours = degrees.into();
+            let degrees_1: DecimalDegrees =
+                hours.into();
+
+            assert_eq!(f64::from(hours) * 15.0, v);
+            assert!(
+                (f64::from(degrees_1) - v).abs()
+                    < 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn not_finite_values_are_rejected() {
+        assert_eq!(
+            DecimalDegrees::try_from(f64::INFINITY),
+            Err(UnitError::NotFinite(f64::INFINITY))
+        );
+        assert!(
+            DecimalHours::try_from(f64::NAN).is_err()
+        );
+    }
+}
@@ -1,7 +1,35 @@
 use chrono::naive::NaiveDate;
 use chrono::Datelike;
+use std::fmt;
 
-use crate::time::julian_day_from_generic_datetime;
+use crate::time::julian_centuries_from_generic_datetime;
+
+/// Errors for `try_overflow`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OverflowError {
+    /// `base` is zero, negative, or not finite.
+    InvalidBase(f64),
+    /// `value` is `NaN` or infinite.
+    NotFinite(f64),
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            OverflowError::InvalidBase(v) => {
+                write!(f, "Invalid base: {}", v)
+            }
+            OverflowError::NotFinite(v) => {
+                write!(f, "Not a finite value: {}", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OverflowError {}
 
 /// Checks if the given value exceeds
 /// the given target value.
@@ -15,6 +43,12 @@ use crate::time::julian_day_from_generic_datetime;
 /// * `result.0` - Value after the calculation.
 /// * `result.1` - Value denoting how much did the value exceed.
 ///
+/// When `base` is not a positive finite number, or
+/// `value` is not finite, nothing can carry over, and
+/// `value` is returned unchanged with the quotient of
+/// 0 (so that no `NaN` nor infinity leaks into the
+/// next unit). Use `try_overflow` to tell those apart.
+///
 /// Example
 /// ```rust
 /// use approx_eq::assert_approx_eq;
@@ -59,15 +93,73 @@ use crate::time::julian_day_from_generic_datetime;
 /// let (res, up) = overflow(-60.1, 60.0);
 /// assert_approx_eq!(res, -0.1, 1e-1);
 /// assert_eq!(up, -1.0);
+///
+/// // Nothing carries over for invalid input.
+/// assert_eq!(overflow(10.0, 0.0), (10.0, 0.0));
+/// assert_eq!(overflow(10.0, -60.0), (10.0, 0.0));
+/// let (res, up) = overflow(f64::NAN, 60.0);
+/// assert!(res.is_nan());
+/// assert_eq!(up, 0.0);
 /// ```
 pub fn overflow(value: f64, base: f64) -> (f64, f64) {
+    try_overflow(value, base).unwrap_or((value, 0.0))
+}
+
+/// Same as `overflow`, but returns `OverflowError`
+/// when `base` is not a positive finite number, or
+/// `value` is not finite.
+///
+/// Example
+/// ```rust
+/// use sowngwala::utils::{try_overflow, OverflowError};
+///
+/// assert_eq!(try_overflow(121.0, 60.0), Ok((1.0, 2.0)));
+/// assert_eq!(
+///     try_overflow(10.0, 0.0),
+///     Err(OverflowError::InvalidBase(0.0))
+/// );
+/// assert!(matches!(
+///     try_overflow(f64::NAN, 60.0),
+///     Err(OverflowError::NotFinite(_))
+/// ));
+/// assert!(matches!(
+///     try_overflow(f64::INFINITY, 60.0),
+///     Err(OverflowError::NotFinite(_))
+/// ));
+/// ```
+pub fn try_overflow(
+    value: f64,
+    base: f64,
+) -> Result<(f64, f64), OverflowError> {
+    if !(base.is_finite() && base > 0.0) {
+        return Err(OverflowError::InvalidBase(base));
+    }
+    if !value.is_finite() {
+        return Err(OverflowError::NotFinite(value));
+    }
+
     let remainder = value % base;
     let divisible = value - remainder;
     let quotient = divisible / base;
 
-    (remainder, quotient)
+    Ok((remainder, quotient))
 }
 
+/// Given a numeric value and the size of the full
+/// circle (`max`, such as 360.0 or 24.0), returns the
+/// value brought into (-max/2, max/2]. Not to be
+/// confused with `coords::normalize_angle` which
+/// calibrates `Angle`.
+///
+/// Example
+/// ```rust
+/// use sowngwala::utils::normalize_angle;
+///
+/// assert_eq!(normalize_angle(190.0, 360.0), -170.0);
+/// assert_eq!(normalize_angle(-180.0, 360.0), 180.0);
+/// assert_eq!(normalize_angle(725.0, 360.0), 5.0);
+/// assert_eq!(normalize_angle(13.0, 24.0), -11.0);
+/// ```
 pub fn normalize_angle(value: f64, max: f64) -> f64 {
     let half = max / 2.0;
     let mut angle = value;
@@ -117,23 +209,206 @@ pub fn mean_obliquity_of_the_epliptic<T>(
 ) -> f64
 where
     T: Datelike,
-    T: std::marker::Copy,
-    T: std::fmt::Debug,
-    T: std::fmt::Display,
 {
-    let mut jd = julian_day_from_generic_datetime(
-        NaiveDate::from_ymd(
+    let t = julian_centuries_from_generic_datetime(
+        NaiveDate::from_ymd_opt(
             date.year(),
             date.month(),
             date.day(),
         )
-        .and_hms(0, 0, 0),
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .unwrap(),
     );
-    jd -= 2_451_545.0; // January 1.5, 2000
-
-    let t = jd / 36_525.0;
     let mut delta = (46.815 * t) + (0.0006 * t * t)
         - (0.001_81 * t * t * t);
     delta /= 3600.0;
     23.439_292 - delta
 }
+
+/// Returns the mean obliquity of the ecliptic (ε) in
+/// degrees using Laskar's 10-term polynomial, which
+/// is valid for 10,000 years either side of J2000
+/// (where `mean_obliquity_of_the_epliptic` drifts).
+/// Use it for dates far in the past (or the future).
+///
+/// * `date` - Datelike
+///
+/// References:
+/// - (Jean Meeus, "Astronomical Algorithms", p.147)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::utils::{
+///     mean_obliquity_laskar,
+///     mean_obliquity_of_the_epliptic,
+/// };
+///
+/// // Within 0.01" of the published figures.
+/// let tolerance: f64 = 0.01 / 3600.0;
+///
+/// // At J2000, it is 23° 26' 21.448".
+/// let date = NaiveDate::from_ymd(2000, 1, 1);
+/// let expected: f64 =
+///     23.0 + (26.0 / 60.0) + (21.448 / 3600.0);
+/// assert!(
+///     (mean_obliquity_laskar(date) - expected).abs()
+///         < tolerance
+/// );
+///
+/// // 1987-04-10 is 23° 26' 27.407" (Meeus, Example
+/// // 22.a, p.148), where the two agree.
+/// let date = NaiveDate::from_ymd(1987, 4, 10);
+/// let expected: f64 =
+///     23.0 + (26.0 / 60.0) + (27.407 / 3600.0);
+/// assert!(
+///     (mean_obliquity_laskar(date) - expected).abs()
+///         < tolerance
+/// );
+/// assert!(
+///     (mean_obliquity_of_the_epliptic(date) - expected)
+///         .abs()
+///         < tolerance
+/// );
+///
+/// // In 4000 BC (-3999), they differ by more than 25".
+/// let date = NaiveDate::from_ymd(-3999, 1, 1);
+/// let laskar: f64 = mean_obliquity_laskar(date);
+/// let series: f64 = mean_obliquity_of_the_epliptic(date);
+/// assert!((series - laskar) > 25.0 / 3600.0);
+/// ```
+pub fn mean_obliquity_laskar<T>(date: T) -> f64
+where
+    T: Datelike,
+{
+    let t = julian_centuries_from_generic_datetime(
+        NaiveDate::from_ymd_opt(
+            date.year(),
+            date.month(),
+            date.day(),
+        )
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .unwrap(),
+    );
+
+    // In units of 10,000 Julian years
+    let u: f64 = t / 100.0;
+
+    // Coefficients (in arcseconds) for u^1 to u^10
+    let terms: [f64; 10] = [
+        -4680.93, -1.55, 1999.25, -51.38, -249.67,
+        -39.05, 7.12, 27.87, 5.79, 2.45,
+    ];
+
+    let mut delta: f64 = 0.0;
+    let mut u_n: f64 = 1.0;
+    for term in terms.iter() {
+        u_n *= u;
+        delta += term * u_n;
+    }
+
+    // 23° 26' 21.448"
+    23.0 + (26.0 / 60.0) + ((21.448 + delta) / 3600.0)
+}
+
+/// Returns the nutation in longitude (Δψ) and in
+/// obliquity (Δε) in degrees for the given date, by
+/// the four largest terms (good to 0.5 arcseconds).
+/// The earth's axis nods (with the period of 18.6
+/// years of the moon's node (Ω)), by some 17
+/// arcseconds in longitude and 9 arcseconds in
+/// obliquity. Add Δψ to the ecliptic longitude (λ),
+/// and Δε to the mean obliquity (see
+/// `apparent_obliquity_of_the_ecliptic`) for the
+/// apparent places.
+///
+/// * `date` - Datelike
+///
+/// References:
+/// - (Jean Meeus, "Astronomical Algorithms", p.144)
+///
+/// Example:
+/// ```rust
+/// use approx_eq::assert_approx_eq;
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::utils::nutation;
+///
+/// // Meeus gives -3.788" and 9.443" (for 0h TD).
+/// let date = NaiveDate::from_ymd(1987, 4, 10);
+/// let (d_psi, d_eps): (f64, f64) = nutation(date);
+///
+/// assert!((d_psi * 3600.0 - -3.788).abs() < 0.5);
+/// assert!((d_eps * 3600.0 - 9.443).abs() < 0.5);
+/// ```
+pub fn nutation<T>(date: T) -> (f64, f64)
+where
+    T: Datelike,
+{
+    let t = julian_centuries_from_generic_datetime(
+        NaiveDate::from_ymd_opt(
+            date.year(),
+            date.month(),
+            date.day(),
+        )
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .unwrap(),
+    );
+
+    // Longitude of the moon's ascending node (Ω)
+    let node: f64 =
+        (125.044_52 - 1_934.136_261 * t).to_radians();
+    // Mean longitudes of the sun (L) and the moon (L')
+    let sun: f64 =
+        (280.466_5 + 36_000.769_8 * t).to_radians();
+    let moon: f64 =
+        (218.316_5 + 481_267.881_3 * t).to_radians();
+
+    // In arcseconds
+    let d_psi: f64 = -17.20 * node.sin()
+        - 1.32 * (2.0 * sun).sin()
+        - 0.23 * (2.0 * moon).sin()
+        + 0.21 * (2.0 * node).sin();
+    let d_eps: f64 = 9.20 * node.cos()
+        + 0.57 * (2.0 * sun).cos()
+        + 0.10 * (2.0 * moon).cos()
+        - 0.09 * (2.0 * node).cos();
+
+    (d_psi / 3600.0, d_eps / 3600.0)
+}
+
+/// Returns the apparent obliquity of the ecliptic
+/// (ε) in degrees, which is the mean obliquity (see
+/// `mean_obliquity_of_the_epliptic`) with the
+/// nutation in obliquity (Δε) added (see
+/// `nutation`).
+///
+/// * `date` - Datelike
+///
+/// References:
+/// - (Jean Meeus, "Astronomical Algorithms", p.147)
+///
+/// Example:
+/// ```rust
+/// use chrono::naive::NaiveDate;
+/// use sowngwala::utils::{
+///     apparent_obliquity_of_the_ecliptic,
+///     mean_obliquity_of_the_epliptic,
+/// };
+///
+/// let date = NaiveDate::from_ymd(1987, 4, 10);
+/// let diff: f64 = apparent_obliquity_of_the_ecliptic(date)
+///     - mean_obliquity_of_the_epliptic(date);
+///
+/// // About 9 arcseconds
+/// assert!((diff * 3600.0 - 9.4).abs() < 0.5);
+/// ```
+pub fn apparent_obliquity_of_the_ecliptic<T>(
+    date: T,
+) -> f64
+where
+    T: Datelike,
+    T: std::marker::Copy,
+{
+    mean_obliquity_of_the_epliptic(date)
+        + nutation(date).1
+}
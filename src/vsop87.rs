@@ -0,0 +1,1039 @@
+//! Truncated VSOP87 series for the planets
+//!
+//! VSOP87 (Variations Séculaires des Orbites
+//! Planétaires) by P. Bretagnon and G. Francou gives
+//! the heliocentric ecliptic longitude (L), latitude
+//! (B), and the radius vector (R) of the planets as
+//! sums of periodic terms. Here, the program carries
+//! only the largest terms (up to 10 for each) of
+//! VSOP87D (referred to the ecliptic and the equinox
+//! of the date), which is enough for a few arcminutes.
+//!
+//! Each term is `[A, B, C]`, and contributes
+//! `A * cos(B + C * τ)` (A in 10^-8 radians or au)
+//! where τ is Julian millennia since J2000.0.
+//! For each of L, B, and R, series are given for the
+//! powers of τ (L0, L1, L2, ...).
+//!
+//! References:
+//!
+//! Jean Meeus, "Astronomical Algorithms" (2nd ed.),
+//! Chapter 32 and Appendix III
+// Phases of π are given as rounded in the tables.
+#![allow(clippy::approx_constant)]
+
+pub type Vsop87Series =
+    &'static [&'static [[f64; 3]]];
+
+/// Series for L, B, and R of a planet.
+pub struct Vsop87 {
+    pub l: Vsop87Series,
+    pub b: Vsop87Series,
+    pub r: Vsop87Series,
+}
+
+/// Given a series and τ (Julian millennia since
+/// J2000.0), returns the sum (in radians or au).
+pub fn sum_of_series(
+    series: Vsop87Series,
+    tau: f64,
+) -> f64 {
+    let mut sum: f64 = 0.0;
+
+    for (power, terms) in series.iter().enumerate() {
+        let partial: f64 = terms
+            .iter()
+            .map(|[a, b, c]| a * (b + c * tau).cos())
+            .sum();
+        sum += partial * tau.powi(power as i32);
+    }
+
+    sum * 1e-8
+}
+
+#[rustfmt::skip]
+pub const MERCURY: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [440250710.0, 0.0, 0.0],
+            [40989415.0, 1.48302034, 26087.90314157],
+            [5046294.0, 4.4778549, 52175.8062831],
+            [855347.0, 1.165203, 78263.709425],
+            [165590.0, 4.119692, 104351.612566],
+            [34562.0, 0.77931, 130439.51571],
+            [7583.0, 3.7135, 156527.4188],
+            [3560.0, 1.512, 1109.3786],
+            [1803.0, 4.1033, 5661.332],
+            [1726.0, 0.3583, 182615.322],
+        ],
+        // L1
+        &[
+            [2608814706223.0, 0.0, 0.0],
+            [1126008.0, 6.2170397, 26087.9031416],
+            [303471.0, 3.055655, 52175.806283],
+            [80538.0, 6.10455, 78263.70942],
+            [21245.0, 2.83532, 104351.61257],
+            [5592.0, 5.8268, 130439.5157],
+            [1472.0, 2.5185, 156527.4188],
+            [388.0, 5.48, 182615.322],
+            [352.0, 3.052, 1109.379],
+            [103.0, 2.149, 208703.225],
+        ],
+        // L2
+        &[
+            [53050.0, 0.0, 0.0],
+            [16904.0, 4.69072, 26087.90314],
+            [7397.0, 1.3474, 52175.8063],
+            [3018.0, 4.4564, 78263.7094],
+            [1107.0, 1.2623, 104351.6126],
+            [378.0, 4.32, 130439.516],
+            [123.0, 1.069, 156527.419],
+            [39.0, 4.08, 182615.32],
+        ],
+        // L3
+        &[
+            [188.0, 0.035, 52175.806],
+            [142.0, 3.125, 26087.903],
+            [97.0, 3.0, 78263.71],
+            [44.0, 6.02, 104351.61],
+            [35.0, 0.0, 0.0],
+            [18.0, 2.78, 130439.52],
+        ],
+        // L4
+        &[
+            [114.0, 3.1416, 0.0],
+            [3.0, 2.03, 26087.9],
+            [2.0, 1.42, 78263.71],
+        ],
+        // L5
+        &[
+            [1.0, 3.14, 0.0],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [11737529.0, 1.98357499, 26087.90314157],
+            [2388077.0, 5.0373896, 52175.8062831],
+            [1222840.0, 3.1415927, 0.0],
+            [543252.0, 1.796444, 78263.709425],
+            [129779.0, 4.832325, 104351.612566],
+            [31867.0, 1.58088, 130439.51571],
+            [7963.0, 4.6097, 156527.4188],
+            [2014.0, 1.3532, 182615.322],
+        ],
+        // B1
+        &[
+            [429151.0, 3.501698, 26087.903142],
+            [146234.0, 3.141593, 0.0],
+            [22675.0, 0.01515, 52175.80628],
+            [10895.0, 0.4854, 78263.70942],
+            [6353.0, 3.4294, 104351.6126],
+            [2496.0, 0.1605, 130439.5157],
+        ],
+        // B2
+        &[
+            [11831.0, 4.79066, 26087.90314],
+            [1914.0, 0.0, 0.0],
+            [1045.0, 1.2122, 52175.8063],
+            [266.0, 4.434, 78263.709],
+            [170.0, 1.623, 104351.613],
+        ],
+        // B3
+        &[
+            [235.0, 0.354, 26087.903],
+            [161.0, 0.0, 0.0],
+            [19.0, 4.36, 52175.81],
+            [6.0, 2.51, 78263.71],
+        ],
+        // B4
+        &[
+            [4.0, 1.75, 26087.9],
+            [1.0, 3.14, 0.0],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [39528272.0, 0.0, 0.0],
+            [7834132.0, 6.1923372, 26087.9031416],
+            [795526.0, 2.959897, 52175.806283],
+            [121282.0, 6.010642, 78263.709425],
+            [21922.0, 2.7782, 104351.61257],
+            [4354.0, 5.8289, 130439.5157],
+            [918.0, 2.597, 156527.419],
+            [290.0, 1.424, 25028.521],
+            [260.0, 3.028, 27197.282],
+            [202.0, 5.647, 182615.322],
+        ],
+        // R1
+        &[
+            [217348.0, 4.656172, 26087.903142],
+            [44142.0, 1.42386, 52175.80628],
+            [10094.0, 4.47466, 78263.70942],
+            [2433.0, 1.2423, 104351.6126],
+            [1624.0, 0.0, 0.0],
+            [604.0, 4.293, 130439.516],
+            [153.0, 1.061, 156527.419],
+        ],
+        // R2
+        &[
+            [3118.0, 3.0823, 26087.9031],
+            [1245.0, 6.1518, 52175.8063],
+            [425.0, 2.926, 78263.709],
+            [136.0, 5.98, 104351.613],
+            [42.0, 2.75, 130439.52],
+            [22.0, 3.14, 0.0],
+        ],
+        // R3
+        &[
+            [33.0, 1.68, 26087.9],
+            [24.0, 4.63, 52175.81],
+            [12.0, 1.39, 78263.71],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const VENUS: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [317614667.0, 0.0, 0.0],
+            [1353968.0, 5.5931332, 10213.2855462],
+            [89892.0, 5.3065, 20426.57109],
+            [5477.0, 4.4163, 7860.4194],
+            [3456.0, 2.6996, 11790.6291],
+            [2372.0, 2.9938, 3930.2097],
+            [1664.0, 4.2502, 1577.3435],
+            [1438.0, 4.1575, 9683.5946],
+            [1317.0, 5.1867, 26.2983],
+            [1201.0, 6.1536, 30639.8566],
+        ],
+        // L1
+        &[
+            [1021352943053.0, 0.0, 0.0],
+            [95708.0, 2.46424, 10213.28555],
+            [14445.0, 0.51625, 20426.57109],
+            [213.0, 1.795, 30639.857],
+            [174.0, 2.655, 26.298],
+            [152.0, 6.106, 1577.344],
+            [82.0, 5.7, 191.45],
+            [70.0, 2.68, 9437.76],
+        ],
+        // L2
+        &[
+            [54127.0, 0.0, 0.0],
+            [3891.0, 0.3451, 10213.2855],
+            [1338.0, 2.0201, 20426.5711],
+            [24.0, 2.05, 26.3],
+            [19.0, 3.54, 30639.86],
+        ],
+        // L3
+        &[
+            [136.0, 4.804, 10213.286],
+            [78.0, 3.67, 20426.57],
+            [26.0, 0.0, 0.0],
+        ],
+        // L4
+        &[
+            [114.0, 3.1416, 0.0],
+            [3.0, 5.21, 20426.57],
+            [2.0, 2.51, 10213.29],
+        ],
+        // L5
+        &[
+            [1.0, 3.14, 0.0],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [5923638.0, 0.2670278, 10213.2855462],
+            [40108.0, 1.14737, 20426.57109],
+            [32815.0, 3.14159, 0.0],
+            [1011.0, 1.0895, 30639.8566],
+            [149.0, 6.254, 18073.705],
+            [138.0, 0.86, 1577.344],
+            [130.0, 3.672, 9437.763],
+            [120.0, 3.705, 2352.866],
+            [108.0, 4.539, 22003.915],
+        ],
+        // B1
+        &[
+            [513348.0, 1.803643, 10213.285546],
+            [4380.0, 3.3862, 20426.5711],
+            [199.0, 0.0, 0.0],
+            [197.0, 2.53, 30639.857],
+        ],
+        // B2
+        &[
+            [22378.0, 3.38509, 10213.28555],
+            [282.0, 0.0, 0.0],
+            [173.0, 5.256, 20426.571],
+            [27.0, 3.87, 30639.86],
+        ],
+        // B3
+        &[
+            [647.0, 4.992, 10213.286],
+            [20.0, 3.14, 0.0],
+            [6.0, 0.77, 20426.57],
+            [3.0, 5.44, 30639.86],
+        ],
+        // B4
+        &[
+            [14.0, 0.32, 10213.29],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [72334821.0, 0.0, 0.0],
+            [489824.0, 4.021518, 10213.285546],
+            [1658.0, 4.9021, 20426.5711],
+            [1632.0, 2.8455, 7860.4194],
+            [1378.0, 1.1285, 11790.6291],
+            [498.0, 2.587, 9683.595],
+            [374.0, 1.423, 3930.21],
+            [264.0, 5.529, 9437.763],
+            [237.0, 2.551, 15720.839],
+            [222.0, 2.013, 19367.189],
+        ],
+        // R1
+        &[
+            [34551.0, 0.89199, 10213.28555],
+            [234.0, 1.772, 20426.571],
+            [234.0, 3.142, 0.0],
+        ],
+        // R2
+        &[
+            [1407.0, 5.0637, 10213.2855],
+            [16.0, 5.47, 20426.57],
+            [13.0, 0.0, 0.0],
+        ],
+        // R3
+        &[
+            [50.0, 3.22, 10213.29],
+        ],
+        // R4
+        &[
+            [1.0, 0.92, 10213.29],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const MARS: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [620347712.0, 0.0, 0.0],
+            [18656368.0, 5.050371, 3340.6124267],
+            [1108217.0, 5.4009984, 6681.2248534],
+            [91798.0, 5.75479, 10021.83728],
+            [27745.0, 5.9705, 3.52312],
+            [12316.0, 0.84956, 2810.92146],
+            [10610.0, 2.93959, 2281.2305],
+            [8927.0, 4.157, 0.01725],
+            [8716.0, 6.11005, 13362.44971],
+            [7775.0, 3.33969, 5621.84292],
+        ],
+        // L1
+        &[
+            [334085627474.0, 0.0, 0.0],
+            [1458227.0, 3.6042605, 3340.6124267],
+            [164901.0, 3.926313, 6681.224853],
+            [19963.0, 4.26594, 10021.83728],
+            [3452.0, 4.7321, 3.5231],
+            [2485.0, 4.6128, 13362.4497],
+            [842.0, 4.459, 2281.23],
+            [538.0, 5.016, 398.149],
+            [521.0, 4.994, 3344.136],
+            [433.0, 2.561, 191.448],
+        ],
+        // L2
+        &[
+            [58016.0, 2.04979, 3340.61243],
+            [54188.0, 0.0, 0.0],
+            [13908.0, 2.45742, 6681.22485],
+            [2465.0, 2.8, 10021.8373],
+            [398.0, 3.141, 13362.45],
+            [222.0, 3.194, 3.523],
+            [121.0, 0.543, 155.42],
+        ],
+        // L3
+        &[
+            [1482.0, 0.4443, 3340.6124],
+            [662.0, 0.885, 6681.225],
+            [188.0, 1.288, 10021.837],
+            [41.0, 1.65, 13362.45],
+            [26.0, 0.0, 0.0],
+            [23.0, 2.05, 155.42],
+        ],
+        // L4
+        &[
+            [114.0, 3.1416, 0.0],
+            [29.0, 5.64, 6681.22],
+            [24.0, 5.14, 3340.61],
+            [11.0, 6.03, 10021.84],
+        ],
+        // L5
+        &[
+            [1.0, 3.14, 0.0],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [3197135.0, 3.7683204, 3340.6124267],
+            [298033.0, 4.10617, 6681.224853],
+            [289105.0, 0.0, 0.0],
+            [31366.0, 4.44651, 10021.83728],
+            [3484.0, 4.7881, 13362.4497],
+            [443.0, 5.026, 3344.136],
+            [443.0, 5.652, 3337.089],
+            [399.0, 5.131, 16703.062],
+            [293.0, 3.793, 2281.23],
+            [182.0, 6.136, 6151.534],
+        ],
+        // B1
+        &[
+            [350069.0, 5.368478, 3340.612427],
+            [14116.0, 3.14159, 0.0],
+            [9671.0, 5.4788, 6681.2249],
+            [1472.0, 3.2021, 10021.8373],
+            [426.0, 3.408, 13362.45],
+        ],
+        // B2
+        &[
+            [16727.0, 0.60221, 3340.61243],
+            [4987.0, 3.1416, 0.0],
+            [302.0, 5.559, 6681.225],
+            [26.0, 1.9, 13362.45],
+        ],
+        // B3
+        &[
+            [607.0, 1.981, 3340.612],
+            [43.0, 0.0, 0.0],
+            [14.0, 1.8, 6681.22],
+        ],
+        // B4
+        &[
+            [13.0, 0.0, 0.0],
+            [11.0, 3.46, 3340.61],
+            [1.0, 0.5, 6681.22],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [153033488.0, 0.0, 0.0],
+            [14184953.0, 3.47971284, 3340.6124267],
+            [660776.0, 3.817834, 6681.224853],
+            [46179.0, 4.15595, 10021.83728],
+            [8110.0, 5.5596, 2810.9215],
+            [7485.0, 1.7724, 5621.8429],
+            [5523.0, 1.3644, 2281.2305],
+            [3825.0, 4.4941, 13362.4497],
+            [2484.0, 4.9255, 2942.4634],
+            [2307.0, 0.0908, 2544.3144],
+        ],
+        // R1
+        &[
+            [1107433.0, 2.0325052, 3340.6124267],
+            [103176.0, 2.370718, 6681.224853],
+            [12877.0, 0.0, 0.0],
+            [10816.0, 2.70888, 10021.83728],
+            [1195.0, 3.047, 13362.4497],
+            [439.0, 2.888, 2281.23],
+            [396.0, 3.423, 3344.136],
+        ],
+        // R2
+        &[
+            [44242.0, 0.47931, 3340.61243],
+            [8138.0, 0.87, 6681.2249],
+            [1275.0, 1.2259, 10021.8373],
+            [187.0, 1.573, 13362.45],
+            [52.0, 3.14, 0.0],
+        ],
+        // R3
+        &[
+            [1113.0, 5.1499, 3340.6124],
+            [424.0, 5.613, 6681.225],
+            [100.0, 5.997, 10021.837],
+            [20.0, 0.08, 13362.45],
+        ],
+        // R4
+        &[
+            [20.0, 3.58, 3340.61],
+            [16.0, 4.05, 6681.22],
+            [6.0, 4.46, 10021.84],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const JUPITER: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [59954691.0, 0.0, 0.0],
+            [9695899.0, 5.0619179, 529.6909651],
+            [573610.0, 1.444062, 7.113547],
+            [306389.0, 5.417347, 1059.38193],
+            [97178.0, 4.14265, 632.78374],
+            [72903.0, 3.64043, 522.57742],
+            [64264.0, 3.41145, 103.09277],
+            [39806.0, 2.29377, 419.48464],
+            [38858.0, 1.27232, 316.39187],
+            [27965.0, 1.78455, 536.80451],
+        ],
+        // L1
+        &[
+            [52993480757.0, 0.0, 0.0],
+            [489741.0, 4.220667, 529.690965],
+            [228919.0, 6.026475, 7.113547],
+            [27655.0, 4.57266, 1059.38193],
+            [20721.0, 5.45939, 522.57742],
+            [12106.0, 0.16986, 536.80451],
+            [6068.0, 4.4242, 103.0928],
+            [5434.0, 3.9848, 419.4846],
+            [4238.0, 5.8901, 14.2271],
+            [2212.0, 5.2677, 206.1855],
+        ],
+        // L2
+        &[
+            [47234.0, 4.32148, 7.11355],
+            [38966.0, 0.0, 0.0],
+            [30629.0, 2.93021, 529.69097],
+            [3189.0, 1.055, 522.5774],
+            [2729.0, 4.8455, 536.8045],
+            [2723.0, 3.4141, 1059.3819],
+            [1721.0, 4.1873, 14.2271],
+            [383.0, 5.768, 419.485],
+        ],
+        // L3
+        &[
+            [6502.0, 2.5986, 7.1135],
+            [1357.0, 1.3464, 529.691],
+            [471.0, 2.475, 14.227],
+            [417.0, 3.245, 536.805],
+            [353.0, 2.974, 522.577],
+            [155.0, 2.076, 1059.382],
+        ],
+        // L4
+        &[
+            [669.0, 0.853, 7.114],
+            [114.0, 3.142, 0.0],
+            [100.0, 0.743, 14.227],
+            [50.0, 1.65, 536.8],
+            [44.0, 5.82, 529.69],
+        ],
+        // L5
+        &[
+            [50.0, 5.26, 7.11],
+            [16.0, 5.25, 14.23],
+            [4.0, 0.01, 536.8],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [2268616.0, 3.5585261, 529.6909651],
+            [110090.0, 0.0, 0.0],
+            [109972.0, 3.908093, 1059.38193],
+            [8101.0, 3.6051, 522.5774],
+            [6438.0, 0.3063, 536.8045],
+            [6044.0, 4.2588, 1589.0729],
+            [1107.0, 2.9853, 1162.4747],
+            [944.0, 1.675, 426.598],
+            [942.0, 2.936, 1052.268],
+            [894.0, 1.754, 7.114],
+        ],
+        // B1
+        &[
+            [177352.0, 5.701665, 529.690965],
+            [3230.0, 5.7794, 1059.3819],
+            [3081.0, 5.4746, 522.5774],
+            [2212.0, 4.7348, 536.8045],
+            [1694.0, 3.1416, 0.0],
+            [346.0, 4.746, 1052.268],
+        ],
+        // B2
+        &[
+            [8094.0, 1.4632, 529.691],
+            [813.0, 3.1416, 0.0],
+            [742.0, 0.957, 522.577],
+            [399.0, 2.899, 536.805],
+            [342.0, 1.447, 1059.382],
+        ],
+        // B3
+        &[
+            [252.0, 3.381, 529.691],
+            [122.0, 2.733, 522.577],
+            [49.0, 1.04, 536.8],
+            [11.0, 2.31, 1052.27],
+        ],
+        // B4
+        &[
+            [15.0, 4.53, 522.58],
+            [5.0, 4.47, 529.69],
+            [4.0, 5.44, 536.8],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [520887429.0, 0.0, 0.0],
+            [25209327.0, 3.4910864, 529.69096509],
+            [610600.0, 3.841154, 1059.38193],
+            [282029.0, 2.574199, 632.783739],
+            [187647.0, 2.075904, 522.577418],
+            [86793.0, 0.71001, 419.48464],
+            [72063.0, 0.21466, 536.80451],
+            [65517.0, 5.97996, 316.39187],
+            [30135.0, 2.16132, 949.17561],
+            [29135.0, 1.67759, 103.09277],
+        ],
+        // R1
+        &[
+            [1271802.0, 2.6493751, 529.6909651],
+            [61662.0, 3.00076, 1059.38193],
+            [53444.0, 3.89718, 522.57742],
+            [41390.0, 0.0, 0.0],
+            [31185.0, 4.88277, 536.80451],
+            [11847.0, 2.4133, 419.48464],
+            [9166.0, 4.7598, 7.1135],
+        ],
+        // R2
+        &[
+            [79645.0, 1.35866, 529.69097],
+            [8252.0, 5.7777, 522.5774],
+            [7030.0, 3.2748, 536.8045],
+            [5314.0, 1.8384, 1059.3819],
+            [1861.0, 2.9768, 7.1135],
+        ],
+        // R3
+        &[
+            [3519.0, 6.058, 529.691],
+            [1073.0, 1.6732, 536.8045],
+            [916.0, 1.413, 522.577],
+            [342.0, 0.523, 1059.382],
+        ],
+        // R4
+        &[
+            [129.0, 0.084, 536.805],
+            [113.0, 4.249, 529.691],
+            [83.0, 3.3, 522.58],
+        ],
+        // R5
+        &[
+            [11.0, 4.75, 536.8],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const SATURN: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [87401354.0, 0.0, 0.0],
+            [11107660.0, 3.9620509, 213.29909544],
+            [1414151.0, 4.5858152, 7.113547],
+            [398379.0, 0.52112, 206.185548],
+            [350769.0, 3.303299, 426.598191],
+            [206816.0, 0.246584, 103.092774],
+            [79271.0, 3.84007, 220.41264],
+            [23990.0, 4.66977, 110.20632],
+            [16574.0, 0.43719, 419.48464],
+            [15820.0, 0.93809, 632.78374],
+        ],
+        // L1
+        &[
+            [21354295596.0, 0.0, 0.0],
+            [1296855.0, 1.8282054, 213.2990954],
+            [564348.0, 2.885001, 7.113547],
+            [107679.0, 2.277699, 206.185548],
+            [98323.0, 1.0807, 426.59819],
+            [40255.0, 2.04128, 220.41264],
+            [19942.0, 1.27955, 103.09277],
+            [10512.0, 2.7488, 14.22709],
+            [6939.0, 0.4049, 639.8973],
+            [4803.0, 2.4419, 419.4846],
+        ],
+        // L2
+        &[
+            [116441.0, 1.179879, 7.113547],
+            [91921.0, 0.07425, 213.2991],
+            [90592.0, 0.0, 0.0],
+            [15277.0, 4.06492, 206.18555],
+            [10631.0, 0.25778, 220.41264],
+            [10605.0, 5.40964, 426.59819],
+            [4265.0, 1.046, 14.2271],
+        ],
+        // L3
+        &[
+            [16039.0, 5.73945, 7.11355],
+            [4250.0, 4.5854, 213.2991],
+            [1907.0, 4.7608, 220.4126],
+            [1466.0, 5.9133, 206.1855],
+            [1162.0, 5.6197, 14.2271],
+            [1067.0, 3.6082, 426.5982],
+        ],
+        // L4
+        &[
+            [1662.0, 3.9983, 7.1135],
+            [257.0, 2.984, 220.413],
+            [236.0, 3.902, 14.227],
+            [149.0, 2.741, 213.299],
+            [114.0, 3.142, 0.0],
+        ],
+        // L5
+        &[
+            [124.0, 2.259, 7.114],
+            [34.0, 2.16, 14.23],
+            [28.0, 1.2, 220.41],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [4330678.0, 3.6028443, 213.2990954],
+            [240348.0, 2.852385, 426.598191],
+            [84746.0, 0.0, 0.0],
+            [34116.0, 0.57297, 206.18555],
+            [30863.0, 3.48442, 220.41264],
+            [14734.0, 2.11847, 639.89729],
+            [9917.0, 5.79, 419.4846],
+            [6994.0, 4.736, 7.1135],
+            [4808.0, 5.4331, 316.3919],
+            [4788.0, 4.9651, 110.2063],
+        ],
+        // B1
+        &[
+            [397555.0, 5.3329, 213.299095],
+            [49479.0, 3.14159, 0.0],
+            [18572.0, 6.09919, 426.59819],
+            [14801.0, 2.30586, 206.18555],
+            [9644.0, 1.6967, 220.4126],
+        ],
+        // B2
+        &[
+            [20630.0, 0.50482, 213.2991],
+            [3720.0, 3.9983, 206.1855],
+            [1627.0, 6.1819, 220.4126],
+            [1346.0, 0.0, 0.0],
+        ],
+        // B3
+        &[
+            [666.0, 1.99, 213.299],
+            [632.0, 5.698, 206.186],
+            [398.0, 0.0, 0.0],
+            [188.0, 4.338, 220.413],
+        ],
+        // B4
+        &[
+            [80.0, 1.12, 206.19],
+            [32.0, 3.12, 213.3],
+            [17.0, 2.48, 220.41],
+        ],
+        // B5
+        &[
+            [8.0, 2.82, 206.19],
+            [1.0, 0.51, 220.41],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [955758136.0, 0.0, 0.0],
+            [52921382.0, 2.3922622, 213.29909544],
+            [1873680.0, 5.2354961, 206.1855484],
+            [1464664.0, 1.6476305, 426.5981909],
+            [821891.0, 5.9352, 316.39187],
+            [547507.0, 5.015326, 103.092774],
+            [371684.0, 2.271148, 220.412642],
+            [361778.0, 3.139043, 7.113547],
+            [140618.0, 5.704067, 632.783739],
+            [108975.0, 3.293136, 110.206321],
+        ],
+        // R1
+        &[
+            [6182981.0, 0.2584352, 213.2990954],
+            [506578.0, 0.711147, 206.185548],
+            [341394.0, 5.796358, 426.598191],
+            [188491.0, 0.472157, 220.412642],
+            [186262.0, 3.141593, 0.0],
+            [143891.0, 1.407449, 7.113547],
+        ],
+        // R2
+        &[
+            [436902.0, 4.786717, 213.299095],
+            [71923.0, 2.5007, 206.18555],
+            [49767.0, 4.97168, 220.41264],
+            [43221.0, 3.8694, 426.59819],
+            [29646.0, 5.9631, 7.11355],
+        ],
+        // R3
+        &[
+            [20315.0, 3.02187, 213.2991],
+            [8924.0, 3.1914, 220.4126],
+            [6909.0, 4.3517, 206.1855],
+            [4087.0, 4.2241, 7.1135],
+            [3879.0, 2.0106, 426.5982],
+        ],
+        // R4
+        &[
+            [1202.0, 1.415, 220.4126],
+            [708.0, 1.162, 213.299],
+            [516.0, 6.24, 206.186],
+            [427.0, 2.469, 7.114],
+            [268.0, 0.187, 426.598],
+        ],
+        // R5
+        &[
+            [129.0, 5.913, 220.413],
+            [32.0, 0.69, 7.11],
+            [27.0, 5.91, 227.53],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const URANUS: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [548129294.0, 0.0, 0.0],
+            [9260408.0, 0.8910642, 74.7815986],
+            [1504248.0, 3.6271926, 1.4844727],
+            [365982.0, 1.899622, 73.297126],
+            [272328.0, 3.358237, 149.563197],
+            [70328.0, 5.39254, 63.7359],
+            [68893.0, 6.09292, 76.26607],
+            [61999.0, 2.26952, 2.96895],
+            [61951.0, 2.85099, 11.0457],
+            [26469.0, 3.14152, 71.81265],
+        ],
+        // L1
+        &[
+            [7502543122.0, 0.0, 0.0],
+            [154458.0, 5.242017, 74.781599],
+            [24456.0, 1.71256, 1.48447],
+            [9258.0, 0.4284, 11.0457],
+            [8266.0, 1.5022, 63.7359],
+            [7842.0, 1.3198, 149.5632],
+        ],
+        // L2
+        &[
+            [53033.0, 0.0, 0.0],
+            [2358.0, 2.2601, 74.7816],
+            [769.0, 4.526, 11.046],
+            [552.0, 3.258, 63.736],
+            [542.0, 2.276, 3.932],
+            [529.0, 4.923, 1.484],
+        ],
+        // L3
+        &[
+            [121.0, 0.024, 74.782],
+            [68.0, 4.12, 3.93],
+            [53.0, 2.39, 11.05],
+        ],
+        // L4
+        &[
+            [114.0, 3.142, 0.0],
+            [6.0, 4.58, 74.78],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [1346278.0, 2.6187781, 74.7815986],
+            [62341.0, 5.08111, 149.5632],
+            [61601.0, 3.14159, 0.0],
+            [9964.0, 1.616, 76.2661],
+            [9926.0, 0.5763, 73.2971],
+        ],
+        // B1
+        &[
+            [206366.0, 4.123943, 74.781599],
+            [8563.0, 0.3382, 149.5632],
+            [1726.0, 2.1219, 73.2971],
+            [1374.0, 0.0, 0.0],
+            [1369.0, 3.0686, 76.2661],
+        ],
+        // B2
+        &[
+            [9212.0, 5.8004, 74.7816],
+            [557.0, 0.0, 0.0],
+            [286.0, 2.177, 149.563],
+        ],
+        // B3
+        &[
+            [268.0, 1.251, 74.782],
+            [11.0, 3.14, 0.0],
+        ],
+        // B4
+        &[
+            [6.0, 2.99, 74.78],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [1921264848.0, 0.0, 0.0],
+            [88784984.0, 5.60377527, 74.78159857],
+            [3440836.0, 0.328361, 73.2971259],
+            [2055653.0, 1.7829517, 149.5631971],
+            [649322.0, 4.522473, 76.266071],
+            [602248.0, 3.860038, 63.735898],
+            [496404.0, 1.401399, 454.909367],
+            [338526.0, 1.580027, 138.517497],
+            [243508.0, 1.570866, 71.812653],
+            [190522.0, 1.998094, 1.484473],
+        ],
+        // R1
+        &[
+            [1479896.0, 3.6720571, 74.7815986],
+            [71212.0, 6.22601, 63.7359],
+            [68627.0, 6.13411, 149.5632],
+            [24060.0, 3.14159, 0.0],
+            [21468.0, 2.60177, 76.26607],
+            [20857.0, 5.24625, 11.0457],
+        ],
+        // R2
+        &[
+            [22440.0, 0.69953, 74.7816],
+            [4727.0, 1.699, 63.7359],
+            [1682.0, 4.6483, 70.8494],
+            [1650.0, 3.0966, 11.0457],
+            [1434.0, 3.5212, 149.5632],
+        ],
+        // R3
+        &[
+            [1164.0, 4.7345, 74.7816],
+            [212.0, 3.343, 63.736],
+            [196.0, 2.98, 70.849],
+        ],
+        // R4
+        &[
+            [53.0, 3.01, 74.78],
+            [10.0, 1.91, 56.62],
+        ],
+    ],
+};
+
+#[rustfmt::skip]
+pub const NEPTUNE: Vsop87 = Vsop87 {
+    l: &[
+        // L0
+        &[
+            [531188633.0, 0.0, 0.0],
+            [1798476.0, 2.9010127, 38.1330356],
+            [1019728.0, 0.4858092, 1.4844727],
+            [124532.0, 4.830081, 36.648563],
+            [42064.0, 5.41055, 2.96895],
+            [37715.0, 6.09222, 35.16409],
+            [33785.0, 1.24489, 76.26607],
+            [16483.0, 8e-05, 491.55793],
+            [9199.0, 4.9375, 39.6175],
+            [8994.0, 0.2746, 175.1661],
+        ],
+        // L1
+        &[
+            [3837687717.0, 0.0, 0.0],
+            [16604.0, 4.86319, 1.48447],
+            [15807.0, 2.27923, 38.13304],
+            [3335.0, 3.682, 76.2661],
+            [1306.0, 3.6732, 2.9689],
+            [605.0, 1.505, 35.164],
+        ],
+        // L2
+        &[
+            [53893.0, 0.0, 0.0],
+            [296.0, 1.855, 1.484],
+            [281.0, 1.191, 38.133],
+            [270.0, 5.721, 76.266],
+        ],
+        // L3
+        &[
+            [31.0, 0.0, 0.0],
+            [15.0, 1.35, 76.27],
+            [12.0, 6.04, 1.48],
+            [12.0, 6.11, 38.13],
+        ],
+        // L4
+        &[
+            [114.0, 3.142, 0.0],
+        ],
+    ],
+    b: &[
+        // B0
+        &[
+            [3088623.0, 1.4410437, 38.1330356],
+            [27780.0, 5.91272, 76.26607],
+            [27624.0, 0.0, 0.0],
+            [15448.0, 3.50877, 39.61751],
+            [15355.0, 2.52124, 36.64856],
+        ],
+        // B1
+        &[
+            [227279.0, 3.807931, 38.133036],
+            [1803.0, 1.9758, 76.2661],
+            [1433.0, 3.1416, 0.0],
+            [1386.0, 4.8256, 36.6486],
+            [1073.0, 6.0805, 39.6175],
+        ],
+        // B2
+        &[
+            [9691.0, 5.5712, 38.133],
+            [79.0, 3.63, 76.27],
+            [72.0, 0.45, 36.65],
+            [59.0, 3.14, 0.0],
+        ],
+        // B3
+        &[
+            [273.0, 1.017, 38.133],
+            [2.0, 0.0, 0.0],
+        ],
+        // B4
+        &[
+            [6.0, 2.67, 38.13],
+        ],
+    ],
+    r: &[
+        // R0
+        &[
+            [3007013206.0, 0.0, 0.0],
+            [27062259.0, 1.32999459, 38.13303564],
+            [1691764.0, 3.2518614, 36.6485629],
+            [807831.0, 5.185928, 1.484473],
+            [537761.0, 4.521139, 35.16409],
+            [495726.0, 1.571057, 491.557929],
+            [274572.0, 1.845523, 175.16606],
+            [135134.0, 3.372206, 39.617508],
+            [121802.0, 5.797544, 76.266071],
+            [100895.0, 0.377027, 73.297126],
+        ],
+        // R1
+        &[
+            [236339.0, 0.70498, 38.133036],
+            [13220.0, 3.32015, 1.48447],
+            [8622.0, 6.2163, 35.1641],
+            [2702.0, 1.8814, 39.6175],
+            [2155.0, 2.0943, 2.9689],
+        ],
+        // R2
+        &[
+            [4247.0, 5.8991, 38.133],
+            [218.0, 0.346, 1.484],
+            [163.0, 2.239, 168.053],
+        ],
+        // R3
+        &[
+            [166.0, 4.552, 38.133],
+        ],
+    ],
+};
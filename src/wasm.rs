@@ -0,0 +1,97 @@
+//! A thin adapter for WebAssembly (`wasm` feature).
+//! Takes primitive integers, and returns plain structs
+//! of primitives, so that they can cross the
+//! `wasm-bindgen` boundary without `chrono` types.
+
+use chrono::naive::NaiveDate;
+use wasm_bindgen::prelude::*;
+
+use crate::coords::{Angle, EquaCoord};
+use crate::sun::equatorial_position_of_the_sun_from_generic_date;
+
+/// Right ascension (α) and declination (δ) broken
+/// into hours (degrees), minutes, and seconds.
+/// Like `Angle`, the sign is held by the first
+/// nonzero component.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WasmEquaCoord {
+    pub asc_h: i32,
+    pub asc_m: i32,
+    pub asc_s: f64,
+    pub dec_h: i32,
+    pub dec_m: i32,
+    pub dec_s: f64,
+}
+
+impl From<EquaCoord> for WasmEquaCoord {
+    fn from(coord: EquaCoord) -> Self {
+        let asc: Angle = coord.asc.into();
+        let dec: Angle = coord.dec.into();
+        WasmEquaCoord {
+            asc_h: asc.hour(),
+            asc_m: asc.minute(),
+            asc_s: asc.second(),
+            dec_h: dec.hour(),
+            dec_m: dec.minute(),
+            dec_s: dec.second(),
+        }
+    }
+}
+
+/// Given year, month, and day, returns the
+/// equatorial position of the sun
+/// (see `equatorial_position_of_the_sun_from_generic_date`).
+/// Returns `None` (`undefined` in JS) for an invalid
+/// date.
+///
+/// * `year` - Year
+/// * `month` - Month (1 to 12)
+/// * `day` - Day of the month
+#[wasm_bindgen]
+pub fn sun_position(
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Option<WasmEquaCoord> {
+    NaiveDate::from_ymd_opt(year, month, day).map(|date| {
+        equatorial_position_of_the_sun_from_generic_date(
+            date,
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sun_position_matches_the_underlying_function()
+    {
+        let date = NaiveDate::from_ymd(1988, 7, 27);
+        let coord =
+            equatorial_position_of_the_sun_from_generic_date(
+                date,
+            );
+        let asc: Angle = coord.asc.into();
+        let dec: Angle = coord.dec.into();
+
+        let pos = sun_position(1988, 7, 27).unwrap();
+        assert_eq!(pos.asc_h, asc.hour());
+        assert_eq!(pos.asc_m, asc.minute());
+        assert_eq!(pos.asc_s, asc.second());
+        assert_eq!(pos.dec_h, dec.hour());
+        assert_eq!(pos.dec_m, dec.minute());
+        assert_eq!(pos.dec_s, dec.second());
+
+        assert_eq!(pos.asc_h, 8);
+        assert_eq!(pos.dec_h, 19);
+    }
+
+    #[test]
+    fn sun_position_rejects_invalid_dates() {
+        assert_eq!(sun_position(2021, 2, 30), None);
+        assert_eq!(sun_position(2021, 13, 1), None);
+    }
+}